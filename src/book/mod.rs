@@ -0,0 +1,172 @@
+pub mod book {
+    //! Module that maintains the `book.toml` of the markdown book
+    //!
+    //! An existing book is updated in place: the settings given on the command line and
+    //! the overrides file replace the matching keys of its `book.toml`, every other key is
+    //! kept, and pages are only rewritten when their contents changed.
+
+    use std::fs::File;
+    use std::io;
+    use std::io::prelude::*;
+    use std::path::Path;
+
+    use toml;
+    use toml::value::Table;
+    use toml::Value;
+
+    use error::error::LojidocError;
+    use model::model::BookConfig;
+    use model::model::Options;
+
+    /// The scripts of the search widget, which every book includes
+    const SEARCH_JS: [&str; 2] = ["search-index.js", "lojidoc-search.js"];
+
+    /// Gets a nested table of a toml document, creating it when it is missing
+    fn table<'a>(root: &'a mut Table, path: &[&str]) -> &'a mut Table {
+        let mut current = root;
+
+        for key in path {
+            let entry = current.entry(key.to_string()).or_insert(Value::Table(Table::new()));
+            if !entry.is_table() {
+                *entry = Value::Table(Table::new());
+            }
+            current = match entry {
+                Value::Table(t) => t,
+                _ => unreachable!(),
+            };
+        }
+
+        current
+    }
+
+    /// Adds strings to an array of a toml table, skipping the ones it already contains
+    fn append(table: &mut Table, key: &str, values: Vec<String>) {
+        let mut items: Vec<Value> = match table.get(key) {
+            Some(Value::Array(items)) => items.clone(),
+            _ => Vec::new(),
+        };
+
+        for value in values {
+            let value = Value::String(value);
+            if !items.contains(&value) {
+                items.push(value);
+            }
+        }
+        if items.len() > 0 {
+            table.insert(key.to_string(), Value::Array(items));
+        }
+    }
+
+    /// Merges the keys of one toml table into another. Nested tables are merged and
+    /// every other value is replaced.
+    fn merge(base: &mut Table, overrides: Table) {
+        for (key, value) in overrides {
+            match (base.get_mut(&key), value) {
+                (Some(Value::Table(ref mut existing)), Value::Table(nested)) => merge(existing, nested),
+                (_, value) => {
+                    base.insert(key, value);
+                }
+            }
+        }
+    }
+
+    /// Creates the contents of `book.toml`
+    ///
+    /// # Arguments
+    ///
+    /// * `existing` - The contents of the book's current `book.toml`, empty for a new book
+    /// * `config` - The settings of the book
+    /// * `overrides` - The contents of a toml file whose keys replace the generated ones
+    pub fn book_toml(existing: &str, config: &BookConfig, overrides: &str) -> Result<String, LojidocError> {
+        let mut root: Table = if existing.trim() == "" {
+            let mut root = Table::new();
+            table(&mut root, &["book"]).insert("title".to_string(), Value::from("Application"));
+            root
+        } else {
+            toml::from_str(existing)?
+        };
+
+        {
+            let book = table(&mut root, &["book"]);
+            if config.title != "" {
+                book.insert("title".to_string(), Value::from(config.title.as_str()));
+            }
+            if config.authors.len() > 0 {
+                book.insert("authors".to_string(), Value::from(config.authors.clone()));
+            }
+            if config.language != "" {
+                book.insert("language".to_string(), Value::from(config.language.as_str()));
+            }
+            if config.description != "" {
+                book.insert("description".to_string(), Value::from(config.description.as_str()));
+            }
+        }
+
+        {
+            let html = table(&mut root, &["output", "html"]);
+            if config.theme != "" {
+                html.insert("theme".to_string(), Value::from(config.theme.as_str()));
+            }
+            append(html, "additional-css", config.additional_css.clone());
+
+            let mut js = config.additional_js.clone();
+            js.extend(SEARCH_JS.iter().map(|s| s.to_string()));
+            append(html, "additional-js", js);
+        }
+
+        if overrides.trim() != "" {
+            merge(&mut root, toml::from_str(overrides)?);
+        }
+
+        Ok(toml::to_string(&Value::Table(root))?)
+    }
+
+    /// Writes a file unless it already has the same contents. Returns whether the
+    /// file was written.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file
+    /// * `contents` - The new contents of the file
+    pub fn write_if_changed(path: &str, contents: &str) -> io::Result<bool> {
+        if Path::new(path).is_file() && read_file(path)? == contents {
+            return Ok(false);
+        }
+
+        File::create(path)?.write_all(contents.as_bytes())?;
+        Ok(true)
+    }
+
+    fn read_file(path: &str) -> io::Result<String> {
+        let mut contents = String::new();
+        File::open(path)?.read_to_string(&mut contents)?;
+
+        Ok(contents)
+    }
+
+    /// Creates or updates the `book.toml` in the root directory of the book
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - The options containing the book's location and settings
+    pub fn write_book_toml(options: &Options) -> Result<(), LojidocError> {
+        let path = format!("{}/book.toml", options.book_root());
+        let existing = if Path::new(&path).is_file() {
+            read_file(&path)?
+        } else {
+            String::new()
+        };
+        let overrides = if options.book_config.overrides != "" {
+            read_file(&options.book_config.overrides)?
+        } else {
+            String::new()
+        };
+
+        write_if_changed(&path, &book_toml(&existing, &options.book_config, &overrides)?)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test;
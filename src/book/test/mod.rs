@@ -0,0 +1,28 @@
+use book::book::*;
+use model::model::BookConfig;
+
+#[test]
+fn test_new_book_toml() {
+    let mut config = BookConfig::new();
+    config.authors = vec!["Jane Doe".to_string()];
+    config.language = "en".to_string();
+    config.additional_css = vec!["custom.css".to_string()];
+
+    let doc = book_toml("", &config, "").unwrap();
+
+    assert!(doc.contains("[book]\nauthors = [\"Jane Doe\"]\nlanguage = \"en\"\ntitle = \"Application\"\n"));
+    assert!(doc.contains("additional-css = [\"custom.css\"]\n"));
+    assert!(doc.contains("additional-js = [\"search-index.js\", \"lojidoc-search.js\"]\n"));
+}
+
+#[test]
+fn test_update_book_toml() {
+    let existing = "[book]\ntitle = \"Shop API\"\nsrc = \"src\"\n\n[output.html]\nadditional-js = [\"search-index.js\"]\n";
+    let mut config = BookConfig::new();
+    config.description = "The shop".to_string();
+
+    let doc = book_toml(existing, &config, "[output.html]\ncurly-quotes = true\n").unwrap();
+
+    assert!(doc.contains("[book]\ndescription = \"The shop\"\nsrc = \"src\"\ntitle = \"Shop API\"\n"));
+    assert!(doc.contains("additional-js = [\"search-index.js\", \"lojidoc-search.js\"]\ncurly-quotes = true\n"));
+}
@@ -0,0 +1,192 @@
+pub mod deprecated {
+    //! Module that creates the report of the deprecated API of a project
+    //!
+    //! `Deprecated.md` lists every deprecated type, member variable, enum constant,
+    //! constructor and method with the version it was deprecated in and the reason from
+    //! its javadoc.
+    //! The elements marked `forRemoval` come first, so cleanups can be planned from them.
+
+    use model::model::constant_anchor;
+    use model::model::field_anchor;
+    use model::model::method_anchor;
+    use model::model::Deprecation;
+    use model::model::LinkFn;
+    use model::model::Method;
+    use model::model::Project;
+    use model::model::TypeDecl;
+    use model::usage::qualified_name;
+    use template::template::table_cell;
+    use template::template::table_name;
+
+    /// The group of the enum constants in the API reports
+    pub const ENUM_CONSTANTS: &str = "Enum Constants";
+    /// The group of the member variables in the API reports
+    pub const FIELDS: &str = "Fields";
+    /// The group of the constructors in the API reports
+    pub const CONSTRUCTORS: &str = "Constructors";
+    /// The group of the methods in the API reports
+    pub const METHODS: &str = "Methods";
+
+    /// The groups of the API reports in the order they are listed. The types are grouped
+    /// by the plural of their kind.
+    pub const GROUPS: [&str; 9] = [
+        "Interfaces",
+        "Classes",
+        "Enums",
+        "Records",
+        "Annotations",
+        ENUM_CONSTANTS,
+        FIELDS,
+        CONSTRUCTORS,
+        METHODS,
+    ];
+
+    /// A deprecated type or member
+    pub struct DeprecatedEntry {
+        /// The qualified name of the type or member
+        pub name: String,
+        /// The group of the report listing the entry, e.g. "Methods"
+        pub group: String,
+        pub deprecation: Deprecation,
+        pub link: String,
+    }
+
+    /// Gets the group of a constructor or method in the API reports
+    ///
+    /// # Arguments
+    ///
+    /// * `decl` - The type declaring the method
+    /// * `method` - The constructor or method
+    pub fn method_group(decl: &TypeDecl, method: &Method) -> &'static str {
+        if method.name == decl.simple_name() {
+            CONSTRUCTORS
+        } else {
+            METHODS
+        }
+    }
+
+    /// Finds the deprecated types and members of a project
+    ///
+    /// # Arguments
+    ///
+    /// * `proj` - The documented project
+    /// * `link` - Creates the links of the type pages
+    pub fn find_deprecated(proj: &Project, link: &LinkFn) -> Vec<DeprecatedEntry> {
+        let mut entries = Vec::new();
+
+        for decl in proj.types.iter() {
+            let type_name = qualified_name(&decl.package_name, &decl.name);
+
+            if let Some(ref deprecation) = decl.deprecation {
+                entries.push(DeprecatedEntry {
+                    name: type_name.clone(),
+                    group: decl.kind.plural().to_string(),
+                    deprecation: deprecation.clone(),
+                    link: link(decl, ""),
+                });
+            }
+            for field in decl.fields.iter() {
+                if let Some(ref deprecation) = field.deprecation {
+                    entries.push(DeprecatedEntry {
+                        name: format!("{}.{}", type_name, field.name),
+                        group: ENUM_CONSTANTS.to_string(),
+                        deprecation: deprecation.clone(),
+                        link: link(decl, &constant_anchor(field)),
+                    });
+                }
+            }
+            for member in decl.variables.iter() {
+                if let Some(ref deprecation) = member.deprecation {
+                    entries.push(DeprecatedEntry {
+                        name: format!("{}.{}", type_name, member.name),
+                        group: FIELDS.to_string(),
+                        deprecation: deprecation.clone(),
+                        link: link(decl, &field_anchor(member)),
+                    });
+                }
+            }
+            for (i, method) in decl.methods.iter().enumerate() {
+                if let Some(ref deprecation) = method.deprecation {
+                    let params: Vec<&str> = method.parameters.iter().map(|p| p.var_type.as_str()).collect();
+
+                    entries.push(DeprecatedEntry {
+                        name: format!("{}.{}({})", type_name, method.name, params.join(", ")),
+                        group: method_group(decl, method).to_string(),
+                        deprecation: deprecation.clone(),
+                        link: link(decl, &method_anchor(&decl.methods, i)),
+                    });
+                }
+            }
+        }
+        entries.sort_by(|a, b| a.name.cmp(&b.name));
+
+        entries
+    }
+
+    /// Generates the markdown tables of the groups of deprecated elements
+    fn gen_groups(entries: &Vec<&DeprecatedEntry>) -> String {
+        let mut doc = String::new();
+
+        for group in GROUPS.iter() {
+            let rows: Vec<&&DeprecatedEntry> = entries.iter().filter(|e| e.group == *group).collect();
+            if rows.len() == 0 {
+                continue;
+            }
+
+            doc.push_str(format!("### {}\n\n| Element | Since | Reason |\n|---|---|---|\n", group).as_str());
+            for entry in rows {
+                doc.push_str(
+                    format!(
+                        "| [{}]({}) | {} | {} |\n",
+                        table_name(&entry.name),
+                        entry.link,
+                        entry.deprecation.since,
+                        table_cell(&entry.deprecation.reason)
+                    ).as_str(),
+                );
+            }
+            doc.push_str("\n");
+        }
+
+        doc
+    }
+
+    /// Generates the markdown of the deprecated API report. The elements marked for
+    /// removal and the other deprecated elements are listed separately, each grouped
+    /// by their kind.
+    ///
+    /// # Arguments
+    ///
+    /// * `entries` - The deprecated types and members of the project
+    pub fn gen_deprecated_report(entries: &Vec<DeprecatedEntry>) -> String {
+        let (removal, deprecated): (Vec<&DeprecatedEntry>, Vec<&DeprecatedEntry>) =
+            entries.iter().partition(|e| e.deprecation.for_removal);
+        let mut doc = String::from("# Deprecated API\n\n");
+
+        if entries.len() == 0 {
+            doc.push_str("The project has no deprecated API.\n");
+            return doc;
+        }
+
+        doc.push_str(
+            format!(
+                "{} deprecated elements, {} of them marked for removal.\n\n",
+                entries.len(),
+                removal.len()
+            ).as_str(),
+        );
+        if removal.len() > 0 {
+            doc.push_str("## For Removal\n\n");
+            doc.push_str(gen_groups(&removal).as_str());
+        }
+        if deprecated.len() > 0 {
+            doc.push_str("## Deprecated\n\n");
+            doc.push_str(gen_groups(&deprecated).as_str());
+        }
+
+        doc
+    }
+}
+
+#[cfg(test)]
+mod test;
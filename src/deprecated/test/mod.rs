@@ -0,0 +1,75 @@
+use deprecated::deprecated::*;
+use model::model::*;
+
+#[test]
+fn test_deprecated_report() {
+    let mut proj = Project::new();
+    let mut decl = TypeDecl::new(TypeKind::Class);
+    decl.ch_name("Order".to_string());
+    decl.ch_package_name("com.shop".to_string());
+
+    let mut removal = Deprecation::from_annotation("since = \"1.2\", forRemoval = true");
+    removal.ch_reason("Use | total instead".to_string());
+    let mut member = Member::new();
+    member.ch_name("sum".to_string());
+    member.ch_deprecation(Some(removal));
+    decl.add_variable(member);
+
+    let mut method = Method::new();
+    method.ch_method_name("Order".to_string());
+    method.add_param(Param {
+        desc: String::new(),
+        name: "id".to_string(),
+        var_type: "long".to_string(),
+    });
+    method.ch_deprecation(Some(Deprecation::new()));
+    decl.add_method(method);
+    decl.add_method(Method::new());
+    proj.add_type(decl);
+
+    let mut status = TypeDecl::new(TypeKind::Enumeration);
+    status.ch_name("Status".to_string());
+    status.ch_package_name("com.shop".to_string());
+    status.ch_fields(vec![
+        EnumField {
+            name: "OPEN".to_string(),
+            value: "0".to_string(),
+            desc: String::new(),
+            deprecation: None,
+            since: String::new(),
+        },
+        EnumField {
+            name: "CANCELLED".to_string(),
+            value: "1".to_string(),
+            desc: String::new(),
+            deprecation: Some(Deprecation::new()),
+            since: String::new(),
+        },
+    ]);
+    proj.add_type(status);
+
+    let entries = find_deprecated(&proj, &|decl, anchor| format!("{}.md#{}", decl.name, anchor));
+
+    assert_eq!(entries.len(), 3);
+    assert_eq!(entries[0].name, "com.shop.Order.Order(long)");
+    assert_eq!(entries[0].group, "Constructors");
+    assert_eq!(entries[1].link, "Order.md#field.sum");
+    assert_eq!(entries[2].name, "com.shop.Status.CANCELLED");
+    assert_eq!(entries[2].group, "Enum Constants");
+
+    let report = gen_deprecated_report(&entries);
+
+    assert!(report.starts_with("# Deprecated API\n\n3 deprecated elements, 1 of them marked for removal.\n\n"));
+    assert!(report.contains(
+        "## For Removal\n\n### Fields\n\n| Element | Since | Reason |\n|---|---|---|\n\
+         | [com.shop.Order.sum](Order.md#field.sum) | 1.2 | Use \\| total instead |\n"
+    ));
+    assert!(report.contains(
+        "## Deprecated\n\n### Enum Constants\n\n| Element | Since | Reason |\n|---|---|---|\n\
+         | [com.shop.Status.CANCELLED](Status.md#constant.CANCELLED) |  |  |\n\n### Constructors\n\n"
+    ));
+    assert_eq!(
+        gen_deprecated_report(&Vec::new()),
+        "# Deprecated API\n\nThe project has no deprecated API.\n"
+    );
+}
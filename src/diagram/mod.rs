@@ -0,0 +1,275 @@
+pub mod diagram {
+    //! Module that creates per-package class diagrams in Mermaid and PlantUML
+    //!
+    //! A diagram shows the classes, interfaces and enums of a package with their
+    //! member variables and methods, the inheritance and implementation edges and the
+    //! associations inferred from the types of member variables.
+
+    use std::collections::BTreeMap;
+
+    use model::model::Member;
+    use model::model::Method;
+    use model::model::Project;
+    use model::model::TypeDecl;
+    use model::model::TypeKind;
+    use model::model::UsageIndex;
+    use model::model::UsageKind;
+
+    /// An association from a type of the package to the type of one of its member variables
+    struct Association {
+        from: String,
+        to: String,
+        member: String,
+    }
+
+    /// Gets the UML visibility marker of a java access modifier
+    fn visibility_marker(access: &str) -> &'static str {
+        match access.trim() {
+            "public" => "+",
+            "protected" => "#",
+            "private" => "-",
+            _ => "~",
+        }
+    }
+
+    /// Removes the generic parameters and package of a type name, e.g. `a.b.List<X>` to `List`
+    fn simple_type_name(name: &str) -> String {
+        let base = name.trim().split("<").next().unwrap_or("");
+        base.split(".").last().unwrap_or("").to_string()
+    }
+
+    /// Gets the identifier of a type in a diagram. The dots of a nested type are not allowed
+    /// in an identifier, e.g. `Order.Builder` becomes `Order_Builder`.
+    fn diagram_id(name: &str) -> String {
+        name.trim().replace('.', "_")
+    }
+
+    /// Finds the associations of the types of a package from the project's usage index
+    fn associations(usages: &UsageIndex, package: &str) -> Vec<Association> {
+        let mut assocs: Vec<Association> = Vec::new();
+
+        for (target, target_usages) in usages.usages.iter() {
+            for usage in target_usages {
+                if usage.kind == UsageKind::Field && usage.package_name == package {
+                    assocs.push(Association {
+                        from: diagram_id(&usage.type_name),
+                        to: simple_type_name(target),
+                        member: usage.member.clone(),
+                    });
+                }
+            }
+        }
+        assocs.sort_by(|a, b| (&a.from, &a.member).cmp(&(&b.from, &b.member)));
+
+        assocs
+    }
+
+    /// Groups the type declarations of a project by package
+    fn group_packages(proj: &Project) -> BTreeMap<String, Vec<&TypeDecl>> {
+        let mut packages: BTreeMap<String, Vec<&TypeDecl>> = BTreeMap::new();
+
+        for decl in proj.types.iter() {
+            let types = packages.entry(decl.package_name.trim().to_string()).or_insert(Vec::new());
+            types.push(decl);
+        }
+        for types in packages.values_mut() {
+            types.sort_by(|a, b| a.name.cmp(&b.name));
+        }
+
+        packages
+    }
+
+    fn mermaid_type(type_str: &str) -> String {
+        type_str.trim().replace("<", "~").replace(">", "~")
+    }
+
+    fn params(method: &Method) -> String {
+        method
+            .parameters
+            .iter()
+            .map(|p| format!("{} {}", p.var_type, p.name))
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+
+    fn mermaid_member(member: &Member) -> String {
+        format!(
+            "    {}{} {}\n",
+            visibility_marker(&member.access),
+            mermaid_type(&member.var_type),
+            member.name
+        )
+    }
+
+    fn mermaid_method(method: &Method) -> String {
+        format!(
+            "    {}{}({}) {}\n",
+            visibility_marker(&method.privacy),
+            method.name,
+            mermaid_type(&params(method)),
+            mermaid_type(&method.return_type)
+        )
+    }
+
+    /// Generates the Mermaid class diagram of a package
+    ///
+    /// # Arguments
+    ///
+    /// * `types` - The type declarations of the package
+    /// * `usages` - The usage index of the project
+    /// * `package` - The name of the package
+    fn gen_mermaid(types: &Vec<&TypeDecl>, usages: &UsageIndex, package: &str) -> String {
+        let mut doc = String::from("classDiagram\n");
+
+        for decl in types.iter() {
+            doc.push_str(format!("  class {} {{\n", diagram_id(&decl.name)).as_str());
+            match decl.kind {
+                TypeKind::Interface => doc.push_str("    <<interface>>\n"),
+                TypeKind::Enumeration => doc.push_str("    <<enumeration>>\n"),
+                TypeKind::Record => doc.push_str("    <<record>>\n"),
+                TypeKind::Annotation => doc.push_str("    <<annotation>>\n"),
+                TypeKind::Class => (),
+            }
+            for field in decl.fields.iter() {
+                doc.push_str(format!("    {}\n", field.name).as_str());
+            }
+            for member in decl.variables.iter() {
+                doc.push_str(mermaid_member(member).as_str());
+            }
+            for method in decl.methods.iter().filter(|m| m.name != "" && m.name != decl.simple_name()) {
+                doc.push_str(mermaid_method(method).as_str());
+            }
+            doc.push_str("  }\n");
+        }
+
+        for decl in types.iter() {
+            if decl.parent.trim() != "" {
+                doc.push_str(
+                    format!("  {} <|-- {}\n", simple_type_name(&decl.parent), diagram_id(&decl.name)).as_str(),
+                );
+            }
+            for inter in decl.interfaces.iter() {
+                doc.push_str(
+                    format!("  {} <|.. {}\n", simple_type_name(inter), diagram_id(&decl.name)).as_str(),
+                );
+            }
+        }
+        for assoc in associations(usages, package) {
+            doc.push_str(format!("  {} --> {} : {}\n", assoc.from, assoc.to, assoc.member).as_str());
+        }
+
+        doc
+    }
+
+    /// Generates the PlantUML class diagram of a package
+    ///
+    /// # Arguments
+    ///
+    /// * `types` - The type declarations of the package
+    /// * `usages` - The usage index of the project
+    /// * `package` - The name of the package
+    fn gen_plantuml(types: &Vec<&TypeDecl>, usages: &UsageIndex, package: &str) -> String {
+        let mut doc = String::from("@startuml\n");
+
+        // The types of the default package are not wrapped in a package
+        if package != "" {
+            doc.push_str(format!("package {} {{\n", package).as_str());
+        }
+        for decl in types.iter() {
+            let kind = match decl.kind {
+                TypeKind::Class | TypeKind::Record => "class",
+                TypeKind::Interface => "interface",
+                TypeKind::Enumeration => "enum",
+                TypeKind::Annotation => "annotation",
+            };
+
+            let stereotype = if decl.kind == TypeKind::Record { " <<record>>" } else { "" };
+
+            let name = if decl.name.contains('.') {
+                format!("\"{}\" as {}", decl.name.trim(), diagram_id(&decl.name))
+            } else {
+                decl.name.trim().to_string()
+            };
+
+            doc.push_str(format!("  {} {}{} {{\n", kind, name, stereotype).as_str());
+            for field in decl.fields.iter() {
+                doc.push_str(format!("    {}\n", field.name).as_str());
+            }
+            for member in decl.variables.iter() {
+                doc.push_str(
+                    format!(
+                        "    {}{} {}\n",
+                        visibility_marker(&member.access),
+                        member.var_type.trim(),
+                        member.name
+                    ).as_str(),
+                );
+            }
+            for method in decl.methods.iter().filter(|m| m.name != "" && m.name != decl.simple_name()) {
+                doc.push_str(
+                    format!(
+                        "    {}{}({}) : {}\n",
+                        visibility_marker(&method.privacy),
+                        method.name,
+                        params(method),
+                        method.return_type.trim()
+                    ).as_str(),
+                );
+            }
+            doc.push_str("  }\n");
+        }
+        if package != "" {
+            doc.push_str("}\n");
+        }
+
+        for decl in types.iter() {
+            if decl.parent.trim() != "" {
+                doc.push_str(
+                    format!("{} <|-- {}\n", simple_type_name(&decl.parent), diagram_id(&decl.name)).as_str(),
+                );
+            }
+            for inter in decl.interfaces.iter() {
+                doc.push_str(
+                    format!("{} <|.. {}\n", simple_type_name(inter), diagram_id(&decl.name)).as_str(),
+                );
+            }
+        }
+        for assoc in associations(usages, package) {
+            doc.push_str(format!("{} --> {} : {}\n", assoc.from, assoc.to, assoc.member).as_str());
+        }
+        doc.push_str("@enduml\n");
+
+        doc
+    }
+
+    /// The class diagrams of a package
+    pub struct PackageDiagram {
+        pub mermaid: String,
+        pub plantuml: String,
+    }
+
+    /// Creates the Mermaid and PlantUML class diagrams of every package of a project
+    ///
+    /// # Arguments
+    ///
+    /// * `proj` - The project, already filtered by visibility
+    pub fn gen_package_diagrams(proj: &Project) -> BTreeMap<String, PackageDiagram> {
+        let usages = UsageIndex::new(proj);
+        let mut diagrams = BTreeMap::new();
+
+        for (package, types) in group_packages(proj).iter() {
+            diagrams.insert(
+                package.clone(),
+                PackageDiagram {
+                    mermaid: gen_mermaid(types, &usages, package),
+                    plantuml: gen_plantuml(types, &usages, package),
+                },
+            );
+        }
+
+        diagrams
+    }
+}
+
+#[cfg(test)]
+mod test;
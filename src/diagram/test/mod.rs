@@ -0,0 +1,78 @@
+use diagram::diagram::*;
+use model::model::*;
+
+fn shop() -> Project {
+    let mut proj = Project::new();
+
+    let mut status = TypeDecl::new(TypeKind::Enumeration);
+    status.ch_name("Status".to_string());
+    status.ch_package_name("com.shop".to_string());
+    status.ch_fields(vec![EnumField {
+        name: "OPEN".to_string(),
+        value: String::new(),
+        desc: String::new(),
+        deprecation: None,
+        since: String::new(),
+    }]);
+    proj.add_type(status);
+
+    let mut order = TypeDecl::new(TypeKind::Class);
+    order.ch_name("Order".to_string());
+    order.ch_package_name("com.shop".to_string());
+    order.ch_parent("Entity".to_string());
+    order.add_interface("Serializable".to_string());
+
+    let mut member = Member::new();
+    member.ch_name("status".to_string());
+    member.ch_access("private".to_string());
+    member.ch_type("Status".to_string());
+    order.add_variable(member);
+
+    let mut method = Method::new();
+    method.ch_method_name("items".to_string());
+    method.ch_privacy("public".to_string());
+    method.ch_return_type("List<String>".to_string());
+    order.add_method(method);
+    proj.add_type(order);
+
+    proj
+}
+
+#[test]
+fn test_mermaid_diagram() {
+    let diagrams = gen_package_diagrams(&shop());
+    let mermaid = &diagrams.get("com.shop").unwrap().mermaid;
+
+    assert!(mermaid.starts_with("classDiagram\n  class Order {\n"));
+    assert!(mermaid.contains("    -Status status\n"));
+    assert!(mermaid.contains("    +items() List~String~\n"));
+    assert!(mermaid.contains("  class Status {\n    <<enumeration>>\n    OPEN\n  }\n"));
+    assert!(mermaid.contains("  Entity <|-- Order\n"));
+    assert!(mermaid.contains("  Serializable <|.. Order\n"));
+    assert!(mermaid.contains("  Order --> Status : status\n"));
+}
+
+#[test]
+fn test_plantuml_diagram() {
+    let diagrams = gen_package_diagrams(&shop());
+    let plantuml = &diagrams.get("com.shop").unwrap().plantuml;
+
+    assert!(plantuml.starts_with("@startuml\npackage com.shop {\n  class Order {\n"));
+    assert!(plantuml.contains("    +items() : List<String>\n"));
+    assert!(plantuml.contains("  enum Status {\n    OPEN\n  }\n"));
+    assert!(plantuml.contains("Order --> Status : status\n"));
+    assert!(plantuml.ends_with("@enduml\n"));
+}
+
+#[test]
+fn test_default_package_diagram() {
+    let mut proj = Project::new();
+    let mut tool = TypeDecl::new(TypeKind::Class);
+    tool.ch_name("Tool".to_string());
+    proj.add_type(tool);
+
+    let diagrams = gen_package_diagrams(&proj);
+    let plantuml = &diagrams.get("").unwrap().plantuml;
+
+    assert_eq!(plantuml, "@startuml\n  class Tool {\n  }\n@enduml\n");
+}
@@ -0,0 +1,700 @@
+pub mod diff {
+    //! Module that compares the API of two revisions of a project
+    //!
+    //! The java sources of both revisions are read from the object database of the git
+    //! repository, without checking them out, and parsed into projects. Types, member
+    //! variables and enum constants are matched by their qualified names and methods also
+    //! by their parameter types, so an overload with new parameters is reported as an
+    //! added and a removed method.
+
+    use std::collections::BTreeMap;
+    use std::fs;
+    use std::fs::File;
+    use std::io::prelude::*;
+    use std::path::Path;
+
+    use serde_json;
+
+    use error::error::LojidocError;
+    use git2::ObjectType;
+    use git2::Repository;
+    use git2::Tree;
+    use model::model::strip_annotations;
+    use model::model::Deprecation;
+    use model::model::Member;
+    use model::model::Method;
+    use model::model::Project;
+    use model::model::TypeDecl;
+    use model::model::TypeKind;
+    use model::model::Visibility;
+    use model::usage::qualified_name;
+    use template::template::table_cell;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+    #[serde(rename_all = "lowercase")]
+    /// Whether an element of the API was added, removed or changed
+    pub enum ChangeKind {
+        Added,
+        Removed,
+        Changed,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize)]
+    /// A changed aspect of an element, e.g. its signature or visibility. An empty value
+    /// means the aspect was not set.
+    pub struct Change {
+        pub aspect: String,
+        pub before: String,
+        pub after: String,
+        /// Whether the change breaks the code compiled against the older revision
+        pub breaking: bool,
+    }
+
+    #[derive(Debug, Clone, Serialize)]
+    /// An added, removed or changed type or member
+    pub struct ApiChange {
+        pub change: ChangeKind,
+        /// The kind of the element, e.g. "class" or "method"
+        pub kind: String,
+        /// The qualified name of the element, with the parameter types of a method
+        pub name: String,
+        /// The visibility of the element before it was removed, or after it was added or changed
+        pub visibility: Visibility,
+        /// Whether the change breaks the code compiled against the older revision: a removed
+        /// public or protected element, an added abstract method or a breaking changed aspect
+        pub breaking: bool,
+        pub details: Vec<Change>,
+    }
+
+    #[derive(Debug, Clone, Serialize)]
+    /// The changes of the API between two revisions
+    pub struct ApiDiff {
+        pub from: String,
+        pub to: String,
+        pub changes: Vec<ApiChange>,
+    }
+
+    /// Collects the paths and contents of the java files of a tree and its subtrees
+    fn collect_java_files(
+        repo: &Repository,
+        tree: &Tree,
+        dir: &str,
+        files: &mut Vec<(String, String)>,
+    ) -> Result<(), LojidocError> {
+        for entry in tree.iter() {
+            let name = entry.name().unwrap_or("");
+            let path = if dir == "" {
+                name.to_string()
+            } else {
+                format!("{}/{}", dir, name)
+            };
+
+            match entry.kind() {
+                Some(ObjectType::Tree) => collect_java_files(repo, &repo.find_tree(entry.id())?, &path, files)?,
+                Some(ObjectType::Blob) if name.ends_with(".java") => {
+                    let blob = repo.find_blob(entry.id())?;
+                    files.push((path, String::from_utf8_lossy(blob.content()).into_owned()));
+                }
+                _ => (),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reads the java files of a revision from the object database of a git repository
+    /// and returns their paths, relative to the repository, and contents
+    ///
+    /// # Arguments
+    ///
+    /// * `repo_dir` - A directory inside the git repository
+    /// * `rev` - The revision, e.g. a tag, branch or commit id
+    /// * `path` - The directory of the java sources relative to the repository, or an empty
+    /// string for the whole repository
+    pub fn read_java_files(repo_dir: &Path, rev: &str, path: &str) -> Result<Vec<(String, String)>, LojidocError> {
+        let repo = Repository::discover(repo_dir)?;
+        let tree = repo.revparse_single(rev)?.peel_to_tree()?;
+        let prefix = path.trim_matches('/');
+        let mut files = Vec::new();
+
+        if prefix == "" {
+            collect_java_files(&repo, &tree, "", &mut files)?;
+        } else {
+            let subtree = repo.find_tree(tree.get_path(Path::new(prefix))?.id())?;
+            collect_java_files(&repo, &subtree, prefix, &mut files)?;
+        }
+
+        Ok(files)
+    }
+
+    /// The simple names of the common unchecked exceptions of the JDK. Any exception
+    /// ending in `Error` is unchecked too.
+    const UNCHECKED_EXCEPTIONS: [&str; 12] = [
+        "RuntimeException",
+        "IllegalArgumentException",
+        "IllegalStateException",
+        "NullPointerException",
+        "UnsupportedOperationException",
+        "IndexOutOfBoundsException",
+        "ArrayIndexOutOfBoundsException",
+        "ArithmeticException",
+        "ClassCastException",
+        "ConcurrentModificationException",
+        "NoSuchElementException",
+        "NumberFormatException",
+    ];
+
+    /// Gets the name of a type without its package
+    fn simple_name(name: &str) -> &str {
+        name.trim().rsplit('.').next().unwrap_or("")
+    }
+
+    /// Finds the simple names of the unchecked exceptions: the known ones and the types of
+    /// the project extending them, directly or through other types of the project
+    fn unchecked_exceptions(proj: &Project) -> Vec<String> {
+        let mut unchecked: Vec<String> = UNCHECKED_EXCEPTIONS.iter().map(|e| e.to_string()).collect();
+
+        loop {
+            let found: Vec<String> = proj
+                .types
+                .iter()
+                .map(|t| (t.name.trim().to_string(), simple_name(&t.parent)))
+                .filter(|&(ref name, parent)| !unchecked.contains(name) && unchecked.iter().any(|u| u == parent))
+                .map(|(name, _)| name)
+                .collect();
+
+            if found.len() == 0 {
+                return unchecked;
+            }
+            unchecked.extend(found);
+        }
+    }
+
+    /// Whether an exception is unchecked, so adding it to a `throws` clause does not break
+    /// the callers of a method
+    fn is_unchecked(exception: &str, unchecked: &Vec<String>) -> bool {
+        let name = simple_name(exception);
+
+        name.ends_with("Error") || unchecked.iter().any(|u| u == name)
+    }
+
+    /// Gets the declared return type of a method from its signature, which is empty for
+    /// a constructor
+    fn return_type(decl: &TypeDecl, method: &Method) -> String {
+        let signature = strip_annotations(&method.signature);
+        let head = match signature.find('(') {
+            Some(i) => signature[..i].trim().to_string(),
+            None => return String::new(),
+        };
+
+        if method.name == decl.simple_name() || !head.ends_with(method.name.as_str()) {
+            return String::new();
+        }
+
+        let head = head[..head.len() - method.name.len()].trim();
+        let mut depth = 0;
+        for (i, ch) in head.char_indices().rev() {
+            match ch {
+                '>' => depth += 1,
+                '<' => depth -= 1,
+                ' ' if depth == 0 => return head[i + 1..].to_string(),
+                _ => (),
+            }
+        }
+
+        head.to_string()
+    }
+
+    /// Gets the sorted exceptions of the `throws` clause of a method
+    fn thrown_exceptions(method: &Method) -> Vec<String> {
+        let signature = strip_annotations(&method.signature);
+        let mut exceptions: Vec<String> = match signature.rfind(')') {
+            Some(i) if signature[i + 1..].trim().starts_with("throws ") => signature[i + 1..].trim()[7..]
+                .split(',')
+                .map(|e| e.trim().to_string())
+                .filter(|e| e != "")
+                .collect(),
+            _ => Vec::new(),
+        };
+        exceptions.sort();
+
+        exceptions
+    }
+
+    /// Whether a method has no body: declared `abstract`, an element of an annotation
+    /// without a default value, or declared in an interface without `default`, `static`
+    /// or `private`
+    fn is_abstract(decl: &TypeDecl, method: &Method) -> bool {
+        let signature = strip_annotations(&method.signature);
+        let has = |word: &str| {
+            method.modifiers.iter().any(|m| m == word) || signature.split_whitespace().any(|w| w == word)
+        };
+
+        match decl.kind {
+            TypeKind::Interface => !has("default") && !has("static") && !has("private"),
+            TypeKind::Annotation => !has("default"),
+            _ => has("abstract"),
+        }
+    }
+
+    /// Whether a change of modifiers breaks compatibility: adding `final` or `abstract`,
+    /// or adding or removing `static`
+    fn breaks_modifiers(before: &Vec<String>, after: &Vec<String>) -> bool {
+        let added = |m: &str| !before.iter().any(|b| b == m) && after.iter().any(|a| a == m);
+        let removed = |m: &str| before.iter().any(|b| b == m) && !after.iter().any(|a| a == m);
+
+        added("final") || added("abstract") || added("static") || removed("static")
+    }
+
+    /// Whether a change of an element is reported at a level. Removals are reported down to
+    /// the protected elements, since removing them breaks the subclasses.
+    ///
+    /// # Arguments
+    ///
+    /// * `change` - The kind of the change
+    /// * `visibility` - The visibility of the element
+    /// * `level` - The least visibility of the compared elements
+    fn is_reported(change: ChangeKind, visibility: Visibility, level: Visibility) -> bool {
+        visibility >= level || (change == ChangeKind::Removed && visibility >= Visibility::Protected)
+    }
+
+    /// Whether a visibility change hides an element from the code outside of its package
+    fn narrows(before: Visibility, after: Visibility) -> bool {
+        after < before && before >= Visibility::Protected
+    }
+
+    /// Describes the deprecation of an element for the comparison
+    fn deprecation_label(deprecation: &Option<Deprecation>) -> String {
+        match deprecation {
+            Some(ref d) if d.for_removal => String::from("deprecated for removal"),
+            Some(_) => String::from("deprecated"),
+            None => String::new(),
+        }
+    }
+
+    /// Gets the key a method is matched by, its name followed by its parameter types. A
+    /// constructor is named after its type, so its overloads are told apart by their
+    /// parameters like those of a method.
+    fn method_key(method: &Method) -> String {
+        let params: Vec<&str> = method.parameters.iter().map(|p| p.var_type.as_str()).collect();
+
+        format!("{}({})", method.name, params.join(", "))
+    }
+
+    /// Adds a change to the details when the aspect differs between the revisions
+    ///
+    /// # Arguments
+    ///
+    /// * `details` - The changed aspects of the element
+    /// * `aspect` - The name of the compared aspect
+    /// * `before` - The aspect in the older revision
+    /// * `after` - The aspect in the newer revision
+    /// * `breaking` - Whether the change breaks compatibility when the aspect differs
+    fn compare(details: &mut Vec<Change>, aspect: &str, before: String, after: String, breaking: bool) {
+        if before != after {
+            details.push(Change {
+                aspect: aspect.to_string(),
+                before: before,
+                after: after,
+                breaking: breaking,
+            });
+        }
+    }
+
+    /// Compares the visibility of an element and returns the higher of both visibilities
+    fn compare_visibility(details: &mut Vec<Change>, before: Visibility, after: Visibility) -> Visibility {
+        compare(
+            details,
+            "visibility",
+            before.name().to_string(),
+            after.name().to_string(),
+            narrows(before, after),
+        );
+
+        if before > after {
+            before
+        } else {
+            after
+        }
+    }
+
+    /// Compares the member variables, methods and enum constants of a type
+    ///
+    /// # Arguments
+    ///
+    /// * `old` - The type in the older revision
+    /// * `new` - The type in the newer revision
+    /// * `level` - Only the members with at least this visibility in either revision are compared
+    /// * `unchecked` - The simple names of the unchecked exceptions
+    /// * `changes` - The changes the members are added to
+    fn diff_members(
+        old: &TypeDecl,
+        new: &TypeDecl,
+        level: Visibility,
+        unchecked: &Vec<String>,
+        changes: &mut Vec<ApiChange>,
+    ) {
+        let type_name = qualified_name(&new.package_name, &new.name);
+        let mut push = |change: ChangeKind, kind: &str, name: String, visibility: Visibility, details: Vec<Change>| {
+            if !is_reported(change, visibility, level) {
+                return;
+            }
+
+            let breaking = match change {
+                ChangeKind::Removed => visibility >= Visibility::Protected,
+                ChangeKind::Added | ChangeKind::Changed => details.iter().any(|d| d.breaking),
+            };
+            changes.push(ApiChange {
+                change: change,
+                kind: kind.to_string(),
+                name: format!("{}.{}", type_name, name),
+                visibility: visibility,
+                breaking: breaking,
+                details: details,
+            });
+        };
+
+        let old_fields: BTreeMap<&str, &Member> = old.variables.iter().map(|m| (m.name.as_str(), m)).collect();
+        let new_fields: BTreeMap<&str, &Member> = new.variables.iter().map(|m| (m.name.as_str(), m)).collect();
+        for (name, member) in old_fields.iter() {
+            if !new_fields.contains_key(name) {
+                let visibility = Visibility::of_member(old, &member.access);
+                push(ChangeKind::Removed, "field", name.to_string(), visibility, Vec::new());
+            }
+        }
+        for (name, member) in new_fields.iter() {
+            let visibility = Visibility::of_member(new, &member.access);
+            let before = match old_fields.get(name) {
+                Some(before) => before,
+                None => {
+                    push(ChangeKind::Added, "field", name.to_string(), visibility, Vec::new());
+                    continue;
+                }
+            };
+            let mut details = Vec::new();
+
+            compare(&mut details, "type", before.var_type.clone(), member.var_type.clone(), true);
+            let shown = compare_visibility(&mut details, Visibility::of_member(old, &before.access), visibility);
+            compare(
+                &mut details,
+                "modifiers",
+                before.modifiers.join(" "),
+                member.modifiers.join(" "),
+                breaks_modifiers(&before.modifiers, &member.modifiers),
+            );
+            compare(
+                &mut details,
+                "deprecation",
+                deprecation_label(&before.deprecation),
+                deprecation_label(&member.deprecation),
+                false,
+            );
+            if details.len() > 0 {
+                push(ChangeKind::Changed, "field", name.to_string(), shown, details);
+            }
+        }
+
+        let kind = |decl: &TypeDecl, method: &Method| {
+            if method.name == decl.simple_name() { "constructor" } else { "method" }
+        };
+        let old_methods: BTreeMap<String, &Method> = old
+            .methods
+            .iter()
+            .map(|m| (method_key(m), m))
+            .collect();
+        let new_methods: BTreeMap<String, &Method> = new
+            .methods
+            .iter()
+            .map(|m| (method_key(m), m))
+            .collect();
+        for (key, method) in old_methods.iter() {
+            if !new_methods.contains_key(key) {
+                let visibility = Visibility::of_member(old, &method.privacy);
+                push(ChangeKind::Removed, kind(old, method), key.clone(), visibility, Vec::new());
+            }
+        }
+        for (key, method) in new_methods.iter() {
+            let visibility = Visibility::of_member(new, &method.privacy);
+            let before = match old_methods.get(key) {
+                Some(before) => before,
+                None => {
+                    // Every implementation of the type has to implement an added abstract method
+                    let details = if is_abstract(new, method) && visibility >= Visibility::Protected {
+                        vec![Change {
+                            aspect: String::from("abstract"),
+                            before: String::new(),
+                            after: String::from("abstract"),
+                            breaking: true,
+                        }]
+                    } else {
+                        Vec::new()
+                    };
+
+                    push(ChangeKind::Added, kind(new, method), key.clone(), visibility, details);
+                    continue;
+                }
+            };
+            let signature = |m: &Method| m.signature.split_whitespace().collect::<Vec<&str>>().join(" ");
+            let old_exceptions = thrown_exceptions(before);
+            let new_exceptions = thrown_exceptions(method);
+            let new_checked = new_exceptions
+                .iter()
+                .any(|e| !old_exceptions.contains(e) && !is_unchecked(e, unchecked));
+            let mut details = Vec::new();
+
+            compare(&mut details, "signature", signature(before), signature(method), false);
+            compare(&mut details, "return type", return_type(old, before), return_type(new, method), true);
+            compare(&mut details, "exceptions", old_exceptions.join(", "), new_exceptions.join(", "), new_checked);
+            let shown = compare_visibility(&mut details, Visibility::of_member(old, &before.privacy), visibility);
+            compare(
+                &mut details,
+                "modifiers",
+                before.modifiers.join(" "),
+                method.modifiers.join(" "),
+                breaks_modifiers(&before.modifiers, &method.modifiers),
+            );
+            compare(
+                &mut details,
+                "deprecation",
+                deprecation_label(&before.deprecation),
+                deprecation_label(&method.deprecation),
+                false,
+            );
+            if details.len() > 0 {
+                push(ChangeKind::Changed, kind(new, method), key.clone(), shown, details);
+            }
+        }
+
+        for field in old.fields.iter() {
+            if !new.fields.iter().any(|f| f.name == field.name) {
+                push(ChangeKind::Removed, "enum constant", field.name.clone(), Visibility::of_type(old), Vec::new());
+            }
+        }
+        for field in new.fields.iter() {
+            if !old.fields.iter().any(|f| f.name == field.name) {
+                push(ChangeKind::Added, "enum constant", field.name.clone(), Visibility::of_type(new), Vec::new());
+            }
+        }
+    }
+
+    /// Compares the API of two projects and returns the added, removed and changed types
+    /// and members sorted by name. The members of an added or removed type are not listed.
+    /// Every change is classified following the binary and source compatibility rules of java.
+    /// Removed protected elements are reported at every level, since they break subclasses.
+    ///
+    /// # Arguments
+    ///
+    /// * `old` - The project at the older revision
+    /// * `new` - The project at the newer revision
+    /// * `level` - Only the elements with at least this visibility in either revision are compared
+    pub fn diff_projects(old: &Project, new: &Project, level: Visibility) -> Vec<ApiChange> {
+        let old_types: BTreeMap<String, &TypeDecl> = old
+            .types
+            .iter()
+            .map(|t| (qualified_name(&t.package_name, &t.name), t))
+            .collect();
+        let new_types: BTreeMap<String, &TypeDecl> = new
+            .types
+            .iter()
+            .map(|t| (qualified_name(&t.package_name, &t.name), t))
+            .collect();
+        let unchecked = unchecked_exceptions(new);
+        let mut changes = Vec::new();
+
+        for (name, decl) in old_types.iter() {
+            let visibility = old.type_visibility(decl);
+
+            if !new_types.contains_key(name) && is_reported(ChangeKind::Removed, visibility, level) {
+                changes.push(ApiChange {
+                    change: ChangeKind::Removed,
+                    kind: decl.kind.label().to_string(),
+                    name: name.clone(),
+                    visibility: visibility,
+                    breaking: visibility >= Visibility::Protected,
+                    details: Vec::new(),
+                });
+            }
+        }
+        for (name, decl) in new_types.iter() {
+            let visibility = new.type_visibility(decl);
+            let before = match old_types.get(name) {
+                Some(before) => before,
+                None => {
+                    if visibility >= level {
+                        changes.push(ApiChange {
+                            change: ChangeKind::Added,
+                            kind: decl.kind.label().to_string(),
+                            name: name.clone(),
+                            visibility: visibility,
+                            breaking: false,
+                            details: Vec::new(),
+                        });
+                    }
+                    continue;
+                }
+            };
+            let mut interfaces = (before.interfaces.clone(), decl.interfaces.clone());
+            interfaces.0.sort();
+            interfaces.1.sort();
+            let mut details = Vec::new();
+
+            compare(&mut details, "kind", before.kind.label().to_string(), decl.kind.label().to_string(), true);
+            let shown = compare_visibility(&mut details, old.type_visibility(before), visibility);
+            compare(
+                &mut details,
+                "modifiers",
+                before.modifiers.join(" "),
+                decl.modifiers.join(" "),
+                breaks_modifiers(&before.modifiers, &decl.modifiers),
+            );
+            compare(
+                &mut details,
+                "parent",
+                before.parent.clone(),
+                decl.parent.clone(),
+                before.parent != "",
+            );
+            compare(
+                &mut details,
+                "interfaces",
+                interfaces.0.join(", "),
+                interfaces.1.join(", "),
+                interfaces.0.iter().any(|i| !interfaces.1.contains(i)),
+            );
+            compare(
+                &mut details,
+                "deprecation",
+                deprecation_label(&before.deprecation),
+                deprecation_label(&decl.deprecation),
+                false,
+            );
+            if details.len() > 0 && shown >= level {
+                changes.push(ApiChange {
+                    change: ChangeKind::Changed,
+                    kind: decl.kind.label().to_string(),
+                    name: name.clone(),
+                    visibility: shown,
+                    breaking: details.iter().any(|d| d.breaking),
+                    details: details,
+                });
+            }
+
+            diff_members(before, decl, level, &unchecked, &mut changes);
+        }
+        changes.sort_by(|a, b| a.name.cmp(&b.name));
+
+        changes
+    }
+
+    /// Describes a changed aspect for the markdown report
+    fn describe(change: &Change) -> String {
+        let value = |v: &str| if v == "" { String::from("none") } else { format!("`{}`", v) };
+
+        format!("{}: {} → {}", change.aspect, value(&change.before), value(&change.after))
+    }
+
+    /// Describes why a change breaks compatibility for the markdown report
+    fn breaking_reason(change: &ApiChange) -> String {
+        match change.change {
+            ChangeKind::Removed => String::from("removed"),
+            ChangeKind::Added => String::from("abstract method added"),
+            ChangeKind::Changed => {
+                let reasons: Vec<String> = change.details.iter().filter(|d| d.breaking).map(describe).collect();
+                reasons.join("<br>")
+            }
+        }
+    }
+
+    /// Generates the markdown report of the changes between two revisions, with tables
+    /// of the breaking, removed, added and changed elements
+    ///
+    /// # Arguments
+    ///
+    /// * `diff` - The changes of the API
+    pub fn gen_diff_report(diff: &ApiDiff) -> String {
+        let mut doc = format!("# API Changes from {} to {}\n\n", diff.from, diff.to);
+
+        if diff.changes.len() == 0 {
+            doc.push_str("The API did not change.\n");
+            return doc;
+        }
+
+        let count = |kind: ChangeKind| diff.changes.iter().filter(|c| c.change == kind).count();
+        doc.push_str(
+            format!(
+                "{} added, {} removed and {} changed elements.\n\n",
+                count(ChangeKind::Added),
+                count(ChangeKind::Removed),
+                count(ChangeKind::Changed)
+            ).as_str(),
+        );
+
+        let breaking: Vec<&ApiChange> = diff.changes.iter().filter(|c| c.breaking).collect();
+        if breaking.len() == 0 {
+            doc.push_str("None of the changes break compatibility.\n\n");
+        } else {
+            doc.push_str(format!("{} of the changes break compatibility.\n\n", breaking.len()).as_str());
+            doc.push_str("## Breaking Changes\n\n| Element | Kind | Reason |\n|---|---|---|\n");
+            for change in breaking {
+                doc.push_str(
+                    format!(
+                        "| `{}` | {} | {} |\n",
+                        table_cell(&change.name),
+                        change.kind,
+                        table_cell(&breaking_reason(change))
+                    ).as_str(),
+                );
+            }
+            doc.push_str("\n");
+        }
+
+        for &(kind, title) in [(ChangeKind::Removed, "Removed"), (ChangeKind::Added, "Added")].iter() {
+            if count(kind) == 0 {
+                continue;
+            }
+
+            doc.push_str(format!("## {}\n\n| Element | Kind |\n|---|---|\n", title).as_str());
+            for change in diff.changes.iter().filter(|c| c.change == kind) {
+                doc.push_str(format!("| `{}` | {} |\n", table_cell(&change.name), change.kind).as_str());
+            }
+            doc.push_str("\n");
+        }
+
+        if count(ChangeKind::Changed) > 0 {
+            doc.push_str("## Changed\n\n| Element | Kind | Changes |\n|---|---|---|\n");
+            for change in diff.changes.iter().filter(|c| c.change == ChangeKind::Changed) {
+                let details: Vec<String> = change.details.iter().map(describe).collect();
+
+                doc.push_str(
+                    format!(
+                        "| `{}` | {} | {} |\n",
+                        table_cell(&change.name),
+                        change.kind,
+                        table_cell(&details.join("<br>"))
+                    ).as_str(),
+                );
+            }
+            doc.push_str("\n");
+        }
+
+        doc
+    }
+
+    /// Writes the changes of the API as `API-Changes.md` and `api-changes.json` into a directory
+    ///
+    /// # Arguments
+    ///
+    /// * `diff` - The changes of the API
+    /// * `dest` - The destination directory
+    pub fn write_diff(diff: &ApiDiff, dest: &str) -> Result<(), LojidocError> {
+        fs::create_dir_all(dest)?;
+        File::create(format!("{}/API-Changes.md", dest))?.write_all(gen_diff_report(diff).as_bytes())?;
+        File::create(format!("{}/api-changes.json", dest))?
+            .write_all(serde_json::to_string_pretty(diff)?.as_bytes())?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,173 @@
+use diff::diff::*;
+use model::model::*;
+use parse::parse::parse_contents;
+
+fn project(sources: Vec<&str>) -> Project {
+    let mut proj = Project::new();
+    for source in sources {
+        proj.add_type(parse_contents(source).unwrap());
+    }
+
+    proj
+}
+
+#[test]
+fn test_diff_projects() {
+    let old = project(vec![
+        "package a;\npublic class Order {\n    public int total;\n    private int secret;\n\n    public void pay() {\n    }\n\n    public void ship(String address) {\n    }\n}\n",
+        "package a;\npublic class Invoice {\n}\n",
+    ]);
+    let new = project(vec![
+        "package a;\npublic class Order {\n    public long total;\n\n    /** @deprecated Use ship */\n    @Deprecated\n    protected void pay() {\n    }\n\n    public void ship(String address, int days) {\n    }\n}\n",
+        "package a;\npublic interface Payment {\n}\n",
+    ]);
+
+    let changes = diff_projects(&old, &new, Visibility::Public);
+    let summary: Vec<(ChangeKind, &str, &str)> = changes
+        .iter()
+        .map(|c| (c.change, c.kind.as_str(), c.name.as_str()))
+        .collect();
+
+    assert_eq!(
+        summary,
+        vec![
+            (ChangeKind::Removed, "class", "a.Invoice"),
+            (ChangeKind::Changed, "method", "a.Order.pay()"),
+            (ChangeKind::Removed, "method", "a.Order.ship(String)"),
+            (ChangeKind::Added, "method", "a.Order.ship(String, int)"),
+            (ChangeKind::Changed, "field", "a.Order.total"),
+            (ChangeKind::Added, "interface", "a.Payment"),
+        ]
+    );
+
+    let aspects: Vec<&str> = changes[1].details.iter().map(|d| d.aspect.as_str()).collect();
+    assert_eq!(aspects, vec!["signature", "visibility", "deprecation"]);
+    assert_eq!(changes[1].visibility, Visibility::Public);
+    assert_eq!(changes[4].details[0].before, "int");
+    assert_eq!(changes[4].details[0].after, "long");
+    let breaking: Vec<&str> = changes.iter().filter(|c| c.breaking).map(|c| c.name.as_str()).collect();
+    assert_eq!(breaking, vec!["a.Invoice", "a.Order.pay()", "a.Order.ship(String)", "a.Order.total"]);
+
+    let report = gen_diff_report(&ApiDiff {
+        from: "v1".to_string(),
+        to: "v2".to_string(),
+        changes: changes,
+    });
+
+    assert!(report.starts_with("# API Changes from v1 to v2\n\n2 added, 2 removed and 2 changed elements.\n\n"));
+    assert!(report.contains("4 of the changes break compatibility.\n\n## Breaking Changes\n"));
+    assert!(report.contains("| `a.Order.ship(String)` | method | removed |\n"));
+    assert!(report.contains("## Removed\n\n| Element | Kind |\n|---|---|\n| `a.Invoice` | class |\n"));
+    assert!(report.contains("| `a.Order.total` | field | type: `int` → `long` |\n"));
+    assert!(report.contains("visibility: `public` → `protected`<br>deprecation: none → `deprecated` |\n"));
+}
+
+#[test]
+fn test_breaking_changes() {
+    let old = project(vec![
+        "package a;\npublic interface Store {\n    String name();\n\n    void save(String key) throws IllegalStateException;\n\n    int size();\n}\n",
+        "package a;\npublic class Cart {\n    public void clear() {\n    }\n\n    public void add(String item) {\n    }\n}\n",
+        "package a;\npublic class StoreException extends RuntimeException {\n}\n",
+    ]);
+    let new = project(vec![
+        "package a;\npublic interface Store {\n    @Override\n    String name();\n\n    void save(String key) throws StoreException, java.io.IOException;\n\n    long size();\n\n    void close();\n\n    default boolean isEmpty() {\n        return size() == 0;\n    }\n}\n",
+        "package a;\npublic class Cart {\n    public final void clear() {\n    }\n\n    public void add(String item) throws StoreException {\n    }\n}\n",
+        "package a;\npublic class StoreException extends RuntimeException {\n}\n",
+    ]);
+
+    let changes = diff_projects(&old, &new, Visibility::Public);
+    let summary: Vec<(&str, bool)> = changes.iter().map(|c| (c.name.as_str(), c.breaking)).collect();
+
+    assert_eq!(
+        summary,
+        vec![
+            ("a.Cart.add(String)", false),
+            ("a.Cart.clear()", true),
+            ("a.Store.close()", true),
+            ("a.Store.isEmpty()", false),
+            ("a.Store.name()", false),
+            ("a.Store.save(String)", true),
+            ("a.Store.size()", true),
+        ]
+    );
+
+    let exceptions = changes[5].details.iter().find(|d| d.aspect == "exceptions").unwrap();
+    assert_eq!(exceptions.before, "IllegalStateException");
+    assert_eq!(exceptions.after, "StoreException, java.io.IOException");
+    assert!(exceptions.breaking);
+
+    let return_type = changes[6].details.iter().find(|d| d.aspect == "return type").unwrap();
+    assert_eq!((return_type.before.as_str(), return_type.after.as_str()), ("int", "long"));
+}
+
+#[test]
+fn test_removed_protected_members() {
+    let old = project(vec![
+        "package a;\npublic class Base {\n    protected int count;\n\n    protected void reset() {\n    }\n\n    void check() {\n    }\n}\n",
+    ]);
+    let new = project(vec!["package a;\npublic class Base {\n}\n"]);
+
+    let changes = diff_projects(&old, &new, Visibility::Public);
+    let summary: Vec<(&str, bool)> = changes.iter().map(|c| (c.name.as_str(), c.breaking)).collect();
+
+    assert_eq!(summary, vec![("a.Base.count", true), ("a.Base.reset()", true)]);
+}
+
+#[test]
+fn test_removed_and_narrowed_constructors() {
+    let old = project(vec![
+        "package a;\npublic class A {\n    public A(String name) {\n    }\n\n    public A(int size) {\n    }\n\n    public static class B {\n        public B() {\n        }\n    }\n}\n",
+    ]);
+    let new = project(vec![
+        "package a;\npublic class A {\n    A(int size) {\n    }\n\n    public static class B {\n        public B() {\n        }\n    }\n}\n",
+    ]);
+
+    let changes = diff_projects(&old, &new, Visibility::Public);
+    let summary: Vec<(ChangeKind, &str, &str, bool)> = changes
+        .iter()
+        .map(|c| (c.change, c.kind.as_str(), c.name.as_str(), c.breaking))
+        .collect();
+
+    assert_eq!(
+        summary,
+        vec![
+            (ChangeKind::Removed, "constructor", "a.A.A(String)", true),
+            (ChangeKind::Changed, "constructor", "a.A.A(int)", true),
+        ]
+    );
+    let aspects: Vec<&str> = changes[1].details.iter().map(|d| d.aspect.as_str()).collect();
+    assert_eq!(aspects, vec!["signature", "visibility"]);
+}
+
+#[test]
+fn test_overloaded_constructors() {
+    let old = project(vec![
+        "package a;\npublic class Point {\n    public Point() {\n    }\n\n    public Point(int x, int y) {\n    }\n\n    public Point(Point other) {\n    }\n}\n",
+    ]);
+    let new = project(vec![
+        "package a;\npublic class Point {\n    public Point() {\n    }\n\n    public Point(int x, int y) throws java.io.IOException {\n    }\n\n    public Point(double x, double y) {\n    }\n}\n",
+    ]);
+
+    let changes = diff_projects(&old, &new, Visibility::Public);
+    let summary: Vec<(ChangeKind, &str, &str)> = changes
+        .iter()
+        .map(|c| (c.change, c.kind.as_str(), c.name.as_str()))
+        .collect();
+
+    assert_eq!(
+        summary,
+        vec![
+            (ChangeKind::Removed, "constructor", "a.Point.Point(Point)"),
+            (ChangeKind::Added, "constructor", "a.Point.Point(double, double)"),
+            (ChangeKind::Changed, "constructor", "a.Point.Point(int, int)"),
+        ]
+    );
+
+    let report = gen_diff_report(&ApiDiff {
+        from: "v1".to_string(),
+        to: "v2".to_string(),
+        changes: changes,
+    });
+    assert!(report.contains("| `a.Point.Point(double, double)` | constructor |\n"));
+    assert!(report.contains("| `a.Point.Point(Point)` | constructor |\n"));
+}
@@ -4,22 +4,56 @@ pub mod document {
 
     use mdbook::MDBook;
 
+    use std::collections::BTreeMap;
     use std::fs;
     use std::fs::File;
+    use std::io;
     use std::io::prelude::*;
     use std::path::Path;
     use std::path::PathBuf;
 
+    use book::book::write_book_toml;
+    use book::book::write_if_changed;
     use colored::*;
+    use deprecated::deprecated::find_deprecated;
+    use deprecated::deprecated::gen_deprecated_report;
+    use history::history::find_recent_changes;
+    use history::history::gen_recent_page;
+    use diagram::diagram::gen_package_diagrams;
+    use error::error::LojidocError;
     use git2::Repository;
+    use index::index::build_symbol_index;
+    use index::index::gen_index_page;
     use model::contents::ApplicationDoc;
-    use model::model::Class;
-    use model::model::Options;
-    use model::model::Enumeration;
-    use model::model::Interface;
+    use model::model::walk_project;
+    use model::model::Backend;
+    use model::model::LintRules;
     use model::model::Member;
     use model::model::Method;
+    use model::model::Options;
     use model::model::Project;
+    use model::model::SiteProfile;
+    use model::model::TypeDecl;
+    use model::model::TypeSummary;
+    use model::model::UsageIndex;
+    use model::model::Visitor;
+    use model::usage::qualified_name;
+    use search::search::build_index;
+    use search::search::write_search_index;
+    use site::site::contents_file;
+    use site::site::contents_front_matter;
+    use site::site::front_matter;
+    use site::site::page_link;
+    use site::site::report_front_matter;
+    use site::site::type_weights;
+    use site::site::write_nav;
+    use template::template::Templates;
+    use template::template::PACKAGE_PAGE;
+    use versions::versions::find_new_api;
+    use versions::versions::gen_version_page;
+    use versions::versions::gen_versions_page;
+    use versions::versions::project_versions;
+    use versions::versions::version_page;
 
     /// Traverses the file structure to find all java files for parsing.
     ///
@@ -38,7 +72,6 @@ pub mod document {
         let file_dir = fs::read_dir(start_dir);
 
         if !file_dir.is_ok() {
-            println!("Incorrect file path");
             return files;
         }
 
@@ -93,491 +126,441 @@ pub mod document {
                     }
                 }
             }
-        } else {
-            println!("Generated directory read error: {:?}", gen_dir);
         }
 
         files
     }
 
-    /// Generates the markdown documentation for a class
+    /// Creates a relative link from the page of a type in one package to the page of
+    /// a type in another package
     ///
     /// # Arguments
     ///
-    /// * `class` - The class struct containing the javadoc data
-    pub fn gen_class_docs(class: Class) -> String {
-        let mut doc = String::new();
-
-        if class.file_path != "" {
-            doc.push_str(
-                format!("# Class {} [[src]]({})  \n\n", class.name, class.file_path).as_str(),
-            );
-            doc.push_str(format!(" > {}  \n\n", class.signature.trim()).as_str());
-        } else {
-            doc.push_str(format!("# Class {}\n\n", class.name).as_str());
-        }
+    /// * `from_package` - The package of the page containing the link
+    /// * `to_package` - The package of the linked type
+    /// * `name` - The name of the linked type
+    pub fn package_link(from_package: &str, to_package: &str, name: &str) -> String {
+        format!("{}{}.md", package_path(from_package, to_package), name)
+    }
 
-        if class.license != "" {
-            doc.push_str("<details>  \n");
-            doc.push_str("  <summary>  \n");
-            doc.push_str("    Show license  \n\n");
-            doc.push_str("  </summary>  \n");
+    /// Creates the relative path from the directory of one package to the directory
+    /// of another package, e.g. `../service/`
+    ///
+    /// # Arguments
+    ///
+    /// * `from_package` - The package of the page containing the link
+    /// * `to_package` - The linked package
+    pub fn package_path(from_package: &str, to_package: &str) -> String {
+        let from: Vec<&str> = from_package.trim().split(".").filter(|p| *p != "").collect();
+        let to: Vec<&str> = to_package.trim().split(".").filter(|p| *p != "").collect();
+        let mut common = 0;
 
-            doc.push_str("  <ul>  \n");
-            doc.push_str(class.license.as_str());
-            doc.push_str("  </ul>  \n");
-            doc.push_str("</details>  \n\n");
-            doc.push_str("<br/>");
+        while common < from.len() && common < to.len() && from[common] == to[common] {
+            common += 1;
         }
 
-        doc.push_str(format!("Access: {}  \n", class.access.trim()).as_str());
-        if class.description.as_str() != "" {
-            doc.push_str(format!("Description:  \n > {}  \n\n", class.description.trim()).as_str());
-        }
-        if class.author != "" {
-            doc.push_str(format!("Author: {}  \n", class.author).as_str());
-        }
-        if class.version != "" {
-            doc.push_str(format!("Since version: {}  \n", class.version).as_str());
+        let mut path = String::new();
+        for _ in common..from.len() {
+            path.push_str("../");
         }
-        if class.parent != "" {
-            doc.push_str(format!("Parent class: {}  \n", class.parent).as_str());
+        for part in &to[common..] {
+            path.push_str(format!("{}/", part).as_str());
         }
 
-        if class.interfaces.len() > 0 {
-            doc.push_str("Interfaces:  \n");
+        path
+    }
+
+    /// Deletes the destination directory of a previous documentation run.
+    /// Fails without deleting anything if the directory contains java files.
+    ///
+    /// # Arguments
+    ///
+    /// * `dest` - The destination directory
+    pub fn remove_old_docs(dest: &str) -> io::Result<()> {
+        if find_file_type(Path::new(dest), vec!["java", "class"]).len() > 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::Other,
+                "Java files found in the destination directory",
+            ));
+        } else if Path::new(dest).exists() {
+            fs::remove_dir_all(dest)?;
+        }
+
+        Ok(())
+    }
 
-            for inter in class.interfaces {
-                doc.push_str(format!("- {}  \n", inter).as_str());
+    /// Renders a page for every type in the project into the package directories
+    /// under `dir` and returns the packages of the project with the overviews of their
+    /// types and the descriptions from their `package-info.java` files
+    ///
+    /// # Arguments
+    ///
+    /// * `proj` - The project containing the java documentation data
+    /// * `templates` - The templates of the output format
+    /// * `dir` - The root directory of the pages
+    /// * `options` - The options passed to the application
+    fn write_type_pages(
+        proj: Project,
+        templates: &Templates,
+        dir: &str,
+        options: &Options,
+    ) -> Result<ApplicationDoc, LojidocError> {
+        let mut app_doc = ApplicationDoc::new();
+        let usages = UsageIndex::new(&proj);
+        let weights = type_weights(&proj);
+        let package_docs = proj.package_docs.clone();
+        let ext = templates.extension;
+
+        for decl in proj.types {
+            let mut doc = templates.render_type(&decl, usages.get(&decl.package_name, &decl.name))?;
+
+            if templates.site != SiteProfile::Plain {
+                let key = format!("{}.{}", decl.package_name.trim(), decl.name.trim());
+                let weight = *weights.get(&key).unwrap_or(&0);
+                doc = format!("{}{}", front_matter(&templates.site, &decl, weight), doc);
             }
-            doc.push_str("\n");
-        }
 
-        doc.push_str(format!("package: {}  \n\n", class.package_name.trim()).as_str());
+            let package_dir = format!("{}/{}", dir, decl.package_name.replace(".", "/").clone());
+            fs::create_dir_all(package_dir.clone())?;
+            let mut file = File::create(format!("{}/{}.{}", package_dir, decl.name, ext))?;
+            file.write_all(doc.as_str().as_bytes())?;
 
-        if class.exceptions.len() > 0 {
-            for exception in class.exceptions {
-                doc.push_str(
-                    format!(
-                        "Throws {}: {}  \n\n",
-                        exception.exception_type, exception.desc
-                    ).as_str(),
-                );
+            if options.book && options.backend == Backend::Markdown {
+                let book_dir = format!("{}/src/{}", options.book_root(), package_path("", &decl.package_name));
+                fs::create_dir_all(book_dir.clone())?;
+                write_if_changed(&format!("{}{}.{}", book_dir, decl.name, ext), &doc)?;
             }
-            doc.push_str("\n");
-        }
 
-        doc.push_str("## Dependencies\n\n");
-        doc.push_str("<details>  \n");
-        doc.push_str("  <summary>  \n");
-        doc.push_str("    Show dependencies  \n");
-        doc.push_str("  </summary>  \n");
+            let summary = TypeSummary::new(&decl);
+            app_doc.add_package_class(decl.package_name.clone(), package_dir, decl.name.clone());
+            app_doc.add_type_summary(&decl.package_name, summary);
+        }
 
-        doc.push_str("  <ul>  \n");
-        for dep in class.dependencies {
-            doc.push_str(format!("<li>{}</li>\n", dep).as_str());
+        for p in app_doc.packages.iter_mut() {
+            if let Some(description) = package_docs.get(p.name.trim()) {
+                p.ch_description(description.clone());
+            }
         }
-        doc.push_str("  </ul>  \n");
-        doc.push_str("</details>  \n\n");
 
-        doc
+        Ok(app_doc)
     }
 
-    /// Generates the markdown documentation for an interface
+    /// Renders the overview page of every package into its directory. With
+    /// `options.book` the markdown pages are also the package chapters of the book.
     ///
     /// # Arguments
     ///
-    /// * `inter` - The interface struct containing the javadoc data
-    pub fn gen_interface_docs(inter: Interface) -> String {
-        let mut doc = String::new();
-
-        if inter.file_path != "" {
-            doc.push_str(
-                format!(
-                    "# Interface {} [[src]]({})  \n\n",
-                    inter.name, inter.file_path
-                ).as_str(),
-            );
-            doc.push_str(format!(" > {}  \n\n", inter.signature.trim()).as_str());
-        } else {
-            doc.push_str(format!("# Interface {}\n\n", inter.name).as_str());
-        }
-
-        if inter.description.as_str() != "" {
-            doc.push_str(format!("description: {}  \n", inter.description.trim()).as_str());
-        }
-        doc.push_str(format!("privacy: {}  \n", inter.access.trim()).as_str());
-        doc.push_str(format!("package: {}  \n\n", inter.package_name.trim()).as_str());
-        doc.push_str("## Dependencies\n\n");
-        doc.push_str("<details>  \n");
-        doc.push_str("  <summary>  \n");
-        doc.push_str("    Show dependencies  \n");
-        doc.push_str("  </summary>  \n");
-
-        doc.push_str("  <ul>  \n");
-        for dep in inter.dependencies {
-            doc.push_str(format!("    <li>{}</li>\n", dep).as_str());
+    /// * `app` - The packages and types of the documented project
+    /// * `templates` - The templates of the output format
+    /// * `options` - The options passed to the application
+    fn write_package_pages(
+        app: &ApplicationDoc,
+        templates: &Templates,
+        options: &Options,
+    ) -> Result<(), LojidocError> {
+        let ext = templates.extension;
+
+        for p in app.packages.iter() {
+            let doc = templates.render_package(p)?;
+            let mut file = File::create(format!("{}/{}.{}", p.package_path, PACKAGE_PAGE, ext))?;
+            file.write_all(doc.as_bytes())?;
+
+            if options.book && options.backend == Backend::Markdown {
+                let book_dir = format!("{}/src/{}", options.book_root(), package_path("", &p.name));
+                fs::create_dir_all(book_dir.clone())?;
+                write_if_changed(&format!("{}{}.{}", book_dir, PACKAGE_PAGE, ext), &doc)?;
+            }
         }
-        doc.push_str("  </ul>  \n");
-        doc.push_str("</details>  \n\n");
 
-        doc
+        Ok(())
     }
 
-    /// Generates the markdown documentation for a enumeration
+    /// Generates a markdown file for every type in the project, a `package-summary.md`
+    /// overview page for every package, a `Contents.md` file listing the packages of the
+    /// project, an `Index.md` file listing every symbol alphabetically, a `Deprecated.md`
+    /// report of the deprecated API and a `New-in-{version}.md` page for every version of
+    /// the `@since` tags, listed in `Versions.md`. With the git history a
+    /// `Recently-Changed.md` page lists the types changed in the latest commits. The
+    /// pages are rendered from the templates in `options.template_dir`, or the default
+    /// layout. With a site profile the pages get front matter and the generator's
+    /// navigation files are written next to them. With `options.diagrams` the overview
+    /// page of every package shows its Mermaid class diagram and a `classes.puml` file is
+    /// written into every package directory. With `options.book` the search index of the
+    /// book is written next to its pages.
     ///
     /// # Arguments
     ///
-    /// * `class` - The class struct containing the javadoc data
-    pub fn gen_enum_docs(enum_ob: Enumeration) -> String {
-        let mut doc = String::new();
+    /// * `proj` - The project containing the java documentation data
+    /// * `options` - The options containing the destination directory and filters
+    pub fn generate_markdown(proj: Project, options: Options) -> Result<(), LojidocError> {
+        let mut templates = Templates::new(&Backend::Markdown, options.template_dir.as_str())?;
+        templates.ch_site(options.site.clone());
 
-        if enum_ob.file_path != "" {
-            doc.push_str(
-                format!(
-                    "# Class {} [[src]]({})  \n\n",
-                    enum_ob.name, enum_ob.file_path
-                ).as_str(),
-            );
-        } else {
-            doc.push_str(format!("# Class {}\n\n", enum_ob.name).as_str());
+        if options.clean {
+            remove_old_docs(options.dest.as_str())?;
         }
 
-        if enum_ob.license != "" {
-            doc.push_str("<details>  \n");
-            doc.push_str("  <summary>  \n");
-            doc.push_str("    Show license  \n\n");
-            doc.push_str("  </summary>  \n");
-
-            doc.push_str("  <ul>  \n");
-            doc.push_str(enum_ob.license.as_str());
-            doc.push_str("  </ul>  \n");
-            doc.push_str("</details>  \n\n");
-            doc.push_str("<br/>");
+        let diagrams = if options.diagrams {
+            gen_package_diagrams(&proj)
+        } else {
+            BTreeMap::new()
+        };
+        let search = if options.book {
+            build_index(&proj, &|decl, anchor| {
+                let page = format!("{}{}.html", package_path("", &decl.package_name), decl.name.trim());
+                if anchor == "" {
+                    page
+                } else {
+                    format!("{}#{}", page, anchor)
+                }
+            })
+        } else {
+            Vec::new()
+        };
+        let site = options.site.clone();
+        let link = |decl: &TypeDecl, anchor: &str| {
+            let page = page_link(&site, "", &decl.package_name, decl.name.trim(), "md");
+            if anchor == "" {
+                page
+            } else {
+                format!("{}#{}", page, anchor)
+            }
+        };
+        let versions = project_versions(&proj);
+        let new_api = find_new_api(&proj, &link);
+        let mut reports = vec![
+            (String::from("Index.md"), gen_index_page(&build_symbol_index(&proj, &link))),
+            (String::from("Deprecated.md"), gen_deprecated_report(&find_deprecated(&proj, &link))),
+            (String::from("Versions.md"), gen_versions_page(&versions, &new_api)),
+        ];
+        for version in versions.iter() {
+            reports.push((format!("{}.md", version_page(version)), gen_version_page(version, &new_api)));
+        }
+        let recent = proj.recent_commits.len() > 0;
+        if recent {
+            reports.push((
+                String::from("Recently-Changed.md"),
+                gen_recent_page(&proj, &find_recent_changes(&proj, &link)),
+            ));
+        }
+        let mut app_doc = write_type_pages(proj, &templates, options.dest.as_str(), &options)?;
+
+        for p in app_doc.packages.iter_mut() {
+            if let Some(diagram) = diagrams.get(p.name.trim()) {
+                let mut file = File::create(format!("{}/classes.puml", p.package_path))?;
+                file.write_all(diagram.plantuml.as_bytes())?;
+                p.ch_diagram(diagram.mermaid.clone());
+            }
         }
 
-        doc.push_str(format!("Access: {}  \n", enum_ob.access.trim()).as_str());
-        if enum_ob.description.as_str() != "" {
-            doc.push_str(
-                format!("Description:  \n > {}  \n\n", enum_ob.description.trim()).as_str(),
-            );
-        }
-        if enum_ob.author != "" {
-            doc.push_str(format!("Author: {}  \n", enum_ob.author).as_str());
+        write_package_pages(&app_doc, &templates, &options)?;
+
+        let contents = templates.render_contents(&app_doc)?;
+        let mut app_file = File::create(format!("{}/{}", options.dest, contents_file(&options.site)))?;
+        app_file.write_all(format!("{}{}", contents_front_matter(&options.site), contents).as_bytes())?;
+        for &(ref name, ref report) in reports.iter() {
+            let page = format!("{}{}", report_front_matter(&options.site, report), report);
+            File::create(format!("{}/{}", options.dest, name))?.write_all(page.as_bytes())?;
         }
-        if enum_ob.version != "" {
-            doc.push_str(format!("Since version: {}  \n", enum_ob.version).as_str());
+        write_nav(&options.site, &app_doc, options.dest.as_str())?;
+        if options.book {
+            reports.push((String::from("Contents.md"), contents));
+            write_book_src(&app_doc, &reports, &versions, recent, &options.book_root())?;
+            write_search_index(&search, options.book_root().as_str())?;
         }
 
-        if enum_ob.interfaces.len() > 0 {
-            doc.push_str("Interfaces:  \n");
+        Ok(())
+    }
 
-            for inter in enum_ob.interfaces {
-                doc.push_str(format!("- {}  \n", inter).as_str());
+    /// Generates the `SUMMARY.md` of the markdown book. The contents page is the
+    /// landing page of the book, every package is a chapter containing its types and
+    /// the last chapter links to the pages of the versions. The recently changed API,
+    /// the deprecated API report and the index are the last pages.
+    ///
+    /// # Arguments
+    ///
+    /// * `app` - The packages and types of the documented project
+    /// * `versions` - The versions of the project from the newest to the oldest
+    /// * `recent` - Whether the book has the page of the recently changed API
+    pub fn gen_book_summary(app: &ApplicationDoc, versions: &Vec<String>, recent: bool) -> String {
+        let mut packages = app.packages.clone();
+        let mut doc = String::from("# Summary\n\n[Application Contents](Contents.md)\n\n");
+
+        packages.sort_by(|a, b| a.name.trim().cmp(b.name.trim()));
+        for p in packages.iter_mut() {
+            let path = package_path("", &p.name);
+
+            p.members.sort();
+            doc.push_str(format!("- [{}]({}{}.md)\n", p.name.trim(), path, PACKAGE_PAGE).as_str());
+            for name in p.members.iter() {
+                doc.push_str(format!("    - [{}]({}{}.md)\n", name, path, name).as_str());
             }
-            doc.push_str("\n");
         }
-
-        doc.push_str(format!("package: {}  \n\n", enum_ob.package_name.trim()).as_str());
-
-        doc.push_str("## Dependencies\n\n");
-        doc.push_str("<details>  \n");
-        doc.push_str("  <summary>  \n");
-        doc.push_str("    Show dependencies  \n");
-        doc.push_str("  </summary>  \n");
-
-        doc.push_str("  <ul>  \n");
-        for dep in enum_ob.dependencies {
-            doc.push_str(format!("<li>{}</li>\n", dep).as_str());
+        if versions.len() > 0 {
+            doc.push_str("- [API by Version](Versions.md)\n");
+            for version in versions.iter() {
+                doc.push_str(format!("    - [New in {}]({}.md)\n", version, version_page(version)).as_str());
+            }
+        }
+        doc.push_str("\n");
+        if recent {
+            doc.push_str("[Recently Changed API](Recently-Changed.md)\n");
         }
-        doc.push_str("  </ul>  \n");
-        doc.push_str("</details>  \n\n");
+        doc.push_str("[Deprecated API](Deprecated.md)\n[Index](Index.md)\n");
 
         doc
     }
 
-    /// Generates the markdown documentation for the member variables of a class
+    /// Writes the landing page, reports and summary of the markdown book next to the
+    /// type and package pages in the book's source directory
     ///
     /// # Arguments
     ///
-    /// * `variables` - The vector of class methods to be documented
-    /// * `ignore` - Variables with this permission will be skipped, if not empty
-    pub fn gen_var_docs(variables: Vec<Member>, path: String, ignore: String) -> String {
-        let mut doc = String::new();
+    /// * `app` - The packages and types of the documented project
+    /// * `pages` - The file names and contents of the landing page and the reports
+    /// * `versions` - The versions of the project from the newest to the oldest
+    /// * `recent` - Whether the book has the page of the recently changed API
+    /// * `root` - The root directory of the book
+    fn write_book_src(
+        app: &ApplicationDoc,
+        pages: &Vec<(String, String)>,
+        versions: &Vec<String>,
+        recent: bool,
+        root: &str,
+    ) -> io::Result<()> {
+        let src = format!("{}/src", root);
+
+        fs::create_dir_all(src.clone())?;
+        for &(ref name, ref page) in pages.iter() {
+            write_if_changed(&format!("{}/{}", src, name), page)?;
+        }
+        write_if_changed(&format!("{}/SUMMARY.md", src), &gen_book_summary(app, versions, recent))?;
+
+        Ok(())
+    }
 
-        if variables.len() > 0 {
-            doc.push_str("## Member Variables\n\n");
-        } else {
-            doc.push_str("## No member variables in this class\n\n");
-
-            return doc;
-        }
-
-        for member in variables {
-            if member.access != ignore {
-                if path != "" {
-                    let mut file_path = path.clone();
-                    file_path.push_str(format!("#L{}", member.line_num).as_str());
-                    doc.push_str(
-                        format!(
-                            "#### {} {} [[src]]({})\n\n",
-                            member.var_type, member.name, file_path
-                        ).as_str(),
-                    );
-                    doc.push_str(format!(" > {}  \n\n", member.signature.trim()).as_str());
+    /// Moves every markdown heading of a page one level down. Only ATX headings, one to six
+    /// `#` followed by a space, are moved, and lines in fenced code blocks are left as they are.
+    pub fn demote_headings(doc: &str) -> String {
+        let mut fence: Option<&str> = None;
+
+        doc.lines()
+            .map(|line| {
+                let trimmed = line.trim_start();
+                let marker = if trimmed.starts_with("```") {
+                    Some("```")
+                } else if trimmed.starts_with("~~~") {
+                    Some("~~~")
                 } else {
-                    doc.push_str(format!("#### {} {}\n\n", member.var_type, member.name).as_str());
-                }
+                    None
+                };
 
-                if member.desc != "" {
-                    doc.push_str(format!("+ Description: {}  \n", member.desc).as_str());
+                match (fence, marker) {
+                    (None, Some(marker)) => fence = Some(marker),
+                    (Some(open), Some(marker)) if open == marker => fence = None,
+                    _ => (),
                 }
 
-                if member.access == "" {
-                    doc.push_str("+ Access: package-private  \n");
+                let level = line.chars().take_while(|c| *c == '#').count();
+                let heading = level > 0 && level < 7 && (line.len() == level || line[level..].starts_with(' '));
+                if fence.is_none() && marker.is_none() && heading {
+                    format!("#{}\n", line)
                 } else {
-                    doc.push_str(format!("+ Access: {}  \n", member.access).as_str());
-                }
-
-                if member.modifiers.len() > 0 {
-                    doc.push_str("+ Modifiers: ");
-
-                    for mem in member.modifiers {
-                        doc.push_str(format!("{} ", mem).as_str())
-                    }
-
-                    doc.push_str("\n");
+                    format!("{}\n", line)
                 }
-
-                doc.push_str("\n");
-            }
-        }
-
-        doc
+            }).collect()
     }
 
-    /// Generates the markdown documentation for the methods of a class
+    /// Generates the whole API reference as a single `API.md` file. The file starts
+    /// with a table of contents, every package is a top-level heading containing the
+    /// sections of its types and every reference to a type links to its section.
     ///
     /// # Arguments
     ///
-    /// * `methods` - The vector of class methods to be documented
-    /// * `ignore` - Methods with this permission will be skipped, if not empty
-    pub fn gen_method_docs(methods: Vec<Method>, path: String, ignore: String) -> String {
-        let mut doc = String::new();
-
-        if methods.len() > 0 {
-            doc.push_str("## Methods\n\n");
-        } else {
-            doc.push_str("## No methods in this class\n\n");
-
-            return doc;
-        }
-
-        for member in methods {
-            if member.privacy != ignore {
-                if member.name != String::from("") {
-                    if path != "" {
-                        let mut file_path = path.clone();
-                        file_path.push_str(format!("#L{}", member.line_num).as_str());
-                        doc.push_str(
-                            format!("### {} [[src]]({})\n\n", member.name, file_path).as_str(),
-                        );
-                    } else {
-                        doc.push_str(format!("### {}\n\n", member.name).as_str());
-                    }
-
-                    doc.push_str(format!("+ Description: {}  \n", member.description).as_str());
-
-                    if member.privacy == "" {
-                        doc.push_str("+ Access: package-private  \n");
-                    } else {
-                        doc.push_str(format!("+ Access: {}  \n", member.privacy).as_str());
-                    }
+    /// * `proj` - The project containing the java documentation data
+    /// * `options` - The options containing the destination directory and filters
+    pub fn generate_single_markdown(proj: Project, options: Options) -> Result<(), LojidocError> {
+        let mut templates = Templates::new(&Backend::Markdown, options.template_dir.as_str())?;
+        templates.ch_single_file(true);
 
-                    if member.modifiers.len() > 0 {
-                        doc.push_str("+ Modifiers: ");
-
-                        for mem in member.modifiers {
-                            doc.push_str(format!("{} ", mem).as_str())
-                        }
-
-                        doc.push_str("\n");
-                    }
-
-                    for exception in member.exceptions {
-                        doc.push_str(
-                            format!(
-                                "+ Throws {}: {}  \n",
-                                exception.exception_type, exception.desc
-                            ).as_str(),
-                        );
-                    }
-                    doc.push_str(format!("+ return: {}  \n\n", member.return_type).as_str());
-
-                    if member.parameters.len() > 0 {
-                        doc.push_str("| Name | Type | Description |  \n");
-                        doc.push_str("| ----- | ----- | ----- |  \n");
-                    } else {
-                        doc.push_str("This method has no parameters.  \n");
-                    }
-
-                    for param in member.parameters {
-                        doc.push_str(
-                            format!(
-                                "| {} | {} | {} |  \n",
-                                param.name, param.var_type, param.desc
-                            ).as_str(),
-                        );
-                    }
+        if options.clean {
+            remove_old_docs(options.dest.as_str())?;
+        }
 
-                    doc.push_str("\n\n");
-                }
-            }
+        let usages = UsageIndex::new(&proj);
+        let mut packages: BTreeMap<String, Vec<&TypeDecl>> = BTreeMap::new();
+        for decl in proj.types.iter() {
+            let types = packages.entry(decl.package_name.trim().to_string()).or_insert(Vec::new());
+            types.push(decl);
         }
 
-        doc
-    }
+        let mut toc = String::from("# API Reference\n\n");
+        let mut body = String::new();
 
-    /// Generates the markdown documentation for the methods of a class
-    ///
-    /// # Arguments
-    ///
-    /// * `methods` - The vector of class methods to be documented
-    pub fn gen_application_doc(app: ApplicationDoc, dest: &str) -> String {
-        let mut doc = String::from("# Application Contents\n\n");
+        for (p, types) in packages.iter_mut() {
+            types.sort_by(|a, b| a.name.cmp(&b.name));
+            toc.push_str(format!("- [{}](#package.{})\n", p, p).as_str());
+            body.push_str(format!("<a id=\"package.{}\"></a>\n\n# Package {}\n\n", p, p).as_str());
 
-        for p in app.packages {
-            doc.push_str(format!("## {}\n", p.name).as_str());
-            for class in p.members {
-                doc.push_str(format!("- [{}](.{}/{}.md)\n", class, p.package_path.split(dest).collect::<Vec<&str>>().join(""), class).as_str());
-            }
+            for decl in types.iter() {
+                let anchor = qualified_name(p, &decl.name);
+                let doc = templates.render_type(decl, usages.get(&decl.package_name, &decl.name))?;
 
-            doc.push_str("\n\n");
+                toc.push_str(
+                    format!("  - [{} {}](#{})\n", decl.kind.title(), decl.name, anchor).as_str(),
+                );
+                body.push_str(format!("<a id=\"{}\"></a>\n\n", anchor).as_str());
+                body.push_str(demote_headings(&doc).as_str());
+            }
         }
+        toc.push_str("\n");
 
-        doc
-    }
+        fs::create_dir_all(options.dest.as_str())?;
+        let mut file = File::create(format!("{}/API.md", options.dest))?;
+        file.write_all(format!("{}{}", toc, body).as_bytes())?;
 
-    macro_rules! remove_old_md {
-        ($d:ident) => {
-            if find_file_type(Path::new($d), vec!["java", "class"]).len() > 0 {
-                panic!("Java files found exiting");
-            } else {
-                fs::remove_dir_all($d).expect("Could not delete files");
-            }
-        };
+        Ok(())
     }
 
-    /// Generates a markdown file for a java file
-    /// Uses a Class struct to write the markdown
+    /// Generates the documentation as an Antora module. The `.adoc` page of every type,
+    /// the `package-summary.adoc` overview page of every package and the `index.adoc`
+    /// contents page are written to `{dest}/pages` and the
+    /// navigation of the packages to `{dest}/nav.adoc`.
     ///
     /// # Arguments
     ///
-    /// * `class` - The class struct containing the java documentation data
-    /// * `dest` - The file path where the markdown file will be saved
-    /// * `ignore` - Permission to ignore when parsing member variables and methods
-    /// * `context` - The project context e.g. `github.com/user/repo`
-    pub fn generate_markdown(proj: Project, options: Options) {
-        println!("{}", options.dest);
-        let mut app_doc = ApplicationDoc::new();
+    /// * `proj` - The project containing the java documentation data
+    /// * `options` - The options containing the destination directory and filters
+    pub fn generate_asciidoc(proj: Project, options: Options) -> Result<(), LojidocError> {
+        let templates = Templates::new(&Backend::Asciidoc, options.template_dir.as_str())?;
+        let pages = format!("{}/pages", options.dest);
 
         if options.clean {
-            let dest = options.dest.as_str();
-            remove_old_md!(dest);
-        }
-
-        for mut class in proj.classes {
-            let mut doc = gen_class_docs(class.clone());
-
-            doc.push_str(
-                gen_var_docs(class.variables, class.file_path.clone(), options.ignore.clone()).as_str(),
-            );
-            doc.push_str(gen_method_docs(class.methods, class.file_path, options.ignore.clone()).as_str());
-
-            let dir = format!("{}/{}", options.dest, class.package_name.replace(".", "/").clone());
-            fs::create_dir_all(dir.clone()).expect("File path not able to be created");
-            let mut file = File::create(format!("{}/{}.{}", dir, class.name, "md"))
-                .expect("Unable to create file for Class documentation");
-            file.write(doc.as_str().as_bytes())
-                .expect("Not able to write to file");
-
-            if options.book {
-                let name = format!("{}/markdown-book/src/{}.{}", options.dest, class.name, "md");
-                fs::create_dir_all(format!("{}/markdown-book/src/", options.dest))
-                    .expect("File path not able to be created");
-                let mut file = File::create(name).unwrap();
-
-                file.write(doc.as_str().as_bytes())
-                    .expect("Not able to write to file");
-            }
-
-            app_doc.add_package_class(class.package_name, dir, class.name.clone());
-
-            println!("{}.{} was created", class.name, "md");
-        }
-
-        for mut inter in proj.interfaces {
-            let mut doc = gen_interface_docs(inter.clone());
-
-            doc.push_str(
-                gen_var_docs(inter.variables, inter.file_path.clone(), options.ignore.clone()).as_str(),
-            );
-            doc.push_str(gen_method_docs(inter.methods, inter.file_path, options.ignore.clone()).as_str());
-
-            let dir = format!("{}/{}", options.dest, inter.package_name.replace(".", "/").clone());
-            fs::create_dir_all(dir.clone()).expect("File path not able to be created");
-            let mut file = File::create(format!("{}/{}.{}", dir, inter.name, "md"))
-                .expect("Unable to create file for Interface documentation");
-            file.write(doc.as_str().as_bytes())
-                .expect("Not able to write to file");
-
-            app_doc.add_package_class(inter.package_name, dir, inter.name.clone());
-
-            println!("{}.{} was created", inter.name, "md");
+            remove_old_docs(options.dest.as_str())?;
         }
 
-        for mut enumeration in proj.enumerations {
-            let mut doc = gen_enum_docs(enumeration.clone());
-
-            doc.push_str(
-                gen_var_docs(
-                    enumeration.variables,
-                    enumeration.file_path.clone(),
-                    options.ignore.clone(),
-                ).as_str(),
-            );
-            doc.push_str(
-                gen_method_docs(enumeration.methods, enumeration.file_path, options.ignore.clone())
-                    .as_str(),
-            );
-
-            let dir = format!(
-                "{}/{}",
-                options.dest,
-                enumeration.package_name.replace(".", "/").clone()
-            );
-            fs::create_dir_all(dir.clone()).expect("File path not able to be created");
-            let mut file = File::create(format!("{}/{}.{}", dir, enumeration.name, "md"))
-                .expect("Not able to create enumeration file");
-            file.write(doc.as_str().as_bytes())
-                .expect("Not able to write to file");
-
-            app_doc.add_package_class(enumeration.package_name, dir, enumeration.name.clone());
+        let app_doc = write_type_pages(proj, &templates, pages.as_str(), &options)?;
+        write_package_pages(&app_doc, &templates, &options)?;
 
-            println!("{}.{} was created", enumeration.name, "md");
-        }
+        let mut index_file = File::create(format!("{}/index.adoc", pages))?;
+        index_file.write_all(templates.render_contents(&app_doc)?.as_bytes())?;
+        let mut nav_file = File::create(format!("{}/nav.adoc", options.dest))?;
+        nav_file.write_all(templates.render_nav(&app_doc)?.as_bytes())?;
 
-        let mut app_file = File::create(format!("{}/Contents.md", options.dest))
-            .expect("Unable to create file for application contents");
-        app_file
-            .write(gen_application_doc(app_doc, options.dest.as_str()).as_str().as_bytes())
-            .expect("Not able to write to file");
+        Ok(())
     }
 
-    pub fn lint_method(method: &Method) -> String {
+    /// Lints the javadoc of a single method
+    ///
+    /// # Arguments
+    ///
+    /// * `decl` - The type declaring the method
+    /// * `method` - The method to lint
+    /// * `rules` - The checks to run
+    pub fn lint_method(decl: &TypeDecl, method: &Method, rules: &LintRules) -> String {
         let mut method_errs = String::new();
 
-        if method.description == "" {
+        if rules.method_description && method.description == "" {
             method_errs.push_str(
                 "\tMissing description for method "
                     .yellow()
@@ -586,7 +569,9 @@ pub mod document {
             );
             method_errs.push_str(format!("{} (Line: {})\n", method.name, method.line_num).as_str());
         }
-        if method.return_type == "" {
+        // Constructors have no return type
+        let constructor = method.name == decl.simple_name();
+        if rules.method_return && !constructor && method.return_type == "" && method.return_desc == "" {
             method_errs.push_str(
                 "\tMissing return type for method "
                     .yellow()
@@ -597,7 +582,7 @@ pub mod document {
         }
 
         for p in method.parameters.clone() {
-            if p.desc == "" {
+            if rules.param_description && p.desc == "" {
                 method_errs.push_str(
                     "\tJavadoc parameter not found "
                         .yellow()
@@ -615,10 +600,16 @@ pub mod document {
 
         method_errs
     }
-    pub fn lint_var(var: &Member) -> String {
+    /// Lints the javadoc of a single member variable
+    ///
+    /// # Arguments
+    ///
+    /// * `var` - The member variable to lint
+    /// * `rules` - The checks to run
+    pub fn lint_var(var: &Member, rules: &LintRules) -> String {
         let mut errs = String::new();
 
-        if var.desc == "" {
+        if rules.var_description && var.desc == "" {
             errs.push_str(
                 "\tMissing description for member variable "
                     .yellow()
@@ -627,7 +618,7 @@ pub mod document {
             );
             errs.push_str(format!("{} (Line: {})\n", var.name, var.line_num).as_str());
         }
-        if var.var_type == "" {
+        if rules.var_type && var.var_type == "" {
             errs.push_str(
                 "\tMissing return type for member variable "
                     .yellow()
@@ -640,102 +631,60 @@ pub mod document {
         errs
     }
 
-    /// Lints the java project's javadoc comments and prints the errors
-    ///
-    /// # Arguments
-    ///
-    /// * `proj` - The project to lint
-    pub fn lint_project(proj: Project) -> String {
-        let mut jdoc_errs = String::new();
-
-        for class in proj.classes {
-            let mut temp_err = String::new();
-
-            for v in class.variables {
-                temp_err.push_str(lint_var(&v).as_str());
-            }
-            for m in class.methods {
-                temp_err.push_str(lint_method(&m).as_str());
-            }
+    /// Visitor collecting the javadoc errors of every type declaration in a project
+    struct Linter<'a> {
+        rules: &'a LintRules,
+        type_errs: String,
+        jdoc_errs: String,
+    }
 
-            if temp_err != "" {
-                jdoc_errs.push_str(
-                    "Javadoc errors for class "
-                        .green()
-                        .bold()
-                        .to_string()
-                        .as_str(),
-                );
-                jdoc_errs.push_str(
-                    format!(
-                        "{}\nFile: {}\n",
-                        class.name,
-                        class.file_path.as_str().blue().to_string()
-                    ).as_str(),
-                );
-                jdoc_errs.push_str(format!("{}\n", temp_err).as_str());
-            }
+    impl<'a> Visitor for Linter<'a> {
+        fn visit_member(&mut self, _decl: &TypeDecl, member: &Member) {
+            self.type_errs.push_str(lint_var(member, self.rules).as_str());
         }
 
-        for mut inter in proj.interfaces {
-            let mut temp_err = String::new();
-
-            for v in inter.variables {
-                temp_err.push_str(lint_var(&v).as_str());
-            }
-            for m in inter.methods {
-                temp_err.push_str(lint_method(&m).as_str());
-            }
+        fn visit_method(&mut self, decl: &TypeDecl, method: &Method) {
+            self.type_errs.push_str(lint_method(decl, method, self.rules).as_str());
+        }
 
-            if temp_err != "" {
-                jdoc_errs.push_str(
-                    "Javadoc errors for interface "
+        fn leave_type(&mut self, decl: &TypeDecl) {
+            if self.type_errs != "" {
+                self.jdoc_errs.push_str(
+                    format!("Javadoc errors for {} ", decl.kind.label())
                         .green()
                         .bold()
                         .to_string()
                         .as_str(),
                 );
-                jdoc_errs.push_str(
+                self.jdoc_errs.push_str(
                     format!(
                         "{}\nFile: {}\n",
-                        inter.name,
-                        inter.file_path.as_str().blue().to_string()
+                        decl.name,
+                        decl.file_path.as_str().blue().to_string()
                     ).as_str(),
                 );
-                jdoc_errs.push_str(format!("{}\n", temp_err).as_str());
+                self.jdoc_errs.push_str(format!("{}\n", self.type_errs).as_str());
+                self.type_errs.clear();
             }
         }
+    }
 
-        for mut enum_ob in proj.enumerations {
-            let mut temp_err = String::new();
-
-            for v in enum_ob.variables {
-                temp_err.push_str(lint_var(&v).as_str());
-            }
-            for m in enum_ob.methods {
-                temp_err.push_str(lint_method(&m).as_str());
-            }
+    /// Lints the java project's javadoc comments and prints the errors
+    ///
+    /// # Arguments
+    ///
+    /// * `proj` - The project to lint
+    /// * `rules` - The checks to run
+    pub fn lint_project(proj: Project, rules: &LintRules) -> String {
+        let mut linter = Linter {
+            rules: rules,
+            type_errs: String::new(),
+            jdoc_errs: String::new(),
+        };
 
-            if temp_err != "" {
-                jdoc_errs.push_str(
-                    "Javadoc errors for enum "
-                        .green()
-                        .bold()
-                        .to_string()
-                        .as_str(),
-                );
-                jdoc_errs.push_str(
-                    format!(
-                        "{}\nFile: {}\n",
-                        enum_ob.name,
-                        enum_ob.file_path.as_str().blue().to_string()
-                    ).as_str(),
-                );
-                jdoc_errs.push_str(format!("{}\n", temp_err).as_str());
-            }
-        }
+        walk_project(&mut linter, &proj);
 
-        jdoc_errs
+        linter.jdoc_errs
     }
 
     /// Determines whether a file path contains a git or mercurial file
@@ -860,35 +809,22 @@ pub mod document {
         new_context
     }
 
-    /// Creates a markdown book using mdbook. Uses the files in the generated documentation
-    /// directory for chapters.
+    /// Builds the markdown book from the pages and summary written into its source
+    /// directory by `generate_markdown`. The `book.toml` is created, or updated with the
+    /// configured settings when the book already exists.
     ///
     /// # Arguments
     ///
-    /// * `gen_dir` - The directory containing the generated documentation
-    pub fn gen_md_book(gen_dir: String) {
-        let name = "./markdown-book/src/SUMMARY.md";
-        let res_file = File::create(name);
-        let files = find_gen_files(&PathBuf::from(gen_dir.as_str()));
-        let mut doc = String::new();
-
-        if res_file.is_ok() {
-            let mut file = res_file.unwrap();
+    /// * `options` - The options containing the book's location and settings
+    pub fn gen_md_book(options: &Options) -> Result<(), LojidocError> {
+        write_book_toml(options)?;
 
-            for f in files {
-                let file_path = format!("./{}.md", f.clone());
-                doc.push_str(format!("- [{}]({})  \n", f, file_path).as_str());
-            }
-
-            file.write(doc.as_str().as_bytes())
-                .expect("Not able to write to file");
-
-            let md = MDBook::load("./markdown-book").expect("Unable to load the book");
-            md.build().expect("Building failed");
+        let md = MDBook::load(options.book_root()).map_err(|err| LojidocError::Book(err.to_string()))?;
+        md.build().map_err(|err| LojidocError::Book(err.to_string()))?;
 
-            println!("Generated the markdown book");
-        } else {
-            println!("Error creating file: {:?}", res_file);
-        }
+        Ok(())
     }
 }
+
+#[cfg(test)]
+mod test;
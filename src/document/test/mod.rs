@@ -0,0 +1,42 @@
+use document::document::*;
+use model::model::ApplicationDoc;
+
+#[test]
+fn test_gen_book_summary() {
+    let mut app = ApplicationDoc::new();
+    app.add_package_class("com.shop.service".to_string(), String::new(), "OrderService".to_string());
+    app.add_package_class("com.shop.model".to_string(), String::new(), "Status".to_string());
+    app.add_package_class("com.shop.model".to_string(), String::new(), "Order".to_string());
+
+    assert_eq!(
+        gen_book_summary(&app, &Vec::new(), false),
+        "# Summary\n\n[Application Contents](Contents.md)\n\n\
+         - [com.shop.model](com/shop/model/package-summary.md)\n    \
+         - [Order](com/shop/model/Order.md)\n    \
+         - [Status](com/shop/model/Status.md)\n\
+         - [com.shop.service](com/shop/service/package-summary.md)\n    \
+         - [OrderService](com/shop/service/OrderService.md)\n\n\
+         [Deprecated API](Deprecated.md)\n\
+         [Index](Index.md)\n"
+    );
+
+    let summary = gen_book_summary(&app, &vec!["3.10".to_string(), "3.2".to_string()], true);
+
+    assert!(summary.contains(
+        "- [OrderService](com/shop/service/OrderService.md)\n\
+         - [API by Version](Versions.md)\n    \
+         - [New in 3.10](New-in-3.10.md)\n    \
+         - [New in 3.2](New-in-3.2.md)\n\n\
+         [Recently Changed API](Recently-Changed.md)\n"
+    ));
+}
+
+#[test]
+fn test_demote_headings() {
+    let page = "# Class Order\n## Methods\n#hashtag in a description\n```java\n# not a heading\n```\n#\n";
+
+    assert_eq!(
+        demote_headings(page),
+        "## Class Order\n### Methods\n#hashtag in a description\n```java\n# not a heading\n```\n##\n"
+    );
+}
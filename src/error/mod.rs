@@ -0,0 +1,97 @@
+pub mod error {
+    //! Module that contains the error type returned by the library functions
+
+    use std::error::Error;
+    use std::fmt;
+    use std::io;
+
+    use git2;
+    use handlebars::RenderError;
+    use handlebars::TemplateError;
+    use handlebars::TemplateFileError;
+    use serde_json;
+    use toml;
+
+    #[derive(Debug)]
+    /// Errors that can occur while parsing a java project or generating its documentation
+    pub enum LojidocError {
+        /// Reading the java sources or writing the generated files failed
+        Io(io::Error),
+        /// The java source could not be parsed
+        Parse(String),
+        /// No java files were found in the input directory
+        NoJavaFiles(String),
+        /// A JSON project document could not be written or read
+        Json(serde_json::Error),
+        /// A documentation template could not be loaded or rendered
+        Template(String),
+        /// The `book.toml` of the markdown book could not be read or written
+        Book(String),
+        /// A revision or its java sources could not be read from the git repository
+        Git(String),
+    }
+
+    impl fmt::Display for LojidocError {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            match self {
+                LojidocError::Io(err) => write!(f, "IO error: {}", err),
+                LojidocError::Parse(msg) => write!(f, "Parse error: {}", msg),
+                LojidocError::NoJavaFiles(dir) => write!(f, "No java files found in {}", dir),
+                LojidocError::Json(err) => write!(f, "JSON error: {}", err),
+                LojidocError::Template(msg) => write!(f, "Template error: {}", msg),
+                LojidocError::Book(msg) => write!(f, "Book error: {}", msg),
+                LojidocError::Git(msg) => write!(f, "Git error: {}", msg),
+            }
+        }
+    }
+
+    impl Error for LojidocError {}
+
+    impl From<io::Error> for LojidocError {
+        fn from(err: io::Error) -> LojidocError {
+            LojidocError::Io(err)
+        }
+    }
+
+    impl From<serde_json::Error> for LojidocError {
+        fn from(err: serde_json::Error) -> LojidocError {
+            LojidocError::Json(err)
+        }
+    }
+
+    impl From<TemplateError> for LojidocError {
+        fn from(err: TemplateError) -> LojidocError {
+            LojidocError::Template(err.to_string())
+        }
+    }
+
+    impl From<TemplateFileError> for LojidocError {
+        fn from(err: TemplateFileError) -> LojidocError {
+            LojidocError::Template(err.to_string())
+        }
+    }
+
+    impl From<RenderError> for LojidocError {
+        fn from(err: RenderError) -> LojidocError {
+            LojidocError::Template(err.to_string())
+        }
+    }
+
+    impl From<toml::de::Error> for LojidocError {
+        fn from(err: toml::de::Error) -> LojidocError {
+            LojidocError::Book(err.to_string())
+        }
+    }
+
+    impl From<toml::ser::Error> for LojidocError {
+        fn from(err: toml::ser::Error) -> LojidocError {
+            LojidocError::Book(err.to_string())
+        }
+    }
+
+    impl From<git2::Error> for LojidocError {
+        fn from(err: git2::Error) -> LojidocError {
+            LojidocError::Git(err.message().to_string())
+        }
+    }
+}
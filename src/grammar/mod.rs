@@ -31,6 +31,19 @@ pub mod grammar {
         ParamEnd,
         LineNumber(String),
         ExpressionEnd(String),
+        /// The closing brace of the body of a class, interface, enum, record or annotation
+        TypeEnd,
+    }
+
+    /// The kinds of blocks the lexer tracks to decide which tokens the parser needs
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum Block {
+        /// The body of a type declaration, whose declarations are lexed
+        Type,
+        /// The body of a method or an initializer, which is skipped
+        Body,
+        /// The braces of an inline javadoc tag like `{@link}`
+        Comment,
     }
 
     /// Gets a full list of all the keywords for the lexer
@@ -152,6 +165,8 @@ pub mod grammar {
         Class,
         Interface,
         Enum,
+        Record,
+        Annotation,
         Other,
     }
 }
@@ -0,0 +1,289 @@
+pub mod graph {
+    //! Module that creates the package dependency graph of a project
+    //!
+    //! A package depends on another package when one of its types imports a type or a
+    //! whole package of the other package, or references one of its types as a parent,
+    //! interface, member variable, parameter, return type or exception. Cyclic
+    //! dependencies are found as the strongly connected components of the graph.
+
+    use std::cmp;
+    use std::collections::BTreeMap;
+    use std::collections::BTreeSet;
+    use std::fs::File;
+    use std::io;
+    use std::io::prelude::*;
+
+    use model::model::Project;
+    use model::model::UsageIndex;
+
+    /// The dependencies between the packages of a project
+    pub struct PackageGraph {
+        pub packages: BTreeSet<String>,
+        /// The number of references for every pair of depending and used package
+        pub edges: BTreeMap<(String, String), usize>,
+    }
+
+    /// State of Tarjan's strongly connected components algorithm
+    struct Tarjan<'a> {
+        graph: &'a PackageGraph,
+        index: usize,
+        indices: BTreeMap<&'a str, usize>,
+        low_links: BTreeMap<&'a str, usize>,
+        stack: Vec<&'a str>,
+        components: Vec<Vec<String>>,
+    }
+
+    impl<'a> Tarjan<'a> {
+        fn connect(&mut self, package: &'a str) {
+            self.indices.insert(package, self.index);
+            self.low_links.insert(package, self.index);
+            self.index += 1;
+            self.stack.push(package);
+
+            for next in self.graph.dependencies(package) {
+                if !self.indices.contains_key(next) {
+                    self.connect(next);
+                    let low = cmp::min(self.low_links[package], self.low_links[next]);
+                    self.low_links.insert(package, low);
+                } else if self.stack.contains(&next) {
+                    let low = cmp::min(self.low_links[package], self.indices[next]);
+                    self.low_links.insert(package, low);
+                }
+            }
+
+            if self.low_links[package] == self.indices[package] {
+                let mut component = Vec::new();
+
+                while let Some(member) = self.stack.pop() {
+                    component.push(member.to_string());
+                    if member == package {
+                        break;
+                    }
+                }
+                component.sort();
+                self.components.push(component);
+            }
+        }
+    }
+
+    impl PackageGraph {
+        /// Creates the package dependency graph of a project
+        ///
+        /// # Arguments
+        ///
+        /// * `proj` - The project containing the java documentation data
+        pub fn new(proj: &Project) -> PackageGraph {
+            let mut graph = PackageGraph {
+                packages: BTreeSet::new(),
+                edges: BTreeMap::new(),
+            };
+
+            for decl in proj.types.iter() {
+                graph.packages.insert(decl.package_name.trim().to_string());
+            }
+
+            for decl in proj.types.iter() {
+                for dep in decl.dependencies.iter() {
+                    let dep = dep.trim();
+                    if dep.ends_with(".*") {
+                        let package = dep[..dep.len() - 2].to_string();
+                        if graph.packages.contains(&package) {
+                            graph.add_edge(decl.package_name.trim(), &package);
+                        }
+                    }
+                }
+            }
+
+            let usages = UsageIndex::new(proj);
+            for (target, target_usages) in usages.usages.iter() {
+                let package = match target.rfind(".") {
+                    Some(index) => &target[..index],
+                    None => "",
+                };
+
+                for usage in target_usages {
+                    graph.add_edge(&usage.package_name, package);
+                }
+            }
+
+            graph
+        }
+
+        fn add_edge(&mut self, from: &str, to: &str) {
+            if from != to {
+                *self.edges.entry((from.to_string(), to.to_string())).or_insert(0) += 1;
+            }
+        }
+
+        /// Gets the packages a package depends on
+        pub fn dependencies(&self, package: &str) -> Vec<&str> {
+            self.edges
+                .keys()
+                .filter(|(from, _)| from == package)
+                .map(|(_, to)| to.as_str())
+                .collect()
+        }
+
+        /// Finds the groups of packages which depend on each other. Every group is a
+        /// strongly connected component with more than one package.
+        pub fn find_cycles(&self) -> Vec<Vec<String>> {
+            let mut tarjan = Tarjan {
+                graph: self,
+                index: 0,
+                indices: BTreeMap::new(),
+                low_links: BTreeMap::new(),
+                stack: Vec::new(),
+                components: Vec::new(),
+            };
+
+            for package in self.packages.iter() {
+                if !tarjan.indices.contains_key(package.as_str()) {
+                    tarjan.connect(package);
+                }
+            }
+
+            let mut cycles: Vec<Vec<String>> =
+                tarjan.components.into_iter().filter(|c| c.len() > 1).collect();
+            cycles.sort();
+
+            cycles
+        }
+
+        /// Determines whether an edge is part of a cycle
+        fn in_cycle(cycles: &Vec<Vec<String>>, from: &str, to: &str) -> bool {
+            cycles
+                .iter()
+                .any(|c| c.iter().any(|p| p == from) && c.iter().any(|p| p == to))
+        }
+
+        /// Generates the graph in the Graphviz DOT language. The packages and
+        /// dependencies of cycles are drawn in red.
+        pub fn to_dot(&self) -> String {
+            let cycles = self.find_cycles();
+            let mut doc = String::from("digraph packages {\n  rankdir=LR;\n  node [shape=box];\n");
+
+            for package in self.packages.iter() {
+                if cycles.iter().any(|c| c.contains(package)) {
+                    doc.push_str(format!("  \"{}\" [color=red, fontcolor=red];\n", package).as_str());
+                } else {
+                    doc.push_str(format!("  \"{}\";\n", package).as_str());
+                }
+            }
+            for ((from, to), count) in self.edges.iter() {
+                let style = if PackageGraph::in_cycle(&cycles, from, to) {
+                    ", color=red, fontcolor=red"
+                } else {
+                    ""
+                };
+
+                doc.push_str(
+                    format!("  \"{}\" -> \"{}\" [label=\"{}\"{}];\n", from, to, count, style).as_str(),
+                );
+            }
+            doc.push_str("}\n");
+
+            doc
+        }
+
+        /// Generates the graph as a Mermaid flowchart. The packages and dependencies of
+        /// cycles are drawn in red.
+        pub fn to_mermaid(&self) -> String {
+            let cycles = self.find_cycles();
+            let ids: BTreeMap<&str, String> = self
+                .packages
+                .iter()
+                .enumerate()
+                .map(|(i, p)| (p.as_str(), format!("p{}", i)))
+                .collect();
+            let mut doc = String::from("graph LR\n");
+            let mut cycle_links = Vec::new();
+
+            for package in self.packages.iter() {
+                doc.push_str(format!("  {}[\"{}\"]\n", ids[package.as_str()], package).as_str());
+            }
+            for (i, ((from, to), count)) in self.edges.iter().enumerate() {
+                doc.push_str(
+                    format!("  {} -->|{}| {}\n", ids[from.as_str()], count, ids[to.as_str()]).as_str(),
+                );
+                if PackageGraph::in_cycle(&cycles, from, to) {
+                    cycle_links.push(i.to_string());
+                }
+            }
+
+            if cycle_links.len() > 0 {
+                doc.push_str("  classDef cycle stroke:red,color:red\n");
+                for package in cycles.iter().flat_map(|c| c.iter()) {
+                    doc.push_str(format!("  class {} cycle\n", ids[package.as_str()]).as_str());
+                }
+                doc.push_str(format!("  linkStyle {} stroke:red\n", cycle_links.join(",")).as_str());
+            }
+
+            doc
+        }
+
+        /// Generates a markdown report listing the dependencies of every package and
+        /// the cyclic dependencies
+        pub fn gen_report(&self) -> String {
+            let cycles = self.find_cycles();
+            let mut doc = String::from("# Package Dependencies\n\n");
+
+            doc.push_str(format!("```mermaid\n{}```\n\n", self.to_mermaid()).as_str());
+            doc.push_str("| Package | Depends on | Used by |\n|---|---|---|\n");
+            for package in self.packages.iter() {
+                let used_by: Vec<&str> = self
+                    .edges
+                    .keys()
+                    .filter(|(_, to)| to == package)
+                    .map(|(from, _)| from.as_str())
+                    .collect();
+
+                doc.push_str(
+                    format!(
+                        "| {} | {} | {} |\n",
+                        package,
+                        self.dependencies(package).join(", "),
+                        used_by.join(", ")
+                    ).as_str(),
+                );
+            }
+
+            doc.push_str("\n## Cycles\n\n");
+            if cycles.len() == 0 {
+                doc.push_str("No cyclic package dependencies were found.\n");
+            }
+            for (i, cycle) in cycles.iter().enumerate() {
+                doc.push_str(format!("{}. {}\n", i + 1, cycle.join(", ")).as_str());
+                for ((from, to), count) in self.edges.iter() {
+                    if cycle.contains(from) && cycle.contains(to) {
+                        doc.push_str(
+                            format!("    - {} -> {} ({} references)\n", from, to, count).as_str(),
+                        );
+                    }
+                }
+            }
+
+            doc
+        }
+    }
+
+    fn write_file(path: String, contents: String) -> io::Result<()> {
+        let mut file = File::create(path)?;
+        file.write_all(contents.as_bytes())
+    }
+
+    /// Writes `packages.dot`, `packages.mmd` and the `Dependencies.md` report, which
+    /// lists the cyclic dependencies, into the destination directory
+    ///
+    /// # Arguments
+    ///
+    /// * `graph` - The package dependency graph of the project
+    /// * `dest` - The destination directory
+    pub fn write_package_graph(graph: &PackageGraph, dest: &str) -> io::Result<()> {
+        write_file(format!("{}/packages.dot", dest), graph.to_dot())?;
+        write_file(format!("{}/packages.mmd", dest), graph.to_mermaid())?;
+        write_file(format!("{}/Dependencies.md", dest), graph.gen_report())
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,59 @@
+use graph::graph::*;
+use model::model::*;
+
+fn decl(package: &str, name: &str, deps: Vec<&str>) -> TypeDecl {
+    let mut decl = TypeDecl::new(TypeKind::Class);
+    decl.ch_name(name.to_string());
+    decl.ch_package_name(package.to_string());
+    for dep in deps {
+        decl.add_dependency(dep.to_string());
+    }
+
+    decl
+}
+
+fn layered() -> Project {
+    let mut proj = Project::new();
+    let mut order = decl("com.shop.model", "Order", vec!["com.shop.service.Pricing"]);
+    order.ch_parent("Entity".to_string());
+
+    proj.add_type(order);
+    proj.add_type(decl("com.shop.model", "Entity", vec![]));
+    proj.add_type(decl("com.shop.service", "Pricing", vec!["com.shop.model.*"]));
+    proj.add_type(decl("com.shop.web", "Api", vec!["com.shop.service.Pricing"]));
+
+    proj
+}
+
+#[test]
+fn test_package_edges() {
+    let graph = PackageGraph::new(&layered());
+
+    assert_eq!(graph.packages.len(), 3);
+    assert_eq!(graph.dependencies("com.shop.web"), vec!["com.shop.service"]);
+    assert_eq!(graph.dependencies("com.shop.model"), vec!["com.shop.service"]);
+    assert_eq!(graph.dependencies("com.shop.service"), vec!["com.shop.model"]);
+}
+
+#[test]
+fn test_find_cycles() {
+    let graph = PackageGraph::new(&layered());
+
+    assert_eq!(
+        graph.find_cycles(),
+        vec![vec!["com.shop.model".to_string(), "com.shop.service".to_string()]]
+    );
+
+    let dot = graph.to_dot();
+    assert!(dot.contains("  \"com.shop.model\" [color=red, fontcolor=red];\n"));
+    assert!(dot.contains("  \"com.shop.web\";\n"));
+    assert!(dot.contains("  \"com.shop.web\" -> \"com.shop.service\" [label=\"1\"];\n"));
+
+    let mermaid = graph.to_mermaid();
+    assert!(mermaid.contains("  p0[\"com.shop.model\"]\n"));
+    assert!(mermaid.contains("  class p1 cycle\n"));
+    assert!(mermaid.contains("  linkStyle 0,1 stroke:red\n"));
+
+    let report = graph.gen_report();
+    assert!(report.contains("1. com.shop.model, com.shop.service\n"));
+}
@@ -0,0 +1,400 @@
+pub mod history {
+    //! Module that reads the git history of the java files of a project
+    //!
+    //! The commits are walked from `HEAD` and compared with their first parent, which gives
+    //! the latest and the first commit of every java file. The latest commit of a method is
+    //! found by blaming the lines from its declaration to the end of its body. The types
+    //! changed in the latest commits are listed in `Recently-Changed.md`.
+
+    use std::collections::BTreeMap;
+    use std::fs;
+    use std::path::Path;
+    use std::path::PathBuf;
+
+    use error::error::LojidocError;
+    use git2::Commit;
+    use git2::Oid;
+    use git2::Patch;
+    use git2::Repository;
+    use git2::Sort;
+    use model::model::LinkFn;
+    use model::model::Options;
+    use model::model::Project;
+    use model::model::Revision;
+    use model::model::TypeDecl;
+    use model::usage::qualified_name;
+    use template::template::table_cell;
+    use template::template::table_name;
+
+    /// The history of the java files of a repository
+    struct History {
+        repo: Repository,
+        /// The canonical path of the working directory
+        workdir: PathBuf,
+        /// The commits reachable from `HEAD`, newest first
+        commits: Vec<Revision>,
+        /// The latest commit of every java file by its path relative to the repository
+        last: BTreeMap<String, Revision>,
+        /// The commit that added every java file by its path relative to the repository
+        first: BTreeMap<String, Revision>,
+    }
+
+    /// A type changed in one of the latest commits
+    pub struct RecentChange {
+        /// The qualified name of the type
+        pub name: String,
+        pub link: String,
+        pub revision: Revision,
+    }
+
+    /// Converts the time of a commit into a `YYYY-MM-DD` date in the time zone of the commit
+    ///
+    /// # Arguments
+    ///
+    /// * `seconds` - The seconds since the unix epoch
+    /// * `offset_minutes` - The offset of the time zone from UTC in minutes
+    pub fn format_date(seconds: i64, offset_minutes: i32) -> String {
+        let local = seconds + offset_minutes as i64 * 60;
+        let days = if local >= 0 { local / 86400 } else { (local - 86399) / 86400 };
+
+        // Converts the days since the epoch into a date of the proleptic Gregorian calendar
+        let z = days + 719468;
+        let era = (if z >= 0 { z } else { z - 146096 }) / 146097;
+        let day_of_era = z - era * 146097;
+        let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_index = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+        let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+        let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+        format!("{:04}-{:02}-{:02}", year, month, day)
+    }
+
+    /// Gets the revision of a commit, dated by its author
+    fn revision(commit: &Commit) -> Revision {
+        let author = commit.author();
+
+        Revision {
+            commit: commit.id().to_string()[..7].to_string(),
+            author: author.name().unwrap_or("").to_string(),
+            date: format_date(author.when().seconds(), author.when().offset_minutes()),
+            summary: commit.summary().unwrap_or("").to_string(),
+        }
+    }
+
+    /// Walks the commits of a repository from `HEAD` and records the latest and the first
+    /// commit of every java file
+    fn read_history(repo: Repository) -> Result<History, LojidocError> {
+        let workdir = match repo.workdir() {
+            Some(dir) => fs::canonicalize(dir)?,
+            None => PathBuf::new(),
+        };
+        let mut history = History {
+            commits: Vec::new(),
+            last: BTreeMap::new(),
+            first: BTreeMap::new(),
+            workdir: workdir,
+            repo: repo,
+        };
+
+        {
+            let repo = &history.repo;
+            let mut walk = repo.revwalk()?;
+            walk.set_sorting(Sort::TIME);
+            walk.push_head()?;
+
+            for id in walk {
+                let commit = repo.find_commit(id?)?;
+                let parent = match commit.parents().next() {
+                    Some(parent) => Some(parent.tree()?),
+                    None => None,
+                };
+                let diff = repo.diff_tree_to_tree(parent.as_ref(), Some(&commit.tree()?), None)?;
+                let rev = revision(&commit);
+
+                for delta in diff.deltas() {
+                    let path = match delta.new_file().path().and_then(|p| p.to_str()) {
+                        Some(path) if path.ends_with(".java") => path.to_string(),
+                        _ => continue,
+                    };
+
+                    history.last.entry(path.clone()).or_insert_with(|| rev.clone());
+                    history.first.insert(path, rev.clone());
+                }
+                history.commits.push(rev);
+            }
+        }
+
+        Ok(history)
+    }
+
+    /// Finds the last line of a member declared at a line: the line of the `;` ending its
+    /// declaration or of the brace closing its body. Braces in comments and literals are
+    /// skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `lines` - The lines of the java file
+    /// * `start` - The line of the declaration, counted from one
+    fn member_end(lines: &Vec<&str>, start: usize) -> usize {
+        let mut depth = 0;
+        let mut in_comment = false;
+
+        for (i, line) in lines.iter().enumerate().skip(start.max(1) - 1) {
+            let mut chars = line.chars().peekable();
+            let mut quote: Option<char> = None;
+
+            while let Some(ch) = chars.next() {
+                if in_comment {
+                    if ch == '*' && chars.peek() == Some(&'/') {
+                        chars.next();
+                        in_comment = false;
+                    }
+                    continue;
+                }
+                if let Some(q) = quote {
+                    if ch == '\\' {
+                        chars.next();
+                    } else if ch == q {
+                        quote = None;
+                    }
+                    continue;
+                }
+
+                match ch {
+                    '"' | '\'' => quote = Some(ch),
+                    '/' if chars.peek() == Some(&'/') => break,
+                    '/' if chars.peek() == Some(&'*') => {
+                        chars.next();
+                        in_comment = true;
+                    }
+                    '{' => depth += 1,
+                    '}' => {
+                        depth -= 1;
+                        if depth <= 0 {
+                            return i + 1;
+                        }
+                    }
+                    ';' if depth == 0 => return i + 1,
+                    _ => (),
+                }
+            }
+        }
+
+        lines.len()
+    }
+
+    /// Maps the lines of the working copy of a file to the lines of its committed version,
+    /// both counted from one. The lines added or changed since the commit map to `None`.
+    ///
+    /// # Arguments
+    ///
+    /// * `committed` - The contents of the file in `HEAD`
+    /// * `working` - The contents of the file in the working directory
+    pub fn committed_lines(committed: &[u8], working: &[u8]) -> Result<Vec<Option<usize>>, LojidocError> {
+        let patch = Patch::from_buffers(committed, None, working, None, None)?;
+        // A hunk without lines on one side starts after the line it names
+        let first = |start: u32, len: u32| if len == 0 { start as usize + 1 } else { start as usize };
+        let mut lines: Vec<Option<usize>> = Vec::new();
+        let mut old = 1;
+
+        for i in 0..patch.num_hunks() {
+            let (hunk, count) = patch.hunk(i)?;
+
+            while lines.len() + 1 < first(hunk.new_start(), hunk.new_lines()) {
+                lines.push(Some(old));
+                old += 1;
+            }
+            for j in 0..count {
+                let line = patch.line_in_hunk(i, j)?;
+                match line.origin() {
+                    ' ' => lines.push(line.old_lineno().map(|n| n as usize)),
+                    '+' => lines.push(None),
+                    _ => (),
+                }
+            }
+            old = first(hunk.old_start(), hunk.old_lines()) + hunk.old_lines() as usize;
+        }
+        for _ in lines.len()..String::from_utf8_lossy(working).lines().count() {
+            lines.push(Some(old));
+            old += 1;
+        }
+
+        Ok(lines)
+    }
+
+    /// Sets the commit that last changed every method of a type by blaming its lines in
+    /// `HEAD`. The lines of the working copy are mapped to the committed ones first, so
+    /// uncommitted edits neither shift the methods onto other commits nor get a commit of
+    /// their own. A file which is not committed is skipped.
+    fn blame_methods(history: &History, decl: &mut TypeDecl, file: &Path, relative: &Path) -> Result<(), LojidocError> {
+        let blame = match history.repo.blame_file(relative, None) {
+            Ok(blame) => blame,
+            Err(_) => return Ok(()),
+        };
+        let tree = history.repo.head()?.peel_to_tree()?;
+        let blob = match tree.get_path(relative) {
+            Ok(entry) => history.repo.find_blob(entry.id())?,
+            Err(_) => return Ok(()),
+        };
+        let contents = fs::read_to_string(file)?;
+        let lines: Vec<&str> = contents.lines().collect();
+        let committed = committed_lines(blob.content(), contents.as_bytes())?;
+        let mut revisions: BTreeMap<Oid, Revision> = BTreeMap::new();
+
+        for method in decl.methods.iter_mut() {
+            let start = match method.line_num.trim().parse::<usize>() {
+                Ok(start) if start > 0 => start,
+                _ => continue,
+            };
+            let mut latest: Option<(i64, Oid)> = None;
+
+            for line in start..(member_end(&lines, start) + 1) {
+                let hunk = match committed.get(line - 1) {
+                    Some(&Some(line)) => blame.get_line(line),
+                    _ => None,
+                };
+                if let Some(hunk) = hunk {
+                    let time = hunk.final_signature().when().seconds();
+                    if latest.map_or(true, |(t, _)| time > t) {
+                        latest = Some((time, hunk.final_commit_id()));
+                    }
+                }
+            }
+
+            if let Some((_, id)) = latest {
+                if !revisions.contains_key(&id) {
+                    revisions.insert(id, revision(&history.repo.find_commit(id)?));
+                }
+                method.ch_last_change(revisions.get(&id).cloned());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reads the git history of the java files of a project. With `options.git_history`
+    /// every type and method gets the commit that last changed it and the project gets
+    /// its `options.recent_commits` latest commits. With `options.git_authors` a type
+    /// without an `@author` tag gets the author of the commit that added its file. Files
+    /// outside of a git repository are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `proj` - The project parsed from the java files
+    /// * `options` - The options of the git history
+    pub fn add_git_history(proj: &mut Project, options: &Options) -> Result<(), LojidocError> {
+        let mut histories: Vec<History> = Vec::new();
+
+        for decl in proj.types.iter_mut() {
+            let file = match fs::canonicalize(&decl.file_path) {
+                Ok(file) => file,
+                Err(_) => continue,
+            };
+
+            if !histories.iter().any(|h| file.starts_with(&h.workdir)) {
+                let dir = file.parent().unwrap_or(Path::new("."));
+                match Repository::discover(dir) {
+                    Ok(repo) => histories.push(read_history(repo)?),
+                    Err(_) => continue,
+                }
+            }
+            let history = match histories.iter().find(|h| file.starts_with(&h.workdir)) {
+                Some(history) => history,
+                None => continue,
+            };
+            let relative = file.strip_prefix(&history.workdir).unwrap_or(&file).to_path_buf();
+            let key = relative.to_str().unwrap_or("").replace('\\', "/");
+
+            if options.git_authors && decl.author.trim() == "" {
+                if let Some(first) = history.first.get(&key) {
+                    decl.ch_author(first.author.clone());
+                }
+            }
+            if options.git_history && history.last.contains_key(&key) {
+                decl.ch_last_change(history.last.get(&key).cloned());
+                blame_methods(history, decl, &file, &relative)?;
+            }
+        }
+
+        if options.git_history {
+            if let Some(history) = histories.first() {
+                proj.recent_commits = history.commits.iter().take(options.recent_commits).cloned().collect();
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Finds the types last changed in one of the recent commits of a project, from the
+    /// newest change to the oldest
+    ///
+    /// # Arguments
+    ///
+    /// * `proj` - The documented project
+    /// * `link` - Creates the links of the type pages
+    pub fn find_recent_changes(proj: &Project, link: &LinkFn) -> Vec<RecentChange> {
+        let position = |rev: &Revision| proj.recent_commits.iter().position(|r| r.commit == rev.commit);
+        let mut changes: Vec<(usize, RecentChange)> = Vec::new();
+
+        for decl in proj.types.iter() {
+            if let Some(ref rev) = decl.last_change {
+                if let Some(i) = position(rev) {
+                    changes.push((
+                        i,
+                        RecentChange {
+                            name: qualified_name(&decl.package_name, &decl.name),
+                            link: link(decl, ""),
+                            revision: rev.clone(),
+                        },
+                    ));
+                }
+            }
+        }
+        changes.sort_by(|a, b| a.0.cmp(&b.0).then(a.1.name.cmp(&b.1.name)));
+
+        changes.into_iter().map(|(_, change)| change).collect()
+    }
+
+    /// Generates the markdown of the `Recently-Changed.md` page listing the types changed
+    /// in the recent commits of a project
+    ///
+    /// # Arguments
+    ///
+    /// * `proj` - The documented project
+    /// * `changes` - The types changed in the recent commits
+    pub fn gen_recent_page(proj: &Project, changes: &Vec<RecentChange>) -> String {
+        let mut doc = format!(
+            "# Recently Changed API\n\n{} types were changed in the last {} commits.\n\n",
+            changes.len(),
+            proj.recent_commits.len()
+        );
+
+        if changes.len() == 0 {
+            return doc;
+        }
+
+        doc.push_str("| Type | Date | Author | Commit |\n|---|---|---|---|\n");
+        for change in changes.iter() {
+            let rev = &change.revision;
+
+            doc.push_str(
+                format!(
+                    "| [{}]({}) | {} | {} | `{}` {} |\n",
+                    table_name(&change.name),
+                    change.link,
+                    rev.date,
+                    table_cell(&rev.author),
+                    rev.commit,
+                    table_cell(&rev.summary)
+                ).as_str(),
+            );
+        }
+
+        doc
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,71 @@
+use history::history::*;
+use model::model::*;
+
+fn revision(commit: &str, date: &str) -> Revision {
+    let mut rev = Revision::new();
+    rev.commit = commit.to_string();
+    rev.author = "Jane Doe".to_string();
+    rev.date = date.to_string();
+    rev.summary = "Add the | operator".to_string();
+
+    rev
+}
+
+#[test]
+fn test_format_date() {
+    assert_eq!(format_date(0, 0), "1970-01-01");
+    assert_eq!(format_date(951825600, 0), "2000-02-29");
+    assert_eq!(format_date(1792368000, 0), "2026-10-19");
+    assert_eq!(format_date(1792368000, -60), "2026-10-18");
+    assert_eq!(format_date(-1, 0), "1969-12-31");
+}
+
+#[test]
+fn test_recent_page() {
+    let mut proj = Project::new();
+    proj.recent_commits = vec![revision("bbbbbbb", "2026-10-19"), revision("aaaaaaa", "2026-10-18")];
+
+    for &(name, commit) in [("Order", "aaaaaaa"), ("Cart", "bbbbbbb"), ("Money", "0000000")].iter() {
+        let mut decl = TypeDecl::new(TypeKind::Class);
+        decl.ch_name(name.to_string());
+        decl.ch_package_name("com.shop".to_string());
+        decl.ch_last_change(Some(revision(commit, "2026-10-18")));
+        proj.add_type(decl);
+    }
+
+    let changes = find_recent_changes(&proj, &|decl, _| format!("{}.md", decl.name));
+    let names: Vec<&str> = changes.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(names, vec!["com.shop.Cart", "com.shop.Order"]);
+
+    let page = gen_recent_page(&proj, &changes);
+    assert!(page.starts_with("# Recently Changed API\n\n2 types were changed in the last 2 commits.\n\n"));
+    assert!(page.contains("| [com.shop.Order](Order.md) | 2026-10-18 | Jane Doe | `aaaaaaa` Add the \\| operator |\n"));
+}
+
+#[test]
+fn test_committed_lines() {
+    let committed = "class A {\n    void a() {}\n    void b() {}\n    void c() {}\n}\n";
+
+    let unchanged = committed_lines(committed.as_bytes(), committed.as_bytes()).unwrap();
+    assert_eq!(vec![Some(1), Some(2), Some(3), Some(4), Some(5)], unchanged);
+
+    // An added import shifts the lines and a changed method has no committed lines
+    let working = "import b.B;\nclass A {\n    void a() {}\n    void b(B b) {}\n    void c() {}\n}\n";
+    let edited = committed_lines(committed.as_bytes(), working.as_bytes()).unwrap();
+    assert_eq!(vec![None, Some(1), Some(2), None, Some(4), Some(5)], edited);
+
+    // Removed lines shift the following lines back
+    let working = "class A {\n    void c() {}\n}\n";
+    let removed = committed_lines(committed.as_bytes(), working.as_bytes()).unwrap();
+    assert_eq!(vec![Some(1), Some(4), Some(5)], removed);
+
+    // Lines between hunks far apart keep their offset
+    let committed: String = (1..21).map(|i| format!("int f{} = {};\n", i, i)).collect();
+    let working = committed.replace("int f2 = 2;\n", "").replace("int f18 = 18;", "int f18 = 0;\nint g = 1;");
+    let far = committed_lines(committed.as_bytes(), working.as_bytes()).unwrap();
+    assert_eq!(20, far.len());
+    assert_eq!(Some(1), far[0]);
+    assert_eq!(Some(3), far[1]);
+    assert_eq!(Some(17), far[15]);
+    assert_eq!(vec![None, None, Some(19), Some(20)], far[16..].to_vec());
+}
@@ -0,0 +1,835 @@
+pub mod html {
+    //! Module that renders a project as a self-contained static HTML site
+
+    use std::collections::BTreeMap;
+    use std::fs;
+    use std::fs::File;
+    use std::io;
+    use std::io::prelude::*;
+    use std::path::Path;
+    use std::path::PathBuf;
+
+    use document::document::package_path;
+    use document::document::remove_old_docs;
+    use model::model::constant_anchor;
+    use model::model::field_anchor;
+    use model::model::method_anchor;
+    use model::model::Deprecation;
+    use model::model::Options;
+    use model::model::Project;
+    use model::model::Revision;
+    use model::model::TypeDecl;
+    use model::model::Usage;
+    use model::model::UsageIndex;
+    use model::usage::qualified_name;
+    use search::search::build_index;
+    use search::search::write_search_index;
+    use serde_json;
+    use versions::versions::project_versions;
+
+    /// The stylesheet embedded in every generated page
+    pub const STYLESHEET: &'static str = "
+body { margin: 0; font-family: -apple-system, 'Segoe UI', Helvetica, Arial, sans-serif; color: #24292e; }
+a { color: #0366d6; text-decoration: none; }
+a:hover { text-decoration: underline; }
+nav.sidebar { position: fixed; top: 0; bottom: 0; left: 0; width: 260px; overflow-y: auto;
+    background: #f6f8fa; border-right: 1px solid #e1e4e8; padding: 16px; box-sizing: border-box; }
+nav.sidebar h3 { font-size: 13px; margin: 16px 0 4px 0; color: #586069; }
+nav.sidebar ul { list-style: none; margin: 0; padding: 0; }
+nav.sidebar li { font-size: 14px; padding: 2px 0; }
+nav.sidebar li.current { font-weight: bold; }
+main { margin-left: 260px; padding: 24px 48px; max-width: 960px; }
+pre.signature { background: #f6f8fa; padding: 12px; border-radius: 4px; overflow-x: auto; }
+dl.info dt { font-weight: bold; float: left; clear: left; width: 120px; }
+dl.info dd { margin: 0 0 4px 130px; }
+.deprecated { border-left: 4px solid #d73a49; background: #ffeef0; padding: 6px 12px; margin: 8px 0; }
+.deprecated .badge { background: #d73a49; color: #fff; border-radius: 3px; padding: 1px 6px; font-size: 0.85em; font-weight: bold; }
+.member { border-top: 1px solid #e1e4e8; padding-top: 8px; margin-top: 16px; }
+.src { font-size: 13px; font-weight: normal; margin-left: 8px; }
+table { border-collapse: collapse; margin: 8px 0; }
+th, td { border: 1px solid #dfe2e5; padding: 6px 12px; text-align: left; }
+#lojidoc-versions select { width: 100%; margin-top: 8px; }
+";
+
+    /// The version filter which shows only the API added in the selected version
+    pub const VERSION_SCRIPT: &'static str = include_str!("versions.js");
+
+    /// Escapes the characters of a string which have a special meaning in HTML
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text to escape
+    pub fn escape(text: &str) -> String {
+        let mut escaped = String::new();
+
+        for ch in text.chars() {
+            match ch {
+                '&' => escaped.push_str("&amp;"),
+                '<' => escaped.push_str("&lt;"),
+                '>' => escaped.push_str("&gt;"),
+                '"' => escaped.push_str("&quot;"),
+                '\'' => escaped.push_str("&#39;"),
+                _ => escaped.push(ch),
+            }
+        }
+
+        escaped
+    }
+
+    /// Creates the relative path from a package directory to the root of the site
+    fn root_path(package: &str) -> String {
+        package_path(package, "")
+    }
+
+    /// Creates the relative link from a page in one package to the page of a type
+    fn type_link(from_package: &str, to_package: &str, name: &str) -> String {
+        format!("{}{}.html", package_path(from_package, to_package), name)
+    }
+
+    /// Creates the anchor id of a type in the single-file reference
+    pub fn type_anchor(package: &str, name: &str) -> String {
+        qualified_name(package, name)
+    }
+
+    /// Creates the heading level of a section. The sections of a type are one level
+    /// deeper in the single-file reference, below the package headings.
+    fn heading(level: usize, single_file: bool) -> usize {
+        if single_file {
+            level + 1
+        } else {
+            level
+        }
+    }
+
+    /// Creates the id of a member of a type. In the single-file reference the id is
+    /// prefixed with the anchor of the type to keep it unique.
+    fn member_id(decl: &TypeDecl, anchor: String, single_file: bool) -> String {
+        if single_file {
+            format!("{}.{}", type_anchor(&decl.package_name, &decl.name), anchor)
+        } else {
+            anchor
+        }
+    }
+
+    /// Groups the names of the project's types by package, both sorted alphabetically
+    fn group_packages(proj: &Project) -> BTreeMap<String, Vec<String>> {
+        let mut packages: BTreeMap<String, Vec<String>> = BTreeMap::new();
+
+        for decl in proj.types.iter() {
+            let names = packages.entry(decl.package_name.trim().to_string()).or_insert(Vec::new());
+            names.push(decl.name.clone());
+        }
+        for names in packages.values_mut() {
+            names.sort();
+        }
+
+        packages
+    }
+
+    /// Generates the navigation sidebar listing every package and its types
+    ///
+    /// # Arguments
+    ///
+    /// * `packages` - The type names of the project grouped by package
+    /// * `package` - The package of the page containing the sidebar
+    /// * `current` - The name of the type documented on the page, if any
+    fn gen_sidebar(packages: &BTreeMap<String, Vec<String>>, package: &str, current: &str) -> String {
+        let mut doc = String::new();
+
+        doc.push_str("<nav class=\"sidebar\">\n");
+        doc.push_str(
+            format!("<a href=\"{}index.html\">Contents</a>\n", root_path(package)).as_str(),
+        );
+        doc.push_str("<div id=\"lojidoc-search\"></div>\n<div id=\"lojidoc-versions\"></div>\n");
+
+        for (p, names) in packages.iter() {
+            doc.push_str(format!("<h3>{}</h3>\n<ul>\n", escape(p)).as_str());
+
+            for name in names {
+                let class = if p == package.trim() && name == current {
+                    " class=\"current\""
+                } else {
+                    ""
+                };
+
+                doc.push_str(
+                    format!(
+                        "<li{}><a href=\"{}\">{}</a></li>\n",
+                        class,
+                        type_link(package, p, name),
+                        escape(name)
+                    ).as_str(),
+                );
+            }
+            doc.push_str("</ul>\n");
+        }
+        doc.push_str("</nav>\n");
+
+        doc
+    }
+
+    /// Wraps the body of a page in the html document with the sidebar and stylesheet
+    fn gen_page(title: &str, package: &str, sidebar: &str, body: &str) -> String {
+        let mut doc = String::from("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n");
+
+        doc.push_str("<meta charset=\"utf-8\">\n");
+        doc.push_str(format!("<title>{}</title>\n", escape(title)).as_str());
+        doc.push_str(format!("<style>{}</style>\n", STYLESHEET).as_str());
+        for script in vec!["search-index.js", "lojidoc-search.js", "lojidoc-versions.js"] {
+            doc.push_str(
+                format!("<script src=\"{}{}\" defer></script>\n", root_path(package), script).as_str(),
+            );
+        }
+        doc.push_str("</head>\n<body>\n");
+        doc.push_str(sidebar);
+        doc.push_str("<main>\n");
+        doc.push_str(body);
+        doc.push_str("</main>\n</body>\n</html>\n");
+
+        doc
+    }
+
+    /// Creates the relative path from a directory to a file. Both must exist, otherwise the
+    /// path of the file is returned unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `from_dir` - The directory the path starts from
+    /// * `to` - The file the path leads to
+    pub fn relative_path(from_dir: &Path, to: &Path) -> String {
+        let (from_dir, to_file) = match (fs::canonicalize(from_dir), fs::canonicalize(to)) {
+            (Ok(from_dir), Ok(to_file)) => (from_dir, to_file),
+            _ => return to.to_str().unwrap_or("").to_string(),
+        };
+        let common = from_dir
+            .components()
+            .zip(to_file.components())
+            .take_while(|&(a, b)| a == b)
+            .count();
+
+        let mut path = PathBuf::new();
+        for _ in common..from_dir.components().count() {
+            path.push("..");
+        }
+        for part in to_file.components().skip(common) {
+            path.push(part.as_os_str());
+        }
+
+        path.to_str().unwrap_or("").replace('\\', "/")
+    }
+
+    /// Points the source links of the types at their java files relative to the pages
+    /// they are shown on. Links to a repository or to source pages are left unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `proj` - The project to relink
+    /// * `dest` - The destination directory of the site, which must exist
+    /// * `single_file` - Whether the pages are sections of a single file in the destination directory
+    fn relink_sources(proj: &mut Project, dest: &str, single_file: bool) {
+        for decl in proj.types.iter_mut() {
+            let file = PathBuf::from(decl.file_path.as_str());
+            if decl.file_path.contains("://") || !file.is_file() {
+                continue;
+            }
+
+            let mut page_dir = PathBuf::from(dest);
+            if !single_file {
+                page_dir.push(decl.package_name.trim().replace(".", "/"));
+            }
+            decl.ch_file_path(relative_path(&page_dir, &file));
+        }
+    }
+
+    /// Creates the link to a line of the source file, or an empty string without a source path
+    fn src_link(path: &str, line_num: &str) -> String {
+        if path == "" {
+            return String::new();
+        }
+
+        if line_num == "" {
+            format!(" <a class=\"src\" href=\"{}\">[src]</a>", escape(path))
+        } else {
+            format!(" <a class=\"src\" href=\"{}#L{}\">[src]</a>", escape(path), line_num)
+        }
+    }
+
+    /// Gets the versions a type and its members were added in, separated by spaces, for
+    /// the `data-since` attribute read by the version filter
+    fn type_versions(decl: &TypeDecl) -> String {
+        let mut versions: Vec<&str> = Vec::new();
+        let constants = decl.fields.iter().map(|f| member_since(decl, &f.since));
+        let members = decl.variables.iter().map(|m| member_since(decl, &m.since));
+        let methods = decl.methods.iter().map(|m| member_since(decl, &m.since));
+
+        for since in Some(decl.since.as_str())
+            .into_iter()
+            .chain(constants)
+            .chain(members)
+            .chain(methods)
+        {
+            if since != "" && !versions.contains(&since) {
+                versions.push(since);
+            }
+        }
+
+        escape(&versions.join(" "))
+    }
+
+    /// Gets the version a member was added in. A member without a `@since` tag was added
+    /// with its type.
+    fn member_since<'a>(decl: &'a TypeDecl, since: &'a str) -> &'a str {
+        if since == "" {
+            &decl.since
+        } else {
+            since
+        }
+    }
+
+    /// Creates the script defining the versions of the project for the version filter,
+    /// followed by the filter itself
+    fn gen_version_script(proj: &Project) -> io::Result<String> {
+        let versions = serde_json::to_string(&project_versions(proj))?;
+
+        Ok(format!("window.LOJIDOC_VERSIONS = {};\n{}", versions, VERSION_SCRIPT))
+    }
+
+    /// Generates the deprecation badge of a type or member, or an empty string when it
+    /// is not deprecated
+    fn deprecation_html(deprecation: &Option<Deprecation>) -> String {
+        let deprecation = match deprecation {
+            Some(d) => d,
+            None => return String::new(),
+        };
+        let mut doc = String::from("<div class=\"deprecated\"><span class=\"badge\">Deprecated</span>");
+
+        if deprecation.since != "" {
+            doc.push_str(format!(" since {}", escape(&deprecation.since)).as_str());
+        }
+        if deprecation.for_removal {
+            doc.push_str(", for removal");
+        }
+        if deprecation.reason != "" {
+            doc.push_str(format!(": {}", escape(&deprecation.reason)).as_str());
+        }
+        doc.push_str("</div>\n");
+
+        doc
+    }
+
+    /// Generates the row of the info list naming the commit that last changed a type or
+    /// method, or an empty string without the git history
+    fn last_change_html(revision: &Option<Revision>) -> String {
+        match revision {
+            Some(r) => format!(
+                "<dt>Last changed</dt><dd>{} by {} in commit <code>{}</code></dd>\n",
+                escape(&r.date),
+                escape(&r.author),
+                escape(&r.commit)
+            ),
+            None => String::new(),
+        }
+    }
+
+    /// Generates the html describing a class, interface or enum
+    ///
+    /// # Arguments
+    ///
+    /// * `decl` - The type declaration containing the javadoc data
+    /// * `usages` - The places in the project where the type is used
+    /// * `single_file` - Whether the type is a section of the single-file reference
+    pub fn gen_type_html(decl: &TypeDecl, usages: Vec<Usage>, single_file: bool) -> String {
+        let mut doc = String::new();
+        let h2 = heading(2, single_file);
+
+        doc.push_str(
+            format!(
+                "<h{0}>{1} {2}{3}</h{0}>\n",
+                heading(1, single_file),
+                decl.kind.title(),
+                escape(&decl.name),
+                src_link(&decl.file_path, "")
+            ).as_str(),
+        );
+        if decl.signature.trim() != "" {
+            doc.push_str(
+                format!("<pre class=\"signature\">{}</pre>\n", escape(decl.signature.trim())).as_str(),
+            );
+        }
+        doc.push_str(deprecation_html(&decl.deprecation).as_str());
+        if decl.description.trim() != "" {
+            doc.push_str(format!("<p>{}</p>\n", escape(decl.description.trim())).as_str());
+        }
+
+        doc.push_str("<dl class=\"info\">\n");
+        doc.push_str(format!("<dt>Package</dt><dd>{}</dd>\n", escape(decl.package_name.trim())).as_str());
+        doc.push_str(format!("<dt>Access</dt><dd>{}</dd>\n", escape(decl.access.trim())).as_str());
+        if decl.author != "" {
+            doc.push_str(format!("<dt>Author</dt><dd>{}</dd>\n", escape(&decl.author)).as_str());
+        }
+        if decl.version != "" {
+            doc.push_str(format!("<dt>Version</dt><dd>{}</dd>\n", escape(&decl.version)).as_str());
+        }
+        if decl.since != "" {
+            doc.push_str(format!("<dt>Since version</dt><dd>{}</dd>\n", escape(&decl.since)).as_str());
+        }
+        doc.push_str(last_change_html(&decl.last_change).as_str());
+        let link = |name: &str| {
+            if single_file {
+                format!("#{}", type_anchor(&decl.package_name, name))
+            } else {
+                type_link(&decl.package_name, &decl.package_name, name)
+            }
+        };
+        if decl.enclosing != "" {
+            doc.push_str(
+                format!(
+                    "<dt>Enclosing type</dt><dd><a href=\"{}\">{}</a></dd>\n",
+                    link(&decl.enclosing),
+                    escape(&decl.enclosing)
+                ).as_str(),
+            );
+        }
+        if decl.parent != "" {
+            doc.push_str(format!("<dt>Parent class</dt><dd>{}</dd>\n", escape(&decl.parent)).as_str());
+        }
+        if decl.interfaces.len() > 0 {
+            doc.push_str(
+                format!("<dt>Interfaces</dt><dd>{}</dd>\n", escape(&decl.interfaces.join(", "))).as_str(),
+            );
+        }
+        for exception in decl.exceptions.iter() {
+            doc.push_str(
+                format!(
+                    "<dt>Throws</dt><dd>{} {}</dd>\n",
+                    escape(&exception.exception_type),
+                    escape(&exception.desc)
+                ).as_str(),
+            );
+        }
+        doc.push_str("</dl>\n");
+
+        if decl.license != "" {
+            doc.push_str("<details>\n<summary>Show license</summary>\n");
+            doc.push_str(format!("<p>{}</p>\n", escape(&decl.license)).as_str());
+            doc.push_str("</details>\n");
+        }
+
+        if decl.dependencies.len() > 0 {
+            doc.push_str(format!("<h{}>Dependencies</h{0}>\n", h2).as_str());
+            doc.push_str("<details>\n<summary>Show dependencies</summary>\n<ul>\n");
+            for dep in decl.dependencies.iter() {
+                doc.push_str(format!("<li>{}</li>\n", escape(dep)).as_str());
+            }
+            doc.push_str("</ul>\n</details>\n");
+        }
+
+        if usages.len() > 0 {
+            doc.push_str(format!("<h{}>Usages</h{0}>\n<details>\n", h2).as_str());
+            doc.push_str(format!("<summary>Show usages ({})</summary>\n<ul>\n", usages.len()).as_str());
+            for usage in usages {
+                let link = match (single_file, usage.anchor.as_str()) {
+                    (true, "") => format!("#{}", type_anchor(&usage.package_name, &usage.type_name)),
+                    (true, anchor) => format!("#{}.{}", type_anchor(&usage.package_name, &usage.type_name), anchor),
+                    (false, "") => type_link(&decl.package_name, &usage.package_name, &usage.type_name),
+                    (false, anchor) => format!(
+                        "{}#{}",
+                        type_link(&decl.package_name, &usage.package_name, &usage.type_name),
+                        anchor
+                    ),
+                };
+                let member = if usage.member != "" {
+                    format!(".{}", escape(&usage.member))
+                } else {
+                    String::new()
+                };
+
+                doc.push_str(
+                    format!(
+                        "<li><a href=\"{}\">{}</a>{} ({})</li>\n",
+                        link,
+                        escape(&usage.type_name),
+                        member,
+                        usage.kind.label()
+                    ).as_str(),
+                );
+            }
+            doc.push_str("</ul>\n</details>\n");
+        }
+
+        if decl.nested.len() > 0 {
+            doc.push_str(format!("<h{}>Nested Types</h{0}>\n<ul>\n", h2).as_str());
+            for name in decl.nested.iter() {
+                doc.push_str(format!("<li><a href=\"{}\">{}</a></li>\n", link(name), escape(name)).as_str());
+            }
+            doc.push_str("</ul>\n");
+        }
+
+        doc.push_str(gen_constant_html(decl, single_file).as_str());
+        doc.push_str(gen_var_html(decl, single_file).as_str());
+        doc.push_str(gen_method_html(decl, single_file).as_str());
+
+        doc
+    }
+
+    /// Generates the html for the enum constants of a type
+    fn gen_constant_html(decl: &TypeDecl, single_file: bool) -> String {
+        let mut doc = String::new();
+
+        if decl.fields.len() == 0 {
+            return doc;
+        }
+
+        doc.push_str(format!("<h{}>Enum Constants</h{0}>\n", heading(2, single_file)).as_str());
+        for field in decl.fields.iter() {
+            doc.push_str(
+                format!(
+                    "<div class=\"member\" id=\"{}\" data-since=\"{}\">\n",
+                    member_id(decl, constant_anchor(field), single_file),
+                    escape(member_since(decl, &field.since))
+                ).as_str(),
+            );
+            doc.push_str(format!("<h{0}>{1}</h{0}>\n", heading(4, single_file), escape(&field.name)).as_str());
+            doc.push_str(deprecation_html(&field.deprecation).as_str());
+            if field.desc != "" {
+                doc.push_str(format!("<p>{}</p>\n", escape(&field.desc)).as_str());
+            }
+            if field.since != "" {
+                doc.push_str("<dl class=\"info\">\n");
+                doc.push_str(format!("<dt>Since version</dt><dd>{}</dd>\n", escape(&field.since)).as_str());
+                doc.push_str("</dl>\n");
+            }
+            doc.push_str("</div>\n");
+        }
+
+        doc
+    }
+
+    /// Generates the html for the member variables of a type
+    fn gen_var_html(decl: &TypeDecl, single_file: bool) -> String {
+        let mut doc = String::new();
+
+        if decl.variables.len() == 0 {
+            return doc;
+        }
+
+        doc.push_str(format!("<h{}>Member Variables</h{0}>\n", heading(2, single_file)).as_str());
+        for member in decl.variables.iter() {
+            doc.push_str(
+                format!(
+                    "<div class=\"member\" id=\"{}\" data-since=\"{}\">\n",
+                    member_id(decl, field_anchor(member), single_file),
+                    escape(member_since(decl, &member.since))
+                ).as_str(),
+            );
+            doc.push_str(
+                format!(
+                    "<h{0}>{1} {2}{3}</h{0}>\n",
+                    heading(4, single_file),
+                    escape(&member.var_type),
+                    escape(&member.name),
+                    src_link(&decl.file_path, &member.line_num)
+                ).as_str(),
+            );
+            if member.signature.trim() != "" {
+                doc.push_str(
+                    format!("<pre class=\"signature\">{}</pre>\n", escape(member.signature.trim())).as_str(),
+                );
+            }
+            doc.push_str(deprecation_html(&member.deprecation).as_str());
+            if member.desc != "" {
+                doc.push_str(format!("<p>{}</p>\n", escape(&member.desc)).as_str());
+            }
+
+            doc.push_str("<dl class=\"info\">\n");
+            if member.access == "" {
+                doc.push_str("<dt>Access</dt><dd>package-private</dd>\n");
+            } else {
+                doc.push_str(format!("<dt>Access</dt><dd>{}</dd>\n", escape(&member.access)).as_str());
+            }
+            if member.since != "" {
+                doc.push_str(format!("<dt>Since version</dt><dd>{}</dd>\n", escape(&member.since)).as_str());
+            }
+            if member.modifiers.len() > 0 {
+                doc.push_str(
+                    format!("<dt>Modifiers</dt><dd>{}</dd>\n", escape(&member.modifiers.join(" "))).as_str(),
+                );
+            }
+            doc.push_str("</dl>\n</div>\n");
+        }
+
+        doc
+    }
+
+    /// Generates the html for the methods of a type
+    fn gen_method_html(decl: &TypeDecl, single_file: bool) -> String {
+        let mut doc = String::new();
+
+        if decl.methods.len() == 0 {
+            return doc;
+        }
+
+        doc.push_str(format!("<h{}>Methods</h{0}>\n", heading(2, single_file)).as_str());
+        for (i, method) in decl.methods.iter().enumerate() {
+            if method.name == "" {
+                continue;
+            }
+
+            doc.push_str(
+                format!(
+                    "<div class=\"member\" id=\"{}\" data-since=\"{}\">\n",
+                    member_id(decl, method_anchor(&decl.methods, i), single_file),
+                    escape(member_since(decl, &method.since))
+                ).as_str(),
+            );
+            doc.push_str(
+                format!(
+                    "<h{0}>{1}{2}</h{0}>\n",
+                    heading(3, single_file),
+                    escape(&method.name),
+                    src_link(&decl.file_path, &method.line_num)
+                ).as_str(),
+            );
+            if method.signature.trim() != "" {
+                doc.push_str(
+                    format!("<pre class=\"signature\">{}</pre>\n", escape(method.signature.trim())).as_str(),
+                );
+            }
+            doc.push_str(deprecation_html(&method.deprecation).as_str());
+            if method.description != "" {
+                doc.push_str(format!("<p>{}</p>\n", escape(&method.description)).as_str());
+            }
+
+            doc.push_str("<dl class=\"info\">\n");
+            if method.privacy == "" {
+                doc.push_str("<dt>Access</dt><dd>package-private</dd>\n");
+            } else {
+                doc.push_str(format!("<dt>Access</dt><dd>{}</dd>\n", escape(&method.privacy)).as_str());
+            }
+            if method.since != "" {
+                doc.push_str(format!("<dt>Since version</dt><dd>{}</dd>\n", escape(&method.since)).as_str());
+            }
+            doc.push_str(last_change_html(&method.last_change).as_str());
+            if method.modifiers.len() > 0 {
+                doc.push_str(
+                    format!("<dt>Modifiers</dt><dd>{}</dd>\n", escape(&method.modifiers.join(" "))).as_str(),
+                );
+            }
+            for exception in method.exceptions.iter() {
+                doc.push_str(
+                    format!(
+                        "<dt>Throws</dt><dd>{} {}</dd>\n",
+                        escape(&exception.exception_type),
+                        escape(&exception.desc)
+                    ).as_str(),
+                );
+            }
+            let returns = if method.return_desc != "" { &method.return_desc } else { &method.return_type };
+            if returns != "" {
+                doc.push_str(format!("<dt>Returns</dt><dd>{}</dd>\n", escape(returns)).as_str());
+            }
+            doc.push_str("</dl>\n");
+
+            if method.parameters.len() > 0 {
+                doc.push_str("<table>\n<tr><th>Name</th><th>Type</th><th>Description</th></tr>\n");
+                for param in method.parameters.iter() {
+                    doc.push_str(
+                        format!(
+                            "<tr><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                            escape(&param.name),
+                            escape(&param.var_type),
+                            escape(&param.desc)
+                        ).as_str(),
+                    );
+                }
+                doc.push_str("</table>\n");
+            }
+            doc.push_str("</div>\n");
+        }
+
+        doc
+    }
+
+    /// Generates the body of the index page listing every package and its types
+    fn gen_index_html(proj: &Project, packages: &BTreeMap<String, Vec<String>>) -> String {
+        let mut doc = String::from("<h1>Application Contents</h1>\n");
+
+        for (p, names) in packages.iter() {
+            doc.push_str(format!("<h2>{}</h2>\n<ul>\n", escape(p)).as_str());
+            for name in names {
+                let versions = proj
+                    .types
+                    .iter()
+                    .find(|d| d.package_name.trim() == p && &d.name == name)
+                    .map_or(String::new(), type_versions);
+
+                doc.push_str(
+                    format!(
+                        "<li data-since=\"{}\"><a href=\"{}\">{}</a></li>\n",
+                        versions,
+                        type_link("", p, name),
+                        escape(name)
+                    ).as_str(),
+                );
+            }
+            doc.push_str("</ul>\n");
+        }
+
+        doc
+    }
+
+    /// Generates a static html site for the project with a page for every type, an
+    /// index page, the search index with its widget and the version filter, which shows
+    /// only the API added in a version of the `@since` tags. The stylesheet is embedded in
+    /// every page and the site works offline without any other files.
+    ///
+    /// # Arguments
+    ///
+    /// * `proj` - The project containing the java documentation data
+    /// * `options` - The options containing the destination directory
+    pub fn generate_html(mut proj: Project, options: Options) -> io::Result<()> {
+        if options.clean {
+            remove_old_docs(options.dest.as_str())?;
+        }
+
+        let usages = UsageIndex::new(&proj);
+        let packages = group_packages(&proj);
+
+        for decl in proj.types.iter() {
+            let dir = format!("{}/{}", options.dest, decl.package_name.trim().replace(".", "/"));
+            fs::create_dir_all(dir)?;
+        }
+        relink_sources(&mut proj, options.dest.as_str(), false);
+
+        for decl in proj.types.iter() {
+            let body = gen_type_html(decl, usages.get(&decl.package_name, &decl.name), false);
+            let sidebar = gen_sidebar(&packages, &decl.package_name, &decl.name);
+            let page = gen_page(
+                format!("{} {}", decl.kind.title(), decl.name).as_str(),
+                &decl.package_name,
+                &sidebar,
+                &body,
+            );
+
+            let dir = format!("{}/{}", options.dest, decl.package_name.trim().replace(".", "/"));
+            let mut file = File::create(format!("{}/{}.html", dir, decl.name))?;
+            file.write_all(page.as_bytes())?;
+        }
+
+        let sidebar = gen_sidebar(&packages, "", "");
+        let index = gen_page("Application Contents", "", &sidebar, &gen_index_html(&proj, &packages));
+        let mut file = File::create(format!("{}/index.html", options.dest))?;
+        file.write_all(index.as_bytes())?;
+
+        let entries = build_index(&proj, &|decl, anchor| {
+            let page = format!("{}{}.html", package_path("", &decl.package_name), decl.name.trim());
+            if anchor == "" {
+                page
+            } else {
+                format!("{}#{}", page, anchor)
+            }
+        });
+        write_search_index(&entries, options.dest.as_str())?;
+        let mut script = File::create(format!("{}/lojidoc-versions.js", options.dest))?;
+        script.write_all(gen_version_script(&proj)?.as_bytes())?;
+
+        Ok(())
+    }
+
+    /// Generates the table of contents of the single-file reference
+    fn gen_toc(packages: &BTreeMap<String, Vec<String>>) -> String {
+        let mut doc = String::from(
+            "<nav class=\"sidebar\">\n<a href=\"#\">API Reference</a>\n<div id=\"lojidoc-versions\"></div>\n",
+        );
+
+        for (p, names) in packages.iter() {
+            doc.push_str(
+                format!(
+                    "<h3><a href=\"#package.{}\">{}</a></h3>\n<ul>\n",
+                    escape(p),
+                    escape(p)
+                ).as_str(),
+            );
+            for name in names {
+                doc.push_str(
+                    format!(
+                        "<li><a href=\"#{}\">{}</a></li>\n",
+                        escape(&type_anchor(p, name)),
+                        escape(name)
+                    ).as_str(),
+                );
+            }
+            doc.push_str("</ul>\n");
+        }
+        doc.push_str("</nav>\n");
+
+        doc
+    }
+
+    /// Generates the whole API reference as a single `API.html` file with the
+    /// stylesheet and version filter inlined. Packages are top-level sections and every
+    /// reference to a type links to its section in the document.
+    ///
+    /// # Arguments
+    ///
+    /// * `proj` - The project containing the java documentation data
+    /// * `options` - The options containing the destination directory
+    pub fn generate_single_html(mut proj: Project, options: Options) -> io::Result<()> {
+        if options.clean {
+            remove_old_docs(options.dest.as_str())?;
+        }
+
+        fs::create_dir_all(options.dest.as_str())?;
+        relink_sources(&mut proj, options.dest.as_str(), true);
+
+        let usages = UsageIndex::new(&proj);
+        let packages = group_packages(&proj);
+        let mut body = String::from("<h1>API Reference</h1>\n");
+
+        for (p, names) in packages.iter() {
+            body.push_str(
+                format!("<h1 id=\"package.{}\">Package {}</h1>\n", escape(p), escape(p)).as_str(),
+            );
+
+            for name in names {
+                let decl = proj
+                    .types
+                    .iter()
+                    .find(|d| d.package_name.trim() == p && &d.name == name);
+
+                if let Some(decl) = decl {
+                    body.push_str(
+                        format!(
+                            "<section id=\"{}\" data-since=\"{}\">\n",
+                            escape(&type_anchor(p, name)),
+                            type_versions(decl)
+                        ).as_str(),
+                    );
+                    body.push_str(
+                        gen_type_html(decl, usages.get(&decl.package_name, &decl.name), true).as_str(),
+                    );
+                    body.push_str("</section>\n");
+                }
+            }
+        }
+
+        let mut doc = String::from("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n");
+        doc.push_str("<meta charset=\"utf-8\">\n<title>API Reference</title>\n");
+        doc.push_str(format!("<style>{}</style>\n", STYLESHEET).as_str());
+        doc.push_str("</head>\n<body>\n");
+        doc.push_str(gen_toc(&packages).as_str());
+        doc.push_str("<main>\n");
+        doc.push_str(body.as_str());
+        doc.push_str("</main>\n");
+        doc.push_str(format!("<script>\n{}</script>\n", gen_version_script(&proj)?).as_str());
+        doc.push_str("</body>\n</html>\n");
+
+        let mut file = File::create(format!("{}/API.html", options.dest))?;
+        file.write_all(doc.as_bytes())?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test;
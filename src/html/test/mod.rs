@@ -0,0 +1,78 @@
+use std::env;
+use std::fs;
+use std::fs::File;
+use std::path::Path;
+
+use html::html::escape;
+use html::html::gen_type_html;
+use html::html::relative_path;
+use model::model::method_anchor;
+use model::model::EnumField;
+use model::model::Method;
+use model::model::TypeDecl;
+use model::model::TypeKind;
+
+#[test]
+fn test_escape() {
+    assert_eq!(
+        escape("Map<String, List<Order>> & \"x\""),
+        "Map&lt;String, List&lt;Order&gt;&gt; &amp; &quot;x&quot;"
+    );
+}
+
+#[test]
+fn test_gen_type_html() {
+    let mut decl = TypeDecl::new(TypeKind::Class);
+    decl.ch_name(String::from("Order"));
+    decl.ch_file_path(String::from("com/shop/Order.java"));
+    decl.ch_since(String::from("1.0"));
+
+    for since in vec!["", "1.2"] {
+        let mut method = Method::new();
+        method.ch_method_name(String::from("total"));
+        method.ch_line_num(String::from("12"));
+        method.ch_since(String::from(since));
+        decl.add_method(method);
+    }
+
+    assert_eq!(method_anchor(&decl.methods, 0), "method.total");
+    assert_eq!(method_anchor(&decl.methods, 1), "method.total-1");
+
+    let page = gen_type_html(&decl, Vec::new(), false);
+    assert!(page.contains("<h1>Class Order"));
+    assert!(page.contains("id=\"method.total\" data-since=\"1.0\""));
+    assert!(page.contains("id=\"method.total-1\" data-since=\"1.2\""));
+    assert!(page.contains("href=\"com/shop/Order.java#L12\""));
+
+    let mut status = TypeDecl::new(TypeKind::Enumeration);
+    status.ch_name(String::from("Status"));
+    status.ch_since(String::from("1.0"));
+    for (name, since) in vec![("OPEN", ""), ("REFUNDED", "1.2")] {
+        status.fields.push(EnumField {
+            name: String::from(name),
+            value: String::new(),
+            desc: String::new(),
+            deprecation: None,
+            since: String::from(since),
+        });
+    }
+
+    let page = gen_type_html(&status, Vec::new(), false);
+    assert!(page.contains("<h2>Enum Constants</h2>\n<div class=\"member\" id=\"constant.OPEN\" data-since=\"1.0\">"));
+    assert!(page.contains("id=\"constant.REFUNDED\" data-since=\"1.2\""));
+}
+
+#[test]
+fn test_relative_path() {
+    let root = env::temp_dir().join("lojidoc-relative-path");
+    fs::create_dir_all(root.join("docs/com/shop")).unwrap();
+    fs::create_dir_all(root.join("src/com/shop")).unwrap();
+    File::create(root.join("src/com/shop/Order.java")).unwrap();
+
+    let file = root.join("src/com/shop/Order.java");
+    assert_eq!(relative_path(&root.join("docs/com/shop"), &file), "../../../src/com/shop/Order.java");
+    assert_eq!(relative_path(&root.join("docs"), &file), "../src/com/shop/Order.java");
+    assert_eq!(relative_path(&root.join("docs"), Path::new("missing/Order.java")), "missing/Order.java");
+
+    fs::remove_dir_all(root).unwrap();
+}
@@ -0,0 +1,64 @@
+// Lojidoc version filter. The versions are read from window.LOJIDOC_VERSIONS and every
+// element with a data-since attribute, which lists the versions it contains, is hidden
+// unless it contains the selected version.
+(function () {
+    var KEY = 'lojidoc-version';
+
+    function apply(version) {
+        var elements = document.querySelectorAll('[data-since]');
+
+        for (var i = 0; i < elements.length; i++) {
+            var versions = elements[i].getAttribute('data-since').split(' ');
+            var shown = version === '' || versions.indexOf(version) >= 0;
+
+            elements[i].style.display = shown ? '' : 'none';
+        }
+    }
+
+    function remember(version) {
+        try {
+            window.sessionStorage.setItem(KEY, version);
+        } catch (e) {
+            // Storage is not available for pages opened from the file system in some browsers
+        }
+    }
+
+    function remembered() {
+        try {
+            return window.sessionStorage.getItem(KEY) || '';
+        } catch (e) {
+            return '';
+        }
+    }
+
+    function init() {
+        var container = document.getElementById('lojidoc-versions');
+        var versions = window.LOJIDOC_VERSIONS || [];
+
+        if (!container || versions.length === 0) {
+            return;
+        }
+
+        var select = document.createElement('select');
+        select.appendChild(new Option('All versions', ''));
+        versions.forEach(function (version) {
+            select.appendChild(new Option('New in ' + version, version));
+        });
+
+        var current = remembered();
+        select.value = versions.indexOf(current) >= 0 ? current : '';
+        select.addEventListener('change', function () {
+            remember(select.value);
+            apply(select.value);
+        });
+
+        container.appendChild(select);
+        apply(select.value);
+    }
+
+    if (document.readyState === 'loading') {
+        document.addEventListener('DOMContentLoaded', init);
+    } else {
+        init();
+    }
+})();
@@ -0,0 +1,138 @@
+pub mod index {
+    //! Module that creates the alphabetical index of the documented symbols
+    //!
+    //! Like javadoc's `index-all` page, `Index.md` lists every type, constructor, method,
+    //! member variable and enum constant grouped by the first letter of its name. Every
+    //! entry shows its kind, the type declaring it, its package and the first sentence
+    //! of its description, and links to its anchor on the page of its type.
+
+    use std::collections::BTreeMap;
+
+    use model::model::constant_anchor;
+    use model::model::field_anchor;
+    use model::model::first_sentence;
+    use model::model::method_anchor;
+    use model::model::LinkFn;
+    use model::model::Project;
+    use template::template::table_cell;
+    use template::template::table_name;
+
+    /// A symbol of the index
+    pub struct IndexEntry {
+        pub name: String,
+        pub kind: String,
+        /// The name of the type declaring the symbol, empty for a type
+        pub type_name: String,
+        pub package_name: String,
+        pub summary: String,
+        pub link: String,
+        /// The link to the page of the type declaring the symbol
+        pub type_link: String,
+    }
+
+    /// Gets the letter an entry of the index is listed under
+    fn letter(name: &str) -> String {
+        name.chars().next().map_or(String::new(), |c| c.to_uppercase().collect())
+    }
+
+    /// Creates the entries of the index sorted by name, ignoring case
+    ///
+    /// # Arguments
+    ///
+    /// * `proj` - The documented project
+    /// * `link` - Creates the links of the type pages
+    pub fn build_symbol_index(proj: &Project, link: &LinkFn) -> Vec<IndexEntry> {
+        let mut entries = Vec::new();
+
+        for decl in proj.types.iter() {
+            let type_name = decl.name.trim().to_string();
+            let package_name = decl.package_name.trim().to_string();
+            let type_link = link(decl, "");
+            let entry = |name: String, kind: &str, summary: &str, anchor: &str| IndexEntry {
+                name: name,
+                kind: kind.to_string(),
+                type_name: type_name.clone(),
+                package_name: package_name.clone(),
+                summary: first_sentence(summary),
+                link: link(decl, anchor),
+                type_link: type_link.clone(),
+            };
+
+            entries.push(IndexEntry {
+                type_name: String::new(),
+                type_link: String::new(),
+                ..entry(type_name.clone(), decl.kind.label(), &decl.description, "")
+            });
+            for field in decl.fields.iter() {
+                entries.push(entry(field.name.clone(), "enum constant", &field.desc, &constant_anchor(field)));
+            }
+            for member in decl.variables.iter() {
+                entries.push(entry(member.name.clone(), "field", &member.desc, &field_anchor(member)));
+            }
+            for (i, method) in decl.methods.iter().enumerate() {
+                let kind = if method.name == decl.simple_name() { "constructor" } else { "method" };
+                let params: Vec<&str> = method.parameters.iter().map(|p| p.var_type.as_str()).collect();
+                let name = format!("{}({})", method.name, params.join(", "));
+
+                entries.push(entry(name, kind, &method.description, &method_anchor(&decl.methods, i)));
+            }
+        }
+        entries.sort_by(|a, b| {
+            (a.name.to_lowercase(), &a.package_name, &a.type_name)
+                .cmp(&(b.name.to_lowercase(), &b.package_name, &b.type_name))
+        });
+
+        entries
+    }
+
+    /// Generates the markdown of the index page. The page starts with links to the
+    /// letters and every letter has a table of its entries.
+    ///
+    /// # Arguments
+    ///
+    /// * `entries` - The sorted entries of the index
+    pub fn gen_index_page(entries: &Vec<IndexEntry>) -> String {
+        let mut letters: BTreeMap<String, Vec<&IndexEntry>> = BTreeMap::new();
+        for entry in entries.iter() {
+            letters.entry(letter(&entry.name)).or_insert(Vec::new()).push(entry);
+        }
+
+        let mut doc = String::from("# Index\n\n");
+        let links: Vec<String> = letters
+            .keys()
+            .map(|l| format!("[{}](#index-{})", l, l))
+            .collect();
+        doc.push_str(format!("{}\n\n", links.join(" | ")).as_str());
+
+        for (l, entries) in letters.iter() {
+            doc.push_str(format!("<a id=\"index-{}\"></a>\n\n## {}\n\n", l, l).as_str());
+            doc.push_str("| Name | Kind | Type | Package | Description |\n|---|---|---|---|---|\n");
+
+            for entry in entries.iter() {
+                let type_cell = if entry.type_name == "" {
+                    String::new()
+                } else {
+                    format!("[{}]({})", table_name(&entry.type_name), entry.type_link)
+                };
+
+                doc.push_str(
+                    format!(
+                        "| [{}]({}) | {} | {} | {} | {} |\n",
+                        table_name(&entry.name),
+                        entry.link,
+                        entry.kind,
+                        type_cell,
+                        entry.package_name,
+                        table_cell(&entry.summary)
+                    ).as_str(),
+                );
+            }
+            doc.push_str("\n");
+        }
+
+        doc
+    }
+}
+
+#[cfg(test)]
+mod test;
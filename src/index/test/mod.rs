@@ -0,0 +1,57 @@
+use index::index::*;
+use model::model::*;
+use parse::parse::parse_contents;
+
+#[test]
+fn test_symbol_index() {
+    let mut proj = Project::new();
+    proj.add_type(
+        parse_contents(
+            "package com.shop;\n\n/** The state of an order. Never null. */\npublic enum Status {\n    \
+             /** The order can be changed. Until it is paid. */\n    OPEN(1);\n\n    \
+             /** The code of the status */\n    private final int code;\n\n    \
+             Status(int code) {\n        this.code = code;\n    }\n\n    \
+             /** Whether the order is open */\n    public boolean isOpen() {\n        return true;\n    }\n\n    \
+             public static Status merge(List<Integer> codes) {\n        return OPEN;\n    }\n\n    \
+             public static class Builder {\n        public Builder(int code) {\n        }\n    }\n}\n",
+        ).unwrap(),
+    );
+
+    let entries = build_symbol_index(&proj, &|decl, anchor| format!("{}.md#{}", decl.name, anchor));
+    let names: Vec<(&str, &str)> = entries.iter().map(|e| (e.name.as_str(), e.kind.as_str())).collect();
+
+    assert_eq!(
+        names,
+        vec![
+            ("Builder(int)", "constructor"),
+            ("code", "field"),
+            ("isOpen()", "method"),
+            ("merge(List<Integer>)", "method"),
+            ("OPEN", "enum constant"),
+            ("Status", "enum"),
+            ("Status(int)", "constructor"),
+            ("Status.Builder", "class"),
+        ]
+    );
+    assert_eq!(entries[0].type_name, "Status.Builder");
+    assert_eq!(entries[2].link, "Status.md#method.isOpen");
+
+    let page = gen_index_page(&entries);
+
+    assert!(page.starts_with(
+        "# Index\n\n[B](#index-B) | [C](#index-C) | [I](#index-I) | [M](#index-M) | [O](#index-O) | [S](#index-S)\n\n"
+    ));
+    assert!(page.contains(
+        "| [isOpen()](Status.md#method.isOpen) | method | [Status](Status.md#) | com.shop | \
+         Whether the order is open |\n"
+    ));
+    assert!(page.contains("| [Status](Status.md#) | enum |  | com.shop | The state of an order. |\n"));
+    assert!(page.contains(
+        "| [OPEN](Status.md#constant.OPEN) | enum constant | [Status](Status.md#) | com.shop | \
+         The order can be changed. |\n"
+    ));
+    assert!(page.contains(
+        "| [code](Status.md#field.code) | field | [Status](Status.md#) | com.shop | The code of the status |\n"
+    ));
+    assert!(page.contains("| [merge(List&lt;Integer&gt;)](Status.md#method.merge) | method |"));
+}
@@ -0,0 +1,87 @@
+pub mod json {
+    //! Module that serializes a parsed project to a versioned JSON document and loads it back
+
+    use std::fs;
+    use std::fs::File;
+    use std::io::prelude::*;
+    use std::path::Path;
+
+    use serde_json;
+
+    use error::error::LojidocError;
+    use model::model::Project;
+
+    /// The version of the JSON document format. Incremented whenever the layout of
+    /// the serialized model changes.
+    pub const FORMAT_VERSION: u32 = 12;
+
+    #[derive(Debug, Serialize, Deserialize)]
+    /// The JSON document written by the json backend
+    pub struct ProjectDocument {
+        pub format_version: u32,
+        pub generator: String,
+        pub project: Project,
+    }
+
+    /// Serializes a project to a JSON string
+    ///
+    /// # Arguments
+    ///
+    /// * `proj` - The project to serialize
+    pub fn to_json(proj: &Project) -> Result<String, LojidocError> {
+        let document = ProjectDocument {
+            format_version: FORMAT_VERSION,
+            generator: format!("lojidoc {}", env!("CARGO_PKG_VERSION")),
+            project: proj.clone(),
+        };
+
+        Ok(serde_json::to_string_pretty(&document)?)
+    }
+
+    /// Loads a project from a JSON string created by `to_json`
+    ///
+    /// # Arguments
+    ///
+    /// * `json` - The JSON document
+    pub fn from_json(json: &str) -> Result<Project, LojidocError> {
+        let document: ProjectDocument = serde_json::from_str(json)?;
+
+        if document.format_version != FORMAT_VERSION {
+            return Err(LojidocError::Parse(format!(
+                "Unsupported JSON format version {} (expected {})",
+                document.format_version, FORMAT_VERSION
+            )));
+        }
+
+        Ok(document.project)
+    }
+
+    /// Writes the JSON document of a project to `project.json` in the destination directory
+    ///
+    /// # Arguments
+    ///
+    /// * `proj` - The project to serialize
+    /// * `dest` - The destination directory
+    pub fn write_json(proj: &Project, dest: &str) -> Result<(), LojidocError> {
+        fs::create_dir_all(dest)?;
+        let mut file = File::create(format!("{}/project.json", dest))?;
+        file.write_all(to_json(proj)?.as_bytes())?;
+
+        Ok(())
+    }
+
+    /// Reads a project from a JSON document on disk
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the JSON document
+    pub fn read_json(path: &Path) -> Result<Project, LojidocError> {
+        let mut contents = String::new();
+        File::open(path)?.read_to_string(&mut contents)?;
+
+        from_json(&contents)
+    }
+}
+
+#[cfg(test)]
+mod test;
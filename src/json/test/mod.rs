@@ -0,0 +1,40 @@
+use json::json::*;
+use model::model::*;
+
+#[test]
+fn test_json_round_trip() {
+    let mut object = TypeDecl::new(TypeKind::Class);
+    object.ch_name("Sample".to_string());
+    object.ch_package_name("com.stuff".to_string());
+
+    let mut method = Method::new();
+    method.ch_method_name("run".to_string());
+    method.ch_line_num("12".to_string());
+    method.add_param(Param {
+        desc: "The count".to_string(),
+        name: "count".to_string(),
+        var_type: "int".to_string(),
+    });
+    object.add_method(method);
+
+    let mut project = Project::new();
+    project.add_type(object);
+    project.add_package_doc("com.stuff".to_string(), "The stuff".to_string());
+
+    let json = to_json(&project).unwrap();
+    let loaded = from_json(&json).unwrap();
+
+    assert_eq!("The stuff", loaded.package_doc("com.stuff"));
+    assert_eq!("Sample", loaded.types[0].name.as_str());
+    assert_eq!("com.stuff", loaded.types[0].package_name.as_str());
+    assert_eq!("run", loaded.types[0].methods[0].name.as_str());
+    assert_eq!("12", loaded.types[0].methods[0].line_num.as_str());
+    assert_eq!("int", loaded.types[0].methods[0].parameters[0].var_type.as_str());
+}
+
+#[test]
+fn test_json_version_mismatch() {
+    let json = "{\"format_version\": 0, \"generator\": \"\", \"project\": {\"classes\": [], \"interfaces\": [], \"enumerations\": []}}";
+
+    assert!(from_json(json).is_err());
+}
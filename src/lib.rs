@@ -0,0 +1,361 @@
+//! Lojidoc is a tool for generating documentation for java projects.
+//!
+//! The library exposes the steps the `lojidoc` command line tool is built from.
+//! Java files are parsed into `TypeDecl` structs, one per class, interface or enum,
+//! which are collected into a `Project`. A project can then be rendered with one of the
+//! output backends or linted for missing javadocs. Projects can also be saved as
+//! JSON and loaded again without re-parsing the java sources.
+//!
+//! ```no_run
+//! use std::path::Path;
+//!
+//! let project = lojidoc::project_from_dir(Path::new("./src/main/java")).unwrap();
+//! println!("{}", lojidoc::lint(project, &lojidoc::LintRules::new()));
+//! ```
+
+extern crate colored;
+extern crate git2;
+#[macro_use]
+extern crate handlebars;
+extern crate mdbook;
+extern crate serde;
+#[macro_use]
+extern crate serde_derive;
+extern crate serde_json;
+extern crate threadpool;
+extern crate toml;
+
+pub mod book;
+pub mod deprecated;
+pub mod diagram;
+pub mod diff;
+pub mod document;
+pub mod error;
+pub mod grammar;
+pub mod graph;
+pub mod history;
+pub mod html;
+pub mod index;
+pub mod json;
+pub mod man;
+pub mod model;
+pub mod parse;
+pub mod search;
+pub mod snapshot;
+pub mod site;
+pub mod source;
+pub mod template;
+pub mod versions;
+
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::mpsc;
+use std::sync::Arc;
+use threadpool::ThreadPool;
+
+use diff::diff::diff_projects;
+use diff::diff::read_java_files;
+use document::document::find_file_type;
+use document::document::generate_asciidoc;
+use document::document::generate_markdown;
+use document::document::generate_single_markdown;
+use document::document::lint_project;
+use document::document::resolve_context;
+pub use diff::diff::ApiDiff;
+pub use error::error::LojidocError;
+pub use model::model::Backend;
+pub use model::model::BookConfig;
+pub use model::model::LintRules;
+pub use model::model::Options;
+pub use model::model::Project;
+pub use model::model::SiteProfile;
+pub use model::model::TypeDecl;
+pub use model::model::TypeKind;
+pub use model::model::Visibility;
+pub use model::model::Visitor;
+
+/// Parses java source code and returns the class, interface, enum, record or annotation
+/// declared in it. The contents of a `package-info.java` file are returned as a
+/// declaration without a name whose description documents the package. Source code
+/// declaring neither a type nor a package is a parse error.
+///
+/// # Arguments
+///
+/// * `contents` - The java source code
+pub fn parse_str(contents: &str) -> Result<TypeDecl, LojidocError> {
+    parse::parse::parse_contents(contents)
+}
+
+/// Reads and parses a java file. The file path of the returned object is set to `path`.
+///
+/// # Arguments
+///
+/// * `path` - The path of the java file
+pub fn parse_file(path: &Path) -> Result<TypeDecl, LojidocError> {
+    let mut decl = parse::parse::parse_file(path)?;
+    decl.ch_file_path(path.to_str().unwrap_or("").to_string());
+
+    Ok(decl)
+}
+
+/// Finds all the java files in a directory and its subdirectories
+///
+/// # Arguments
+///
+/// * `dir` - The directory to search, or a single java file
+pub fn find_java_files(dir: &Path) -> Vec<PathBuf> {
+    find_file_type(dir, vec!["java"])
+}
+
+/// Determines whether a java file is the `package-info.java` file documenting its package
+///
+/// # Arguments
+///
+/// * `path` - The path of the java file
+pub fn is_package_info(path: &Path) -> bool {
+    path.file_name().map_or(false, |name| name == "package-info.java")
+}
+
+/// Adds a parsed java file to a project. The description of a `package-info.java` file
+/// becomes the description of its package, every other file declares a type.
+///
+/// # Arguments
+///
+/// * `project` - The project to add the file to
+/// * `path` - The path of the java file
+/// * `decl` - The parsed contents of the file
+fn add_file(project: &mut Project, path: &Path, decl: TypeDecl) {
+    if is_package_info(path) {
+        project.add_package_doc(decl.package_name.trim().to_string(), decl.description);
+    } else {
+        project.add_type(decl);
+    }
+}
+
+/// Parses a list of java files into a project
+///
+/// # Arguments
+///
+/// * `files` - The paths of the java files
+pub fn project_from_files(files: &Vec<PathBuf>) -> Result<Project, LojidocError> {
+    let mut project: Project = Project::new();
+
+    for file in files {
+        add_file(&mut project, &file, parse_file(&file)?);
+    }
+
+    Ok(project)
+}
+
+/// Parses every java file in a directory into a project
+///
+/// # Arguments
+///
+/// * `dir` - The directory containing the java project
+pub fn project_from_dir(dir: &Path) -> Result<Project, LojidocError> {
+    let files = find_java_files(dir);
+
+    if files.len() == 0 {
+        return Err(LojidocError::NoJavaFiles(dir.to_str().unwrap_or("").to_string()));
+    }
+
+    project_from_files(&files)
+}
+
+/// Parses the java files of a git revision into a project. The files are read from the
+/// object database of the repository, so the revision does not have to be checked out.
+///
+/// # Arguments
+///
+/// * `repo` - A directory inside the git repository
+/// * `rev` - The revision, e.g. a tag, branch or commit id
+/// * `path` - The directory of the java sources relative to the repository, or an empty
+/// string for the whole repository
+pub fn project_from_revision(repo: &Path, rev: &str, path: &str) -> Result<Project, LojidocError> {
+    let mut project = Project::new();
+
+    for (file, contents) in read_java_files(repo, rev, path)? {
+        let mut decl = parse::parse::parse_source(&contents, &file)?;
+        decl.ch_file_path(file.clone());
+        add_file(&mut project, Path::new(&file), decl);
+    }
+
+    Ok(project)
+}
+
+/// Compares the API of two git revisions of a project
+///
+/// # Arguments
+///
+/// * `repo` - A directory inside the git repository
+/// * `from` - The older revision
+/// * `to` - The newer revision
+/// * `path` - The directory of the java sources relative to the repository
+/// * `level` - Only the types and members with at least this visibility are compared
+pub fn diff_revisions(
+    repo: &Path,
+    from: &str,
+    to: &str,
+    path: &str,
+    level: Visibility,
+) -> Result<ApiDiff, LojidocError> {
+    let old = project_from_revision(repo, from, path)?;
+    let new = project_from_revision(repo, to, path)?;
+
+    Ok(ApiDiff {
+        from: from.to_string(),
+        to: to.to_string(),
+        changes: diff_projects(&old, &new, level),
+    })
+}
+
+/// Loads a project from a JSON document written by the json backend
+///
+/// # Arguments
+///
+/// * `path` - The path of the JSON document
+pub fn project_from_json(path: &Path) -> Result<Project, LojidocError> {
+    json::json::read_json(path)
+}
+
+/// Renders the documentation for a project into `options.dest`. Only the types and
+/// members visible at `options.visibility` are documented, without the members whose
+/// access modifier is `options.ignore`. If `options.internal_dest` is set a second
+/// build documenting everything is written there. With `options.package_graph` the
+/// package dependency graph of all types is written too. With `options.source_pages`
+/// the markdown and html backends link to highlighted source pages of the java files
+/// which have no web remote. With `options.git_history` or `options.git_authors` the
+/// git history of the java files is read first.
+///
+/// # Arguments
+///
+/// * `project` - The project to document
+/// * `backend` - The output format of the documentation
+/// * `options` - The options containing the destination directory and filters
+pub fn render(mut project: Project, backend: Backend, options: Options) -> Result<(), LojidocError> {
+    if options.git_history || options.git_authors {
+        history::history::add_git_history(&mut project, &options)?;
+    }
+
+    if options.internal_dest != "" {
+        let mut internal = options.clone();
+        internal.dest = options.internal_dest.clone();
+        internal.internal_dest = String::new();
+        internal.visibility = Visibility::Private;
+        internal.ignore = String::new();
+        internal.git_history = false;
+        internal.git_authors = false;
+
+        render(project.clone(), backend.clone(), internal)?;
+    }
+
+    let mut visible = project.with_visibility(options.visibility);
+    if options.ignore != "" {
+        visible = visible.without_access(&options.ignore);
+    }
+    let sources = match backend {
+        Backend::Markdown | Backend::Html if options.source_pages => {
+            source::source::link_source_pages(&mut visible, &options)
+        }
+        _ => Vec::new(),
+    };
+    let graph = if options.package_graph {
+        Some(graph::graph::PackageGraph::new(&project))
+    } else {
+        None
+    };
+    let dest = options.dest.clone();
+    let source_dirs = source::source::source_dirs(&options);
+
+    match backend {
+        Backend::Markdown if options.single_file => generate_single_markdown(visible, options)?,
+        Backend::Markdown => generate_markdown(visible, options)?,
+        Backend::Json => json::json::write_json(&visible, options.dest.as_str())?,
+        Backend::Html if options.single_file => html::html::generate_single_html(visible, options)?,
+        Backend::Html => html::html::generate_html(visible, options)?,
+        Backend::Asciidoc => generate_asciidoc(visible, options)?,
+        Backend::Man => man::man::generate_man(visible, options)?,
+    }
+
+    if let Some(graph) = graph {
+        graph::graph::write_package_graph(&graph, dest.as_str())?;
+    }
+    source::source::write_source_pages(&sources, &source_dirs)?;
+
+    Ok(())
+}
+
+/// Lints the javadocs of a project and returns the report
+///
+/// # Arguments
+///
+/// * `project` - The project to lint
+/// * `rules` - The checks to run
+pub fn lint(project: Project, rules: &LintRules) -> String {
+    lint_project(project, rules)
+}
+
+/// Handles the single threaded option for running the application
+///
+/// # Arguments
+///
+/// * `file_paths` - A vector of the file paths of java files
+/// * `options` - The options passed to the application
+pub fn document_single(file_paths: Vec<PathBuf>, options: Options) -> Result<(), LojidocError> {
+    let project = project_from_files(&file_paths)?;
+
+    let backend = options.backend.clone();
+    render(project, backend, options)
+}
+
+/// Parses the java files on a thread pool, four files per thread, and renders the merged
+/// project once, so the usages and the project-wide pages cover every file. The file
+/// path of every type is set to its web URL when its repository has a remote.
+///
+/// # Arguments
+///
+/// * `file_paths` - A vector of the file paths of java files
+/// * `options` - The options passed to the application
+pub fn document(file_paths: Vec<PathBuf>, options: Options) -> Result<(), LojidocError> {
+    let files = Arc::new(file_paths);
+    let size = files.len();
+    let mut pool_size = size / 4;
+
+    if files.len() % 4 != 0 {
+        pool_size += 1;
+    }
+    let pool = ThreadPool::new(pool_size.max(1));
+    let (sender, receiver) = mpsc::channel();
+
+    for i in 0..pool_size {
+        let file_cp = files.clone();
+        let sender = sender.clone();
+
+        pool.execute(move || {
+            for j in 0..4 {
+                if (i * 4) + j < size {
+                    let file = file_cp[(i * 4) + j].clone();
+                    let parsed = parse_file(&file).map(|mut decl| {
+                        decl.ch_file_path(resolve_context(&file));
+                        decl
+                    });
+
+                    sender.send((file, parsed)).unwrap_or(());
+                }
+            }
+        });
+    }
+    drop(sender);
+
+    let mut parsed: Vec<(PathBuf, Result<TypeDecl, LojidocError>)> = receiver.iter().collect();
+    pool.join();
+    parsed.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut project = Project::new();
+    for (file, decl) in parsed {
+        add_file(&mut project, &file, decl?);
+    }
+
+    let backend = options.backend.clone();
+    render(project, backend, options)
+}
@@ -1,172 +1,537 @@
 extern crate clap;
-extern crate colored;
-extern crate mdbook;
-extern crate threadpool;
-extern crate git2;
+extern crate lojidoc;
+extern crate serde_json;
 
-mod document;
-mod grammar;
-mod model;
-mod parse;
-
-use mdbook::config::Config;
-use mdbook::MDBook;
+use clap::{App, AppSettings, Arg, ArgMatches, SubCommand};
 
 use std::fs;
 use std::path::Path;
-use std::path::PathBuf;
-use std::sync::Arc;
-use threadpool::ThreadPool;
-
-use document::document::find_file_type;
-use document::document::gen_md_book;
-use document::document::generate_markdown;
-use document::document::lint_project;
-use document::document::resolve_context;
-use model::model::Options;
-use model::model::ObjectType;
-use model::model::Project;
-use parse::parse::parse_file;
-
-fn get_project<'a>(files: &Vec<PathBuf>) -> Result<Project, &'a str> {
-    let mut project: Project = Project::new();
-
-    for file in files {
-        match parse_file(&file, true) {
-            ObjectType::Class(mut class) => {
-                class.ch_file_path(file.to_str().unwrap().to_string());
-                project.add_class(class);
-            }
-            ObjectType::Interface(mut inter) => {
-                inter.ch_file_path(file.to_str().unwrap().to_string());
-                project.add_interface(inter)
-            }
-            ObjectType::Enumeration(mut enumeration) => {
-                enumeration.ch_file_path(file.to_str().unwrap().to_string());
-                project.add_enumeration(enumeration);
-            }
-        }
+use std::process;
+
+use lojidoc::diff::diff::gen_diff_report;
+use lojidoc::diff::diff::write_diff;
+use lojidoc::document::document::gen_md_book;
+use lojidoc::snapshot::snapshot::diff_snapshots;
+use lojidoc::snapshot::snapshot::gen_api_snapshot;
+use lojidoc::versions::versions::is_major_bump;
+use lojidoc::Backend;
+use lojidoc::LintRules;
+use lojidoc::Options;
+use lojidoc::SiteProfile;
+use lojidoc::Visibility;
+
+/// Creates the parser of the command line arguments
+fn get_app() -> App<'static, 'static> {
+    App::new("Lojidoc")
+        .version("0.3.1")
+        .author("Josh Brudnak <jobrud314@gmail.com>")
+        .about("A tool for generating markdown documentation for java projects")
+        .setting(AppSettings::SubcommandsNegateReqs)
+        .subcommand(
+            SubCommand::with_name("api")
+                .about("Writes or checks a sorted snapshot of the API with one line per type and member")
+                .arg(
+                    Arg::with_name("INPUT")
+                        .value_name("FILE")
+                        .required(true)
+                        .help("The directory of the java sources, or a project saved with the json format")
+                        .index(1),
+                ).arg(
+                    Arg::with_name("output")
+                        .value_name("FILE")
+                        .required(false)
+                        .short("o")
+                        .long("output")
+                        .help("Writes the snapshot into this file instead of printing it"),
+                ).arg(
+                    Arg::with_name("check")
+                        .value_name("FILE")
+                        .required(false)
+                        .long("check")
+                        .conflicts_with("output")
+                        .help("Fails and prints the differences when the sources do not match this snapshot"),
+                ).arg(
+                    Arg::with_name("visibility")
+                        .value_name("LEVEL")
+                        .required(false)
+                        .long("visibility")
+                        .possible_values(&["public", "protected", "package", "private"])
+                        .help("Only list types and members with at least this visibility, by default public"),
+                ),
+        ).subcommand(
+            SubCommand::with_name("diff")
+                .about("Reports the API changes between two git revisions")
+                .arg(
+                    Arg::with_name("FROM")
+                        .required(true)
+                        .help("The older revision, e.g. a tag or commit")
+                        .index(1),
+                ).arg(
+                    Arg::with_name("TO")
+                        .required(true)
+                        .help("The newer revision")
+                        .index(2),
+                ).arg(
+                    Arg::with_name("repo")
+                        .value_name("DIR")
+                        .required(false)
+                        .long("repo")
+                        .help("A directory inside the git repository, by default the current directory"),
+                ).arg(
+                    Arg::with_name("path")
+                        .value_name("DIR")
+                        .required(false)
+                        .long("path")
+                        .help("The directory of the java sources relative to the repository"),
+                ).arg(
+                    Arg::with_name("visibility")
+                        .value_name("LEVEL")
+                        .required(false)
+                        .long("visibility")
+                        .possible_values(&["public", "protected", "package", "private"])
+                        .help("Only compare types and members with at least this visibility, by default public"),
+                ).arg(
+                    Arg::with_name("destination")
+                        .value_name("DIR")
+                        .required(false)
+                        .short("d")
+                        .help("Writes API-Changes.md and api-changes.json into this directory"),
+                ).arg(
+                    Arg::with_name("format")
+                        .value_name("FORMAT")
+                        .required(false)
+                        .short("f")
+                        .long("format")
+                        .possible_values(&["markdown", "json"])
+                        .help("Sets the format of the report printed without a destination"),
+                ).arg(
+                    Arg::with_name("check")
+                        .required(false)
+                        .long("check")
+                        .help("Fails when a change breaks compatibility without a major version bump"),
+                ).arg(
+                    Arg::with_name("from_version")
+                        .value_name("VERSION")
+                        .required(false)
+                        .long("from-version")
+                        .help("The version of the older revision, by default the revision itself"),
+                ).arg(
+                    Arg::with_name("to_version")
+                        .value_name("VERSION")
+                        .required(false)
+                        .long("to-version")
+                        .help("The version of the newer revision, by default the revision itself"),
+                ),
+        ).arg(
+            Arg::with_name("INPUT")
+                .value_name("FILE")
+                .required(true)
+                .help("Set the input directory to use")
+                .index(1),
+        ).arg(
+            Arg::with_name("visibility")
+                .value_name("LEVEL")
+                .required(false)
+                .long("visibility")
+                .possible_values(&["public", "protected", "package", "private"])
+                .help("Only document types and members with at least this visibility"),
+        ).arg(
+            Arg::with_name("ignore")
+                .value_name("STRING")
+                .required(false)
+                .short("i")
+                .help("Ignore fields with a certain permission"),
+        ).arg(
+            Arg::with_name("internal")
+                .value_name("FILE")
+                .required(false)
+                .long("internal")
+                .help("Also generate documentation of all types and members into this directory"),
+        ).arg(
+            Arg::with_name("templates")
+                .value_name("DIR")
+                .required(false)
+                .short("t")
+                .long("templates")
+                .help("Use the handlebars templates in this directory instead of the default layout"),
+       ).arg(
+           Arg::with_name("include-def")
+               .short("s")
+               .required(false)
+               .help("Include the object, method, and variable signatures in the documentation"),
+        ).arg(
+           Arg::with_name("book")
+                .required(false)
+                .short("b")
+                .help("Use mdbook to create a book for your generated documentation"),
+        ).arg(
+            Arg::with_name("book-dir")
+                .value_name("DIR")
+                .required(false)
+                .long("book-dir")
+                .help("Sets the root directory of the book, by default markdown-book in the destination"),
+        ).arg(
+            Arg::with_name("book-title")
+                .value_name("TITLE")
+                .required(false)
+                .long("book-title")
+                .help("Sets the title of the book"),
+        ).arg(
+            Arg::with_name("book-author")
+                .value_name("NAME")
+                .required(false)
+                .long("book-author")
+                .multiple(true)
+                .number_of_values(1)
+                .help("Adds an author of the book"),
+        ).arg(
+            Arg::with_name("book-language")
+                .value_name("LANG")
+                .required(false)
+                .long("book-language")
+                .help("Sets the language of the book"),
+        ).arg(
+            Arg::with_name("book-description")
+                .value_name("TEXT")
+                .required(false)
+                .long("book-description")
+                .help("Sets the description of the book"),
+        ).arg(
+            Arg::with_name("book-theme")
+                .value_name("DIR")
+                .required(false)
+                .long("book-theme")
+                .help("Sets the theme directory of the book"),
+        ).arg(
+            Arg::with_name("book-css")
+                .value_name("FILE")
+                .required(false)
+                .long("book-css")
+                .multiple(true)
+                .number_of_values(1)
+                .help("Adds a stylesheet to the book, relative to the book's root directory"),
+        ).arg(
+            Arg::with_name("book-js")
+                .value_name("FILE")
+                .required(false)
+                .long("book-js")
+                .multiple(true)
+                .number_of_values(1)
+                .help("Adds a script to the book, relative to the book's root directory"),
+        ).arg(
+            Arg::with_name("book-config")
+                .value_name("FILE")
+                .required(false)
+                .long("book-config")
+                .help("Merges the keys of this toml file into the book.toml of the book"),
+        ).arg(
+            Arg::with_name("lint")
+                .help("Check a java project for incorrect and missing javadocs")
+                .short("l"),
+        ).arg(
+            Arg::with_name("clean")
+                .help("Delete the destination directory before generating documentation")
+                .short("c"),
+        ).arg(
+            Arg::with_name("verbose")
+                .short("v")
+                .help("Generate documentation for a project and provide verbose output"),
+        ).arg(
+            Arg::with_name("multi-thread")
+                .short("m")
+                .help("Use multiple threads to execute the program"),
+        ).arg(
+            Arg::with_name("destination")
+                .required(false)
+                .value_name("FILE")
+                .short("d")
+                .help("Sets the destination directory of the created markdown files"),
+        ).arg(
+            Arg::with_name("single-file")
+                .long("single-file")
+                .help("Generate the API reference as a single markdown or html document"),
+        ).arg(
+            Arg::with_name("diagrams")
+                .long("diagrams")
+                .help("Generate a Mermaid and PlantUML class diagram for every package"),
+        ).arg(
+            Arg::with_name("package-graph")
+                .long("package-graph")
+                .help("Generate the package dependency graph and report cyclic dependencies"),
+        ).arg(
+            Arg::with_name("source-pages")
+                .long("source-pages")
+                .help("Link to highlighted source pages when the project has no git remote"),
+        ).arg(
+            Arg::with_name("git-history")
+                .long("git-history")
+                .help("Show when and by whom types and methods last changed and list the recently changed API"),
+        ).arg(
+            Arg::with_name("git-authors")
+                .long("git-authors")
+                .help("Fill in a missing @author with the author of the first commit of the file"),
+        ).arg(
+            Arg::with_name("recent-commits")
+                .required(false)
+                .value_name("COUNT")
+                .long("recent-commits")
+                .help("Sets the number of commits the recently changed API is read from, by default 10"),
+        ).arg(
+            Arg::with_name("man-section")
+                .required(false)
+                .value_name("SECTION")
+                .long("man-section")
+                .possible_values(&["1", "3"])
+                .help("Sets the manual section of the man pages"),
+        ).arg(
+            Arg::with_name("man-pattern")
+                .required(false)
+                .value_name("PATTERN")
+                .long("man-pattern")
+                .help("Generate man pages for the classes matching this pattern instead of the main classes"),
+        ).arg(
+            Arg::with_name("site")
+                .required(false)
+                .value_name("PROFILE")
+                .long("site")
+                .possible_values(&["plain", "hugo", "jekyll", "mkdocs", "docusaurus"])
+                .help("Lay out the markdown pages for a static site generator"),
+        ).arg(
+            Arg::with_name("format")
+                .required(false)
+                .value_name("FORMAT")
+                .short("f")
+                .long("format")
+                .possible_values(&["markdown", "json", "html", "asciidoc", "man"])
+                .help("Sets the output format of the documentation"),
+        )
+}
+
+/// Parses the command line arguments into the options for the application
+fn get_options(matches: &ArgMatches) -> Options {
+    let mut options = Options::new(
+        matches
+            .value_of("INPUT")
+            .expect("Documentation directory not chosen"),
+    );
+
+    options.clean = matches.is_present("clean");
+    options.lint = matches.is_present("lint");
+    options.include_def = matches.is_present("include-def");
+    options.verbose = matches.is_present("verbose");
+    options.book = matches.is_present("book");
+    options.book_dir = matches.value_of("book-dir").unwrap_or("").to_string();
+    options.book_config.title = matches.value_of("book-title").unwrap_or("").to_string();
+    options.book_config.language = matches.value_of("book-language").unwrap_or("").to_string();
+    options.book_config.description = matches.value_of("book-description").unwrap_or("").to_string();
+    options.book_config.theme = matches.value_of("book-theme").unwrap_or("").to_string();
+    options.book_config.overrides = matches.value_of("book-config").unwrap_or("").to_string();
+    if let Some(authors) = matches.values_of("book-author") {
+        options.book_config.authors = authors.map(|a| a.to_string()).collect();
+    }
+    if let Some(css) = matches.values_of("book-css") {
+        options.book_config.additional_css = css.map(|c| c.to_string()).collect();
+    }
+    if let Some(js) = matches.values_of("book-js") {
+        options.book_config.additional_js = js.map(|j| j.to_string()).collect();
+    }
+    options.single_file = matches.is_present("single-file");
+    options.diagrams = matches.is_present("diagrams");
+    options.package_graph = matches.is_present("package-graph");
+    options.source_pages = matches.is_present("source-pages");
+    options.git_history = matches.is_present("git-history");
+    options.git_authors = matches.is_present("git-authors");
+    if let Some(count) = matches.value_of("recent-commits") {
+        options.recent_commits = count.parse().expect("The number of recent commits is not a number");
+    }
+    options.ignore = matches.value_of("ignore").unwrap_or("").to_string();
+    options.internal_dest = matches.value_of("internal").unwrap_or("").to_string();
+    options.template_dir = matches.value_of("templates").unwrap_or("").to_string();
+    options.multi_thread = matches.is_present("multi-thread");
+    if let Some(dest) = matches.value_of("destination") {
+        options.dest = dest.to_string();
+    }
+    if let Some(visibility) = matches.value_of("visibility") {
+        options.visibility = Visibility::from_name(visibility).expect("Unsupported visibility");
+    }
+    if let Some(section) = matches.value_of("man-section") {
+        options.man_section = section.to_string();
+    }
+    options.man_pattern = matches.value_of("man-pattern").unwrap_or("").to_string();
+    if let Some(site) = matches.value_of("site") {
+        options.site = SiteProfile::from_name(site).expect("Unsupported site profile");
+    }
+    if let Some(format) = matches.value_of("format") {
+        options.backend = Backend::from_name(format).expect("Unsupported output format");
     }
 
-    Ok(project)
+    options
 }
 
-/// Handles the single threaded option for running the application
-///
-/// # Arguments
-///
-/// * `file_paths` - A vector of the file paths of java files
-/// * `dest` - The file path where the markdown will be saved
-/// * `context` - The project context e.g. `github.com/user/repo`
-/// * `ignore` - Permission to ignore when parsing member variables and methods
-/// * `verbose` - Whether the program will output verbose logging
-pub fn document_single(file_paths: Vec<PathBuf>, options: Options) {
-    if options.verbose {
-        println!("{}", lint_project(get_project(&file_paths).unwrap()));
-    }
-
-    generate_markdown(get_project(&file_paths).unwrap(), options);
-
-    println!(
-        "\nDocumentation finished. Generated {} markdown files.",
-        file_paths.len()
+/// Compares the API of two git revisions and prints or writes the report. Returns
+/// whether the check of the compatibility passed, which it always does without `--check`.
+fn diff(matches: &ArgMatches) -> Result<bool, lojidoc::LojidocError> {
+    let level = Visibility::from_name(matches.value_of("visibility").unwrap_or("public"))
+        .expect("Unsupported visibility");
+    let diff = lojidoc::diff_revisions(
+        Path::new(matches.value_of("repo").unwrap_or(".")),
+        matches.value_of("FROM").unwrap_or(""),
+        matches.value_of("TO").unwrap_or(""),
+        matches.value_of("path").unwrap_or(""),
+        level,
+    )?;
+
+    match matches.value_of("destination") {
+        Some(dest) => write_diff(&diff, dest)?,
+        None if matches.value_of("format") == Some("json") => {
+            println!("{}", serde_json::to_string_pretty(&diff)?)
+        }
+        None => print!("{}", gen_diff_report(&diff)),
+    }
+
+    let breaking = diff.changes.iter().filter(|c| c.breaking).count();
+    let from = matches.value_of("from_version").unwrap_or(diff.from.as_str());
+    let to = matches.value_of("to_version").unwrap_or(diff.to.as_str());
+    if !matches.is_present("check") || breaking == 0 || is_major_bump(from, to) {
+        return Ok(true);
+    }
+
+    eprintln!(
+        "{} breaking API changes from {} to {} without a major version bump",
+        breaking, from, to
     );
+    Ok(false)
 }
 
-/// Handles thread pooling the application
-///
-/// # Arguments
-///
-/// * `file_paths` - A vector of the file paths of java files
-/// * `dest` - The file path where the markdown will be saved
-/// * `ignore` - Permission to ignore when parsing member variables and methods
-pub fn document(file_paths: Vec<PathBuf>, options: Options) {
-    let files = Arc::new(file_paths);
-    let opts = Arc::new(options);
-    let size = files.len();
-    let mut pool_size = size / 4;
-
-    if files.len() % 4 != 0 {
-        pool_size += 1;
-    }
-    let pool = ThreadPool::new(pool_size);
-
-    for i in 0..pool_size {
-        let file_cp = files.clone();
-        let options_cp = opts.clone();
-
-        pool.execute(move || {
-            let mut project: Project = Project::new();
-
-            for j in 0..4 {
-                if (i * 4) + j < size {
-                    let mut file = file_cp[(i * 4) + j].clone();
-                    let m_context = resolve_context(&file);
-
-                    match parse_file(&file, options_cp.verbose.clone()) {
-                        ObjectType::Class(mut class) => {
-                            class.ch_file_path(m_context);
-                            project.add_class(class.clone());
-                        }
-                        ObjectType::Interface(mut inter) => {
-                            inter.ch_file_path(m_context);
-                            project.add_interface(inter.clone());
-                        }
-                        ObjectType::Enumeration(mut enumeration) => {
-                            enumeration.ch_file_path(m_context);
-                            project.add_enumeration(enumeration.clone());
-                        }
-                    }
-                }
-            }
+/// Writes, prints or checks the API snapshot of a project. Returns whether the sources
+/// match the snapshot passed with `--check`, which they always do without it.
+fn api(matches: &ArgMatches) -> Result<bool, lojidoc::LojidocError> {
+    let level = Visibility::from_name(matches.value_of("visibility").unwrap_or("public"))
+        .expect("Unsupported visibility");
+    let input = matches.value_of("INPUT").unwrap_or("");
+    let project = if input.ends_with(".json") {
+        lojidoc::project_from_json(Path::new(input))?
+    } else {
+        lojidoc::project_from_dir(Path::new(input))?
+    };
+    let snapshot = gen_api_snapshot(&project, level);
+
+    if let Some(path) = matches.value_of("check") {
+        let changes = diff_snapshots(&fs::read_to_string(path)?, &snapshot);
+        if changes.len() == 0 {
+            return Ok(true);
+        }
 
-            let opts_deref = match Arc::try_unwrap(options_cp) {
-                Ok(res) => res,
-                Err(err) => panic!(err),
-            };
+        println!("The API does not match {}:\n", path);
+        for change in changes {
+            println!("{}", change);
+        }
+        println!("\nRun `lojidoc api {} -o {}` to update the snapshot.", input, path);
+        return Ok(false);
+    }
 
-            generate_markdown(project, opts_deref);
-        });
+    match matches.value_of("output") {
+        Some(path) => fs::write(path, snapshot)?,
+        None => print!("{}", snapshot),
     }
 
-    pool.join();
+    Ok(true)
+}
 
-    println!(
-        "\nDocumentation finished. Generated {} markdown files.",
-        files.len()
-    );
+/// Renders or lints a project previously saved with the json backend
+fn document_json(options: &Options) -> Result<(), lojidoc::LojidocError> {
+    let project = lojidoc::project_from_json(Path::new(options.dir.as_str()))?;
+
+    if options.lint {
+        println!("{}", lojidoc::lint(project, &LintRules::new()));
+        Ok(())
+    } else {
+        lojidoc::render(project, options.backend.clone(), options.clone())
+    }
 }
 
 fn main() {
-    let options = Options::get_options();
-    let file_paths = find_file_type(Path::new(options.dir.clone().as_str()), vec!["java"]);
+    let matches = get_app().get_matches();
+
+    if let Some(matches) = matches.subcommand_matches("api") {
+        match api(matches) {
+            Ok(true) => (),
+            Ok(false) => process::exit(2),
+            Err(err) => {
+                println!("{}", err);
+                process::exit(1);
+            }
+        }
+
+        return;
+    }
+
+    if let Some(matches) = matches.subcommand_matches("diff") {
+        match diff(matches) {
+            Ok(true) => (),
+            Ok(false) => process::exit(2),
+            Err(err) => {
+                println!("{}", err);
+                process::exit(1);
+            }
+        }
 
+        return;
+    }
+
+    let options = get_options(&matches);
+
+    if options.dir.ends_with(".json") {
+        if let Err(err) = document_json(&options) {
+            println!("{}", err);
+            process::exit(1);
+        }
+
+        return;
+    }
+
+    let file_paths = lojidoc::find_java_files(Path::new(options.dir.clone().as_str()));
 
     fs::create_dir_all(options.dest.as_str()).expect("File path not able to be created");
     println!("\nGenerating documentation from {}\n", options.dir);
 
     if file_paths.len() > 0 {
-        if options.book {
-            let mut cfg = Config::default();
-            cfg.book.title = Some(String::from("Application"));
+        // The threaded parse links the types to their remote, the git history and the
+        // source pages read the local files
+        let local_files = options.source_pages || options.git_history || options.git_authors;
+        let count = file_paths.len();
+        let lint = || {
+            lojidoc::project_from_files(&file_paths)
+                .map(|project| println!("{}", lojidoc::lint(project, &LintRules::new())))
+        };
+        let res = if options.lint {
+            lint()
+        } else {
+            let linted = if options.verbose { lint() } else { Ok(()) };
 
-            let init_res = MDBook::init("./markdown-book").with_config(cfg).build();
+            linted.and_then(|_| {
+                if options.multi_thread && options.backend == Backend::Markdown && !local_files {
+                    lojidoc::document(file_paths.clone(), options.clone())
+                } else {
+                    lojidoc::document_single(file_paths.clone(), options.clone())
+                }
+            })
+        };
 
-            if !init_res.is_ok() {
-                println!("Error initializing markdown book");
-            }
+        if let Err(err) = res {
+            println!("{}", err);
+            process::exit(1);
         }
-
-        if options.multi_thread {
-            document(file_paths, options.clone());
-        } else if options.lint {
-            println!("{}", lint_project(get_project(&file_paths).unwrap()));
-        } else {
-            document_single(file_paths, options.clone());
+        if !options.lint {
+            println!("\nDocumentation finished. Generated {} markdown files.", count);
         }
 
-        if options.book.clone() {
-            gen_md_book(options.dest);
+        if options.book {
+            if let Err(err) = gen_md_book(&options) {
+                println!("{}", err);
+                process::exit(1);
+            }
+            println!("Generated the markdown book");
         }
     } else {
         println!("No java files found");
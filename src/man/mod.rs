@@ -0,0 +1,265 @@
+pub mod man {
+    //! Module that renders selected classes of a project as roff man pages
+    //!
+    //! By default every class with a `public static void main(String[])` method is
+    //! documented, which suits the entry points of command line tools. A pattern can
+    //! select classes by name instead. The pages are written to `{dest}/man{section}` so
+    //! the destination directory can be added to the `MANPATH`.
+
+    use std::fs;
+    use std::fs::File;
+    use std::io;
+    use std::io::prelude::*;
+
+    use document::document::remove_old_docs;
+    use model::model::first_sentence;
+    use model::model::Method;
+    use model::model::Options;
+    use model::model::Project;
+    use model::model::TypeDecl;
+    use model::usage::qualified_name;
+
+    /// Escapes text for roff. Backslashes are escaped and lines starting with a
+    /// control character are protected.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text to escape
+    pub fn escape(text: &str) -> String {
+        text.trim()
+            .replace("\\", "\\e")
+            .replace("-", "\\-")
+            .lines()
+            .map(|line| {
+                let line = line.trim();
+                if line.starts_with(".") || line.starts_with("'") {
+                    format!("\\&{}", line)
+                } else {
+                    line.to_string()
+                }
+            }).collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    /// Escapes text for an argument of a roff macro like `.TH` or `.B`. Double quotes
+    /// would end a quoted argument and are written as `\(dq`, and the text is kept on one
+    /// line.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text to escape
+    pub fn escape_arg(text: &str) -> String {
+        escape(&text.replace("\n", " ")).replace("\"", "\\(dq")
+    }
+
+    /// Determines whether a method is a java entry point, `public static void main(String[])`
+    pub fn is_main_method(method: &Method) -> bool {
+        method.name == "main"
+            && method.privacy == "public"
+            && method.modifiers.iter().any(|m| m == "static")
+            && method.parameters.len() == 1
+            && method.parameters[0].var_type.starts_with("String")
+    }
+
+    /// Matches a name against a pattern where `*` matches any sequence of characters
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The pattern, e.g. `com.acme.tools.*` or `*Command`
+    /// * `name` - The name to match
+    pub fn matches_pattern(pattern: &str, name: &str) -> bool {
+        let parts: Vec<&str> = pattern.split("*").collect();
+        let mut rest = name;
+
+        for (i, part) in parts.iter().enumerate() {
+            if i == 0 {
+                if !rest.starts_with(part) {
+                    return false;
+                }
+                rest = &rest[part.len()..];
+            } else if i == parts.len() - 1 {
+                return rest.ends_with(part);
+            } else {
+                match rest.find(part) {
+                    Some(index) => rest = &rest[index + part.len()..],
+                    None => return false,
+                }
+            }
+        }
+
+        rest == ""
+    }
+
+    /// Determines whether a type gets a man page. Without a pattern only the classes
+    /// with a main method are selected, otherwise the pattern is matched against the
+    /// simple and the qualified name of the type.
+    ///
+    /// # Arguments
+    ///
+    /// * `decl` - The type declaration
+    /// * `pattern` - The pattern passed to `--man-pattern`, or an empty string
+    pub fn is_selected(decl: &TypeDecl, pattern: &str) -> bool {
+        if pattern == "" {
+            decl.methods.iter().any(is_main_method)
+        } else {
+            matches_pattern(pattern, &decl.name)
+                || matches_pattern(pattern, &qualified_name(&decl.package_name, &decl.name))
+        }
+    }
+
+    /// Gets the name of the man page of a type. Commands in section 1 use the lower
+    /// case class name, section 3 pages use the qualified name of the type.
+    pub fn page_name(decl: &TypeDecl, section: &str) -> String {
+        if section == "1" {
+            decl.name.trim().to_lowercase()
+        } else {
+            qualified_name(&decl.package_name, &decl.name)
+        }
+    }
+
+    /// Generates the roff source of the man page of a type
+    ///
+    /// # Arguments
+    ///
+    /// * `decl` - The type declaration containing the javadoc data
+    /// * `section` - The manual section, `1` or `3`
+    pub fn gen_man_page(decl: &TypeDecl, section: &str) -> String {
+        let name = page_name(decl, section);
+        let mut doc = String::new();
+        let source = if decl.version != "" {
+            format!("{} {}", decl.name.trim(), decl.version.trim())
+        } else {
+            decl.name.trim().to_string()
+        };
+        let manual = if section == "1" { "User Commands" } else { "Java API" };
+
+        doc.push_str(
+            format!(
+                ".TH \"{}\" \"{}\" \"\" \"{}\" \"{}\"\n",
+                escape_arg(&name.to_uppercase()),
+                escape_arg(section),
+                escape_arg(&source),
+                manual
+            ).as_str(),
+        );
+
+        doc.push_str(".SH NAME\n");
+        if decl.description.trim() != "" {
+            doc.push_str(
+                format!("{} \\- {}\n", escape(&name), escape(&first_sentence(&decl.description))).as_str(),
+            );
+        } else {
+            doc.push_str(format!("{}\n", escape(&name)).as_str());
+        }
+
+        doc.push_str(".SH SYNOPSIS\n");
+        if section == "1" {
+            doc.push_str(
+                format!(
+                    ".B java {}\n.RI [ args ...]\n",
+                    escape_arg(&qualified_name(&decl.package_name, &decl.name))
+                ).as_str(),
+            );
+        } else {
+            doc.push_str(".nf\n");
+            doc.push_str(format!("package {};\n", escape(&decl.package_name)).as_str());
+            doc.push_str(
+                format!(
+                    "{} {}\n",
+                    escape(&decl.access),
+                    escape(&format!("{} {}", decl.kind.label(), decl.name))
+                ).as_str(),
+            );
+            doc.push_str(".fi\n");
+        }
+
+        if decl.description.trim() != "" {
+            doc.push_str(".SH DESCRIPTION\n");
+            doc.push_str(format!("{}\n", escape(&decl.description)).as_str());
+        }
+
+        if section != "1" {
+            let methods: Vec<&Method> = decl
+                .methods
+                .iter()
+                .filter(|m| m.name != "" && m.name != decl.simple_name() && m.privacy == "public")
+                .collect();
+
+            if methods.len() > 0 {
+                doc.push_str(".SH METHODS\n");
+                for method in methods {
+                    let params: Vec<String> = method
+                        .parameters
+                        .iter()
+                        .map(|p| format!("{} {}", p.var_type, p.name))
+                        .collect();
+
+                    doc.push_str(".TP\n");
+                    doc.push_str(
+                        format!(
+                            ".B {}\n",
+                            escape_arg(&format!("{}({})", method.name, params.join(", ")))
+                        ).as_str(),
+                    );
+                    if method.description != "" {
+                        doc.push_str(format!("{}\n", escape(&method.description)).as_str());
+                    }
+                }
+            }
+        }
+
+        if decl.author.trim() != "" {
+            doc.push_str(".SH AUTHOR\n");
+            doc.push_str(format!("{}\n", escape(&decl.author)).as_str());
+        }
+
+        if decl.version.trim() != "" {
+            doc.push_str(".SH VERSION\n");
+            doc.push_str(format!("{}\n", escape(&decl.version)).as_str());
+        }
+
+        if decl.see.len() > 0 {
+            doc.push_str(".SH SEE ALSO\n");
+            doc.push_str(
+                format!(
+                    "{}\n",
+                    decl.see.iter().map(|s| escape(s)).collect::<Vec<String>>().join(",\n")
+                ).as_str(),
+            );
+        }
+
+        doc
+    }
+
+    /// Generates a man page for every selected class of the project into
+    /// `{dest}/man{section}`
+    ///
+    /// # Arguments
+    ///
+    /// * `proj` - The project containing the java documentation data
+    /// * `options` - The options containing the destination, man section and pattern
+    pub fn generate_man(proj: Project, options: Options) -> io::Result<()> {
+        if options.clean {
+            remove_old_docs(options.dest.as_str())?;
+        }
+
+        let section = options.man_section.as_str();
+        let dir = format!("{}/man{}", options.dest, section);
+
+        for decl in proj.types.iter() {
+            if !is_selected(decl, options.man_pattern.as_str()) {
+                continue;
+            }
+
+            fs::create_dir_all(dir.clone())?;
+            let name = format!("{}.{}", page_name(decl, section), section);
+            let mut file = File::create(format!("{}/{}", dir, name))?;
+            file.write_all(gen_man_page(decl, section).as_bytes())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test;
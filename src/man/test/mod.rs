@@ -0,0 +1,63 @@
+use man::man::*;
+use model::model::*;
+
+fn tool() -> TypeDecl {
+    let mut decl = TypeDecl::new(TypeKind::Class);
+    decl.ch_name("SyncTool".to_string());
+    decl.ch_package_name("com.acme.ops".to_string());
+    decl.ch_access("public".to_string());
+    decl.ch_description("Synchronizes the inventory. Runs nightly.".to_string());
+    decl.ch_author("Ops Team".to_string());
+    decl.ch_version("2.1".to_string());
+    decl.add_see("inventory-report".to_string());
+
+    let mut main = Method::new();
+    main.ch_method_name("main".to_string());
+    main.ch_privacy("public".to_string());
+    main.add_modifier("static".to_string());
+    main.ch_return_type("void".to_string());
+    main.add_param(Param {
+        desc: String::new(),
+        name: "args".to_string(),
+        var_type: "String[]".to_string(),
+    });
+    decl.add_method(main);
+
+    decl
+}
+
+#[test]
+fn test_selection() {
+    let decl = tool();
+    let plain = TypeDecl::new(TypeKind::Class);
+
+    assert!(is_selected(&decl, ""));
+    assert!(!is_selected(&plain, ""));
+    assert!(is_selected(&decl, "com.acme.*"));
+    assert!(is_selected(&decl, "*Tool"));
+    assert!(!is_selected(&decl, "com.acme.web.*"));
+}
+
+#[test]
+fn test_gen_man_page() {
+    let page = gen_man_page(&tool(), "1");
+
+    assert!(page.starts_with(".TH \"SYNCTOOL\" \"1\" \"\" \"SyncTool 2.1\" \"User Commands\"\n"));
+    assert!(page.contains(".SH NAME\nsynctool \\- Synchronizes the inventory.\n"));
+    assert!(page.contains(".B java com.acme.ops.SyncTool\n"));
+    assert!(page.contains(".SH AUTHOR\nOps Team\n"));
+    assert!(page.contains(".SH SEE ALSO\ninventory\\-report\n"));
+    assert_eq!(escape(".hidden\nback\\slash"), "\\&.hidden\nback\\eslash");
+}
+
+#[test]
+fn test_header_escapes() {
+    let mut decl = tool();
+    decl.ch_name("Sync\"Tool".to_string());
+    decl.ch_version(".2".to_string());
+    let page = gen_man_page(&decl, "1");
+
+    assert!(page.starts_with(".TH \"SYNC\\(dqTOOL\" \"1\" \"\" \"Sync\\(dqTool .2\" \"User Commands\"\n"));
+    assert!(page.contains(".B java com.acme.ops.Sync\\(dqTool\n"));
+    assert_eq!(escape_arg(".hidden \"name\""), "\\&.hidden \\(dqname\\(dq");
+}
@@ -0,0 +1,37 @@
+use model::member::Member;
+use model::method::Method;
+use model::type_decl::EnumField;
+use model::type_decl::TypeDecl;
+
+/// Creates the link of the page of a type from the type and the anchor of one of its
+/// members, which is empty for the type itself. Every backend passes its own, so the
+/// reports link to pages the way the backend writes them.
+pub type LinkFn<'a> = dyn Fn(&TypeDecl, &str) -> String + 'a;
+
+/// Creates the anchor id of a member variable
+pub fn field_anchor(member: &Member) -> String {
+    format!("field.{}", member.name)
+}
+
+/// Creates the anchor id of an enum constant
+pub fn constant_anchor(field: &EnumField) -> String {
+    format!("constant.{}", field.name)
+}
+
+/// Creates the anchor id of a method. Overloaded methods are numbered in the
+/// order they are declared.
+///
+/// # Arguments
+///
+/// * `methods` - All the methods of the type
+/// * `index` - The index of the method in `methods`
+pub fn method_anchor(methods: &Vec<Method>, index: usize) -> String {
+    let name = &methods[index].name;
+    let overloads = methods[..index].iter().filter(|m| &m.name == name).count();
+
+    if overloads == 0 {
+        format!("method.{}", name)
+    } else {
+        format!("method.{}-{}", name, overloads)
+    }
+}
@@ -0,0 +1,29 @@
+#[derive(Debug, Clone)]
+/// Struct for the settings of the markdown book. Empty values keep the setting of
+/// an existing `book.toml`.
+pub struct BookConfig {
+    pub title: String,
+    pub authors: Vec<String>,
+    pub language: String,
+    pub description: String,
+    pub theme: String,
+    pub additional_css: Vec<String>,
+    pub additional_js: Vec<String>,
+    pub overrides: String,
+}
+
+impl BookConfig {
+    /// Creates the settings of a book without any configured values
+    pub fn new() -> BookConfig {
+        BookConfig {
+            title: String::new(),
+            authors: Vec::new(),
+            language: String::new(),
+            description: String::new(),
+            theme: String::new(),
+            additional_css: Vec::new(),
+            additional_js: Vec::new(),
+            overrides: String::new(),
+        }
+    }
+}
@@ -1,15 +1,55 @@
+use model::doc::first_sentence;
+use model::type_decl::TypeDecl;
+use model::type_decl::TypeKind;
+
+#[derive(Clone)]
+/// Struct for the row of a type in the overview page of its package
+pub struct TypeSummary {
+    pub name: String,
+    pub kind: TypeKind,
+    pub summary: String,
+    pub deprecated: bool,
+    /// The first sentence of the reason the type is deprecated
+    pub deprecation: String,
+}
+
+impl TypeSummary {
+    /// Creates the overview of a type from the first sentence of its description
+    pub fn new(decl: &TypeDecl) -> TypeSummary {
+        TypeSummary {
+            name: decl.name.trim().to_string(),
+            kind: decl.kind.clone(),
+            summary: first_sentence(&decl.description),
+            deprecated: decl.deprecation.is_some(),
+            deprecation: decl.deprecation.as_ref().map_or(String::new(), |d| first_sentence(&d.reason)),
+        }
+    }
+}
+
 #[derive(Clone)]
 /// Struct for a java package. stores the name and member files
 pub struct Package {
     pub name: String,
     pub package_path: String,
     pub members: Vec<String>,
+    pub diagram: String,
+    pub description: String,
+    pub summaries: Vec<TypeSummary>,
 }
 
 impl Package {
     pub fn add_class(&mut self, class_name: String) {
         self.members.push(class_name);
     }
+    pub fn ch_diagram(&mut self, value: String) {
+        self.diagram = value;
+    }
+    pub fn ch_description(&mut self, value: String) {
+        self.description = value;
+    }
+    pub fn add_summary(&mut self, value: TypeSummary) {
+        self.summaries.push(value);
+    }
 }
 
 /// Struct representing all the application data
@@ -46,7 +86,16 @@ impl ApplicationDoc {
                 name: package,
                 package_path: dir,
                 members: vec![class],
+                diagram: String::new(),
+                description: String::new(),
+                summaries: Vec::new(),
             });
         }
     }
+    /// Adds the overview of a type to its package, which must already be listed
+    pub fn add_type_summary(&mut self, package: &str, summary: TypeSummary) {
+        for p in self.packages.iter_mut().filter(|p| p.name == package) {
+            p.add_summary(summary.clone());
+        }
+    }
 }
@@ -0,0 +1,44 @@
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// Struct representing the deprecation of a type or member, from its `@deprecated`
+/// javadoc tag and its `@Deprecated` annotation
+pub struct Deprecation {
+    pub reason: String,
+    pub since: String,
+    pub for_removal: bool,
+}
+
+impl Deprecation {
+    pub fn new() -> Deprecation {
+        Deprecation {
+            reason: String::new(),
+            since: String::new(),
+            for_removal: false,
+        }
+    }
+    /// Reads the elements of a `@Deprecated` annotation
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - The text between the parentheses of the annotation, e.g. `since = "9", forRemoval = true`
+    pub fn from_annotation(args: &str) -> Deprecation {
+        let mut deprecation = Deprecation::new();
+
+        for element in args.split(",") {
+            let parts: Vec<&str> = element.splitn(2, "=").map(|p| p.trim()).collect();
+            if parts.len() < 2 {
+                continue;
+            }
+
+            match parts[0] {
+                "since" => deprecation.since = parts[1].trim_matches('"').to_string(),
+                "forRemoval" => deprecation.for_removal = parts[1] == "true",
+                _ => (),
+            }
+        }
+
+        deprecation
+    }
+    pub fn ch_reason(&mut self, value: String) {
+        self.reason = value;
+    }
+}
@@ -2,16 +2,17 @@ use model::exception::Exception;
 use model::method::Param;
 
 /// Struct representing data contained in javadoc comments
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Doc {
     pub params: Vec<Param>,
     pub description: String,
     pub author: String,
     pub version: String,
+    pub since: String,
     pub exceptions: Vec<Exception>,
     pub deprecated: String,
     pub return_desc: String,
-    pub see: String,
+    pub see: Vec<String>,
 }
 
 impl Doc {
@@ -22,9 +23,22 @@ impl Doc {
             return_desc: String::new(),
             author: String::new(),
             version: String::new(),
+            since: String::new(),
             exceptions: Vec::new(),
             deprecated: String::new(),
-            see: String::new(),
+            see: Vec::new(),
         }
     }
 }
+
+/// Gets the first sentence of a description, which is used as its summary
+///
+/// # Arguments
+///
+/// * `description` - The description from a javadoc comment
+pub fn first_sentence(description: &str) -> String {
+    match description.trim().find(". ") {
+        Some(index) => description.trim()[..index + 1].to_string(),
+        None => description.trim().to_string(),
+    }
+}
@@ -0,0 +1,22 @@
+#[derive(Debug, Clone)]
+/// Struct for the set of checks run when linting a project's javadocs
+pub struct LintRules {
+    pub method_description: bool,
+    pub method_return: bool,
+    pub param_description: bool,
+    pub var_description: bool,
+    pub var_type: bool,
+}
+
+impl LintRules {
+    /// Creates a rule set with every check enabled
+    pub fn new() -> LintRules {
+        LintRules {
+            method_description: true,
+            method_return: true,
+            param_description: true,
+            var_description: true,
+            var_type: true,
+        }
+    }
+}
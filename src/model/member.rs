@@ -1,4 +1,6 @@
-#[derive(Debug)]
+use model::deprecation::Deprecation;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 /// Struct representing member variable data contained in javadoc and declaration
 pub struct Member {
     pub line_num: String,
@@ -8,20 +10,11 @@ pub struct Member {
     pub access: String,
     pub name: String,
     pub var_type: String,
+    pub deprecation: Option<Deprecation>,
+    pub since: String,
 }
 
 impl Member {
-    pub fn clone(&self) -> Member {
-        Member {
-            line_num: self.line_num.clone(),
-            signature: self.signature.clone(),
-            desc: self.desc.clone(),
-            access: self.access.clone(),
-            name: self.name.clone(),
-            modifiers: self.modifiers.clone(),
-            var_type: self.var_type.clone(),
-        }
-    }
     pub fn new() -> Member {
         Member {
             line_num: String::new(),
@@ -31,6 +24,8 @@ impl Member {
             name: String::new(),
             modifiers: Vec::new(),
             var_type: String::new(),
+            deprecation: None,
+            since: String::new(),
         }
     }
     pub fn ch_name(&mut self, value: String) {
@@ -42,6 +37,9 @@ impl Member {
     pub fn ch_access(&mut self, value: String) {
         self.access = value;
     }
+    pub fn ch_desc(&mut self, value: String) {
+        self.desc = value;
+    }
     pub fn ch_type(&mut self, value: String) {
         self.var_type = value;
     }
@@ -51,4 +49,10 @@ impl Member {
     pub fn ch_line_number(&mut self, value: String) {
         self.line_num = value;
     }
+    pub fn ch_deprecation(&mut self, value: Option<Deprecation>) {
+        self.deprecation = value;
+    }
+    pub fn ch_since(&mut self, value: String) {
+        self.since = value;
+    }
 }
@@ -1,6 +1,8 @@
+use model::deprecation::Deprecation;
 use model::exception::Exception;
+use model::revision::Revision;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 /// Struct representing method parameter data contained in javadoc and method declaration
 pub struct Param {
     pub desc: String,
@@ -8,7 +10,7 @@ pub struct Param {
     pub var_type: String,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 /// Struct containing method data from the javadoc and method declaration
 pub struct Method {
     pub line_num: String,
@@ -19,7 +21,14 @@ pub struct Method {
     pub privacy: String,
     pub description: String,
     pub exceptions: Vec<Exception>,
+    pub see: Vec<String>,
     pub return_type: String,
+    /// The description of the returned value from the `@return` tag
+    pub return_desc: String,
+    pub deprecation: Option<Deprecation>,
+    pub since: String,
+    /// The commit that last changed the lines of the method, read from the git history
+    pub last_change: Option<Revision>,
 }
 
 impl Method {
@@ -28,39 +37,17 @@ impl Method {
             parameters: Vec::new(),
             modifiers: Vec::new(),
             exceptions: Vec::new(),
+            see: Vec::new(),
             line_num: String::new(),
             name: String::new(),
             signature: String::new(),
             privacy: String::new(),
             description: String::new(),
             return_type: String::new(),
-        }
-    }
-    pub fn clone(&mut self) -> Method {
-        let mut new_params = Vec::new();
-        let mut new_excepts = Vec::new();
-        let mut new_modifiers = Vec::new();
-
-        for i in 0..self.parameters.len() {
-            new_params.push(self.parameters[i].clone());
-        }
-        for i in 0..self.exceptions.len() {
-            new_excepts.push(self.exceptions[i].clone());
-        }
-        for i in 0..self.modifiers.len() {
-            new_modifiers.push(self.modifiers[i].clone());
-        }
-
-        Method {
-            line_num: self.line_num.clone(),
-            parameters: new_params,
-            modifiers: new_modifiers,
-            exceptions: new_excepts,
-            name: self.name.clone(),
-            signature: self.signature.clone(),
-            privacy: self.privacy.clone(),
-            description: self.description.clone(),
-            return_type: self.return_type.clone(),
+            return_desc: String::new(),
+            deprecation: None,
+            since: String::new(),
+            last_change: None,
         }
     }
     pub fn ch_line_num(&mut self, value: String) {
@@ -93,4 +80,19 @@ impl Method {
     pub fn ch_return_type(&mut self, value: String) {
         self.return_type = value;
     }
+    pub fn ch_return_desc(&mut self, value: String) {
+        self.return_desc = value;
+    }
+    pub fn add_see(&mut self, value: String) {
+        self.see.push(value);
+    }
+    pub fn ch_deprecation(&mut self, value: Option<Deprecation>) {
+        self.deprecation = value;
+    }
+    pub fn ch_last_change(&mut self, value: Option<Revision>) {
+        self.last_change = value;
+    }
+    pub fn ch_since(&mut self, value: String) {
+        self.since = value;
+    }
 }
@@ -1,38 +1,56 @@
-pub mod class;
+pub mod anchor;
+pub mod book;
 pub mod contents;
+pub mod deprecation;
 pub mod doc;
-pub mod enumeration;
 pub mod exception;
-pub mod interface;
+pub mod lint;
 pub mod member;
 pub mod method;
-pub mod object;
-pub mod project;
 pub mod options;
+pub mod project;
+pub mod revision;
+pub mod signature;
+pub mod type_decl;
+pub mod usage;
+pub mod visibility;
+pub mod visitor;
 
 pub mod model {
     //! Module that contains all necessary data stuctures for parsing javadocs and generating docs
 
-    pub use model::class::Class;
+    pub use model::anchor::constant_anchor;
+    pub use model::anchor::field_anchor;
+    pub use model::anchor::method_anchor;
+    pub use model::anchor::LinkFn;
+    pub use model::book::BookConfig;
     pub use model::contents::ApplicationDoc;
+    pub use model::contents::Package;
+    pub use model::contents::TypeSummary;
+    pub use model::deprecation::Deprecation;
+    pub use model::doc::first_sentence;
     pub use model::doc::Doc;
-    pub use model::enumeration::Enumeration;
-    pub use model::enumeration::EnumField;
     pub use model::exception::Exception;
-    pub use model::interface::Interface;
+    pub use model::lint::LintRules;
     pub use model::member::Member;
     pub use model::method::Param;
     pub use model::method::Method;
-    pub use model::object::Object;
-    pub use model::object::ObjectState;
+    pub use model::options::Backend;
     pub use model::options::Options;
+    pub use model::options::SiteProfile;
     pub use model::project::Project;
-
-    pub enum ObjectType {
-        Class(Class),
-        Interface(Interface),
-        Enumeration(Enumeration),
-    }
+    pub use model::revision::Revision;
+    pub use model::signature::strip_annotations;
+    pub use model::type_decl::EnumField;
+    pub use model::type_decl::TypeDecl;
+    pub use model::type_decl::TypeKind;
+    pub use model::usage::Usage;
+    pub use model::usage::UsageIndex;
+    pub use model::usage::UsageKind;
+    pub use model::visibility::Visibility;
+    pub use model::visitor::walk_project;
+    pub use model::visitor::walk_type;
+    pub use model::visitor::Visitor;
 }
 
 #[cfg(test)]
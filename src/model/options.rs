@@ -1,4 +1,53 @@
-use clap::{ App, Arg};
+use model::book::BookConfig;
+use model::visibility::Visibility;
+
+#[derive(Debug, Clone, PartialEq)]
+/// The output formats a project can be rendered to
+pub enum Backend {
+    Markdown,
+    Json,
+    Html,
+    Asciidoc,
+    Man,
+}
+
+impl Backend {
+    /// Gets the backend for the name passed to the `--format` option
+    pub fn from_name(name: &str) -> Option<Backend> {
+        match name {
+            "markdown" | "md" => Some(Backend::Markdown),
+            "json" => Some(Backend::Json),
+            "html" => Some(Backend::Html),
+            "asciidoc" | "adoc" => Some(Backend::Asciidoc),
+            "man" => Some(Backend::Man),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// The static site generators the markdown pages can be laid out for
+pub enum SiteProfile {
+    Plain,
+    Hugo,
+    Jekyll,
+    Mkdocs,
+    Docusaurus,
+}
+
+impl SiteProfile {
+    /// Gets the site profile for the name passed to the `--site` option
+    pub fn from_name(name: &str) -> Option<SiteProfile> {
+        match name {
+            "plain" => Some(SiteProfile::Plain),
+            "hugo" => Some(SiteProfile::Hugo),
+            "jekyll" => Some(SiteProfile::Jekyll),
+            "mkdocs" => Some(SiteProfile::Mkdocs),
+            "docusaurus" => Some(SiteProfile::Docusaurus),
+            _ => None,
+        }
+    }
+}
 
 #[derive(Debug, Clone)]
 /// Struct for flags and options passed to Lojidoc
@@ -9,79 +58,69 @@ pub struct Options {
     pub multi_thread: bool,
     pub verbose: bool,
     pub book: bool,
+    pub single_file: bool,
+    pub book_dir: String,
+    pub book_config: BookConfig,
+    pub diagrams: bool,
+    pub package_graph: bool,
+    pub source_pages: bool,
+    pub git_history: bool,
+    pub git_authors: bool,
+    /// The number of latest commits the recently changed API is read from
+    pub recent_commits: usize,
     pub dest: String,
     pub dir: String,
+    pub internal_dest: String,
+    /// Member variables and methods with this access modifier are skipped, if not empty
     pub ignore: String,
+    pub template_dir: String,
+    pub man_section: String,
+    pub man_pattern: String,
+    pub visibility: Visibility,
+    pub backend: Backend,
+    pub site: SiteProfile,
 }
 
 impl Options {
-    pub fn get_options() -> Options {
-        let matches = App::new("Lojidoc")
-        .version("0.3.1")
-        .author("Josh Brudnak <jobrud314@gmail.com>")
-        .about("A tool for generating markdown documentation for java projects")
-        .arg(
-            Arg::with_name("INPUT")
-                .value_name("FILE")
-                .required(true)
-                .help("Set the input directory to use")
-                .index(1),
-        ).arg(
-            Arg::with_name("ignore")
-                .value_name("STRING")
-                .required(false)
-                .short("i")
-                .help("Ignore fields with a certain permission"),
-       ).arg(
-           Arg::with_name("include-def")
-               .short("s")
-               .required(false)
-               .help("Include the object, method, and variable signatures in the documentation"),
-        ).arg(
-           Arg::with_name("book")
-                .required(false)
-                .short("b")
-                .help("Use mdbook to create a book for your generated documentation"),
-        ).arg(
-            Arg::with_name("lint")
-                .help("Check a java project for incorrect and missing javadocs")
-                .short("l"),
-        ).arg(
-            Arg::with_name("clean")
-                .help("Delete the destination directory before generating documentation")
-                .short("c"),
-        ).arg(
-            Arg::with_name("verbose")
-                .short("v")
-                .help("Generate documentation for a project and provide verbose output"),
-        ).arg(
-            Arg::with_name("multi-thread")
-                .short("m")
-                .help("Use multiple threads to execute the program"),
-        ).arg(
-            Arg::with_name("destination")
-                .required(false)
-                .value_name("FILE")
-                .short("d")
-                .help("Sets the destination directory of the created markdown files"),
-        ).get_matches();
-
+    /// Creates the default options for documenting the java files in `dir`.
+    /// The documentation is written to `./generated/`.
+    pub fn new(dir: &str) -> Options {
         Options {
-            clean: matches.is_present("clean"),
-            lint: matches.is_present("lint"),
-            include_def: matches.is_present("include_def"),
-            verbose: matches.is_present("verbose"),
-            book: matches.is_present("book"),
-            ignore: matches.value_of("ignore").unwrap_or("").to_string(),
-            multi_thread: matches.is_present("multi_thread"),
-            dest: matches
-                .value_of("destination")
-                .unwrap_or("./generated/")
-                .to_string(),
-            dir: matches
-                .value_of("INPUT")
-                .expect("Documentation directory not chosen")
-                .to_string(),
+            clean: false,
+            lint: false,
+            include_def: false,
+            multi_thread: false,
+            verbose: false,
+            book: false,
+            single_file: false,
+            book_dir: String::new(),
+            book_config: BookConfig::new(),
+            diagrams: false,
+            package_graph: false,
+            source_pages: false,
+            git_history: false,
+            git_authors: false,
+            recent_commits: 10,
+            dest: String::from("./generated/"),
+            dir: dir.to_string(),
+            internal_dest: String::new(),
+            ignore: String::new(),
+            template_dir: String::new(),
+            man_section: String::from("1"),
+            man_pattern: String::new(),
+            visibility: Visibility::Private,
+            backend: Backend::Markdown,
+            site: SiteProfile::Plain,
+        }
+    }
+
+    /// Gets the root directory of the markdown book, `{dest}/markdown-book` unless
+    /// `book_dir` is set
+    pub fn book_root(&self) -> String {
+        if self.book_dir != "" {
+            self.book_dir.clone()
+        } else {
+            format!("{}/markdown-book", self.dest.trim_end_matches('/'))
         }
     }
 }
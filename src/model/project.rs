@@ -1,29 +1,132 @@
-use model::class::Class;
-use model::enumeration::Enumeration;
-use model::interface::Interface;
+use std::collections::BTreeMap;
+use std::mem;
 
+use model::revision::Revision;
+use model::type_decl::TypeDecl;
+use model::type_decl::TypeKind;
+use model::visibility::Visibility;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 /// Struct representing all the project data
 pub struct Project {
-    pub classes: Vec<Class>,
-    pub interfaces: Vec<Interface>,
-    pub enumerations: Vec<Enumeration>,
+    pub types: Vec<TypeDecl>,
+    /// The descriptions of the packages from their `package-info.java` files
+    pub package_docs: BTreeMap<String, String>,
+    /// The latest commits of the repository, newest first, when the git history was read
+    pub recent_commits: Vec<Revision>,
 }
 
 impl Project {
     pub fn new() -> Project {
         Project {
-            classes: Vec::new(),
-            interfaces: Vec::new(),
-            enumerations: Vec::new(),
+            types: Vec::new(),
+            package_docs: BTreeMap::new(),
+            recent_commits: Vec::new(),
         }
     }
-    pub fn add_class(&mut self, value: Class) {
-        self.classes.push(value);
+    /// Adds a type declaration and the types nested in it, which share its file
+    pub fn add_type(&mut self, mut value: TypeDecl) {
+        let nested = mem::take(&mut value.nested_types);
+        let file_path = value.file_path.clone();
+        self.types.push(value);
+
+        for mut decl in nested {
+            decl.ch_file_path(file_path.clone());
+            self.add_type(decl);
+        }
+    }
+    pub fn add_package_doc(&mut self, package: String, description: String) {
+        self.package_docs.insert(package, description);
+    }
+    /// Gets the description of a package, empty when it has no `package-info.java`
+    pub fn package_doc(&self, package: &str) -> &str {
+        self.package_docs.get(package.trim()).map_or("", |d| d.as_str())
+    }
+    /// Gets the type declarations of a certain kind
+    pub fn of_kind(&self, kind: TypeKind) -> Vec<&TypeDecl> {
+        self.types.iter().filter(|t| t.kind == kind).collect()
+    }
+    /// Gets the visibility of a type declaration. A nested type is never more visible
+    /// than the type enclosing it.
+    ///
+    /// # Arguments
+    ///
+    /// * `decl` - A type declaration of the project
+    pub fn type_visibility(&self, decl: &TypeDecl) -> Visibility {
+        let enclosing = self
+            .types
+            .iter()
+            .find(|t| decl.enclosing != "" && t.name == decl.enclosing && t.package_name == decl.package_name);
+
+        match enclosing {
+            Some(outer) => {
+                let own = Visibility::of_member(outer, &decl.access);
+                let outer = self.type_visibility(outer);
+
+                if outer < own {
+                    outer
+                } else {
+                    own
+                }
+            }
+            None => Visibility::of_type(decl),
+        }
     }
-    pub fn add_interface(&mut self, value: Interface) {
-        self.interfaces.push(value);
+    /// Creates a copy of the project with only the types, member variables and methods
+    /// visible at the threshold. Members of hidden types are hidden with them, and so
+    /// are the types nested in them.
+    ///
+    /// # Arguments
+    ///
+    /// * `threshold` - The least visible access level to keep
+    pub fn with_visibility(&self, threshold: Visibility) -> Project {
+        let mut project = Project::new();
+        project.package_docs = self.package_docs.clone();
+        project.recent_commits = self.recent_commits.clone();
+
+        for decl in self.types.iter() {
+            if self.type_visibility(decl) < threshold {
+                continue;
+            }
+
+            let mut visible = decl.clone();
+            visible.nested.retain(|name| {
+                self.types
+                    .iter()
+                    .any(|t| &t.name == name && t.package_name == decl.package_name && self.type_visibility(t) >= threshold)
+            });
+            visible.variables = decl
+                .variables
+                .iter()
+                .filter(|v| Visibility::of_member(decl, &v.access) >= threshold)
+                .cloned()
+                .collect();
+            visible.methods = decl
+                .methods
+                .iter()
+                .filter(|m| Visibility::of_member(decl, &m.privacy) >= threshold)
+                .cloned()
+                .collect();
+
+            project.add_type(visible);
+        }
+
+        project
     }
-    pub fn add_enumeration(&mut self, value: Enumeration) {
-        self.enumerations.push(value);
+    /// Creates a copy of the project without the member variables and methods declared
+    /// with an access modifier, e.g. `private`
+    ///
+    /// # Arguments
+    ///
+    /// * `access` - The access modifier of the members to skip
+    pub fn without_access(&self, access: &str) -> Project {
+        let mut project = self.clone();
+
+        for decl in project.types.iter_mut() {
+            decl.variables.retain(|v| v.access != access);
+            decl.methods.retain(|m| m.privacy != access);
+        }
+
+        project
     }
 }
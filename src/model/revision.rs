@@ -0,0 +1,22 @@
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// Struct representing a commit of the git history of a project
+pub struct Revision {
+    /// The abbreviated id of the commit
+    pub commit: String,
+    pub author: String,
+    /// The date of the commit as `YYYY-MM-DD`
+    pub date: String,
+    /// The first line of the commit message
+    pub summary: String,
+}
+
+impl Revision {
+    pub fn new() -> Revision {
+        Revision {
+            commit: String::new(),
+            author: String::new(),
+            date: String::new(),
+            summary: String::new(),
+        }
+    }
+}
@@ -0,0 +1,49 @@
+/// Removes the annotations and their elements from a signature and collapses its whitespace
+///
+/// # Arguments
+///
+/// * `signature` - The signature of a type or member
+pub fn strip_annotations(signature: &str) -> String {
+    let mut text = String::new();
+    let mut chars = signature.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        if ch != '@' {
+            text.push(ch);
+            continue;
+        }
+
+        let mut name = String::new();
+        while let Some(&c) = chars.peek() {
+            if c.is_alphanumeric() || c == '_' || c == '.' {
+                name.push(c);
+                chars.next();
+            } else {
+                break;
+            }
+        }
+        if name == "interface" {
+            text.push_str("@interface");
+            continue;
+        }
+
+        while chars.peek() == Some(&' ') {
+            chars.next();
+        }
+        if chars.peek() == Some(&'(') {
+            let mut depth = 0;
+            while let Some(c) = chars.next() {
+                if c == '(' {
+                    depth += 1;
+                } else if c == ')' {
+                    depth -= 1;
+                    if depth == 0 {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
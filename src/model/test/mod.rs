@@ -1,13 +1,14 @@
 use model::model::*;
+use parse::parse::parse_contents;
 
 #[test]
 fn test_class_impl() {
-    let mut object = Object::new();
+    let mut object = TypeDecl::new(TypeKind::Class);
     object.ch_name(String::from("sample"));
     object.ch_package_name("com.stuff.package".to_string());
     object.ch_access("private".to_string());
     object.ch_author("jim smith".to_string());
-    let class = object.to_class();
+    let class = object.clone();
 
     assert_eq!("sample", object.name.as_str());
     assert_eq!("private", object.access.as_str());
@@ -17,6 +18,22 @@ fn test_class_impl() {
     assert_eq!("private", class.access.as_str());
 }
 
+#[test]
+fn test_type_kind() {
+    let mut decl = TypeDecl::new(TypeKind::Interface);
+    decl.ch_name(String::from("Shape"));
+
+    assert_eq!(TypeKind::Interface, decl.kind);
+    assert_eq!("Interface", decl.kind.title());
+
+    decl.ch_kind(TypeKind::Enumeration);
+
+    assert_eq!(TypeKind::Enumeration, decl.kind);
+    assert_eq!("Enum", decl.kind.title());
+    assert_eq!("Enums", decl.kind.plural());
+    assert_eq!("Shape", decl.name.as_str());
+}
+
 #[test]
 fn test_method_impl() {
     let mut method = Method::new();
@@ -29,3 +46,197 @@ fn test_method_impl() {
     assert_eq!("sample2", method2.name.as_str());
     assert_eq!("private", method2.privacy.as_str());
 }
+
+#[test]
+fn test_usage_index() {
+    let mut order = TypeDecl::new(TypeKind::Class);
+    order.ch_name("Order".to_string());
+    order.ch_package_name("com.shop.model".to_string());
+
+    let mut service = TypeDecl::new(TypeKind::Interface);
+    service.ch_name("OrderService".to_string());
+    service.ch_package_name("com.shop.service".to_string());
+    service.add_dependency("com.shop.model.Order".to_string());
+
+    let mut member = Member::new();
+    member.ch_name("orders".to_string());
+    member.ch_type("List<Order>".to_string());
+    service.add_variable(member);
+
+    let mut method = Method::new();
+    method.ch_method_name("find".to_string());
+    method.ch_return_type("Order".to_string());
+    service.add_method(method);
+
+    let mut project = Project::new();
+    project.add_type(order);
+    project.add_type(service);
+
+    let index = UsageIndex::new(&project);
+    let usages = index.get("com.shop.model", "Order");
+
+    assert_eq!(3, usages.len());
+    assert_eq!(UsageKind::Import, usages[0].kind);
+    assert_eq!(UsageKind::Field, usages[1].kind);
+    assert_eq!("orders", usages[1].member.as_str());
+    assert_eq!("field.orders", usages[1].anchor.as_str());
+    assert_eq!(UsageKind::Return, usages[2].kind);
+    assert_eq!("OrderService", usages[2].type_name.as_str());
+    assert_eq!("method.find", usages[2].anchor.as_str());
+    assert_eq!("", usages[0].anchor.as_str());
+    assert_eq!(0, index.get("com.shop.service", "OrderService").len());
+}
+
+#[test]
+fn test_usage_of_documented_return() {
+    let order = parse_contents("package com.shop.model;\npublic class Order {\n}\n").unwrap();
+    let service = parse_contents(
+        "package com.shop.service;\n\nimport com.shop.model.Order;\n\npublic interface OrderService {\n    \
+         /**\n     * Finds an order\n     * @param id The id of the order\n     * @return The order or null\n     \
+         */\n    Order find(long id);\n}\n",
+    ).unwrap();
+
+    let mut project = Project::new();
+    project.add_type(order);
+    project.add_type(service);
+
+    let index = UsageIndex::new(&project);
+    let usages = index.get("com.shop.model", "Order");
+
+    assert_eq!(2, usages.len());
+    assert_eq!(UsageKind::Import, usages[0].kind);
+    assert_eq!(UsageKind::Return, usages[1].kind);
+    assert_eq!("find", usages[1].member.as_str());
+    assert_eq!("method.find", usages[1].anchor.as_str());
+}
+
+#[test]
+fn test_visitor_walk() {
+    struct Counter {
+        types: usize,
+        members: usize,
+        methods: usize,
+    }
+
+    impl Visitor for Counter {
+        fn visit_type(&mut self, _decl: &TypeDecl) {
+            self.types += 1;
+        }
+        fn visit_member(&mut self, _decl: &TypeDecl, _member: &Member) {
+            self.members += 1;
+        }
+        fn visit_method(&mut self, _decl: &TypeDecl, _method: &Method) {
+            self.methods += 1;
+        }
+    }
+
+    let mut class = TypeDecl::new(TypeKind::Class);
+    class.add_variable(Member::new());
+    class.add_method(Method::new());
+    class.add_method(Method::new());
+
+    let mut project = Project::new();
+    project.add_type(class);
+    project.add_type(TypeDecl::new(TypeKind::Enumeration));
+
+    let mut counter = Counter {
+        types: 0,
+        members: 0,
+        methods: 0,
+    };
+    walk_project(&mut counter, &project);
+
+    assert_eq!(2, counter.types);
+    assert_eq!(1, counter.members);
+    assert_eq!(2, counter.methods);
+}
+
+#[test]
+fn test_visibility_filter() {
+    let mut public_var = Member::new();
+    public_var.ch_name("count".to_string());
+    public_var.ch_access("public".to_string());
+    let mut package_var = Member::new();
+    package_var.ch_name("cache".to_string());
+
+    let mut class = TypeDecl::new(TypeKind::Class);
+    class.ch_access("public".to_string());
+    class.add_variable(public_var.clone());
+    class.add_variable(package_var);
+
+    let mut inter_method = Method::new();
+    inter_method.ch_method_name("run".to_string());
+    let mut inter = TypeDecl::new(TypeKind::Interface);
+    inter.ch_access("public".to_string());
+    inter.add_method(inter_method);
+
+    let mut hidden = TypeDecl::new(TypeKind::Class);
+    hidden.add_variable(public_var);
+
+    let mut project = Project::new();
+    project.add_type(class);
+    project.add_type(inter);
+    project.add_type(hidden);
+
+    let public = project.with_visibility(Visibility::Public);
+    assert_eq!(2, public.types.len());
+    assert_eq!(1, public.types[0].variables.len());
+    assert_eq!("count", public.types[0].variables[0].name.as_str());
+    assert_eq!(1, public.types[1].methods.len());
+
+    let package = project.with_visibility(Visibility::Package);
+    assert_eq!(3, package.types.len());
+    assert_eq!(2, package.types[0].variables.len());
+    assert_eq!(1, package.types[2].variables.len());
+
+    let ignored = project.without_access("public");
+    assert_eq!(3, ignored.types.len());
+    assert_eq!(1, ignored.types[0].variables.len());
+    assert_eq!("cache", ignored.types[0].variables[0].name.as_str());
+    assert_eq!(1, ignored.types[1].methods.len());
+}
+
+#[test]
+fn test_nested_visibility() {
+    let mut helper = TypeDecl::new(TypeKind::Class);
+    helper.ch_name("Outer.Helper".to_string());
+    helper.ch_access("private".to_string());
+    helper.ch_enclosing("Outer".to_string());
+    let mut builder = TypeDecl::new(TypeKind::Class);
+    builder.ch_name("Outer.Builder".to_string());
+    builder.ch_access("public".to_string());
+    builder.ch_enclosing("Outer".to_string());
+
+    let mut outer = TypeDecl::new(TypeKind::Class);
+    outer.ch_name("Outer".to_string());
+    outer.ch_access("public".to_string());
+    outer.ch_file_path("a/Outer.java".to_string());
+    outer.add_nested_type(helper);
+    outer.add_nested_type(builder);
+
+    let mut project = Project::new();
+    project.add_type(outer);
+
+    assert_eq!(3, project.types.len());
+    assert_eq!("a/Outer.java", project.types[1].file_path.as_str());
+    assert_eq!(Visibility::Private, project.type_visibility(&project.types[1]));
+    assert_eq!(Visibility::Public, project.type_visibility(&project.types[2]));
+
+    let public = project.with_visibility(Visibility::Public);
+    assert_eq!(2, public.types.len());
+    assert_eq!(vec!["Outer.Builder".to_string()], public.types[0].nested);
+    assert_eq!("Outer.Builder", public.types[1].name.as_str());
+}
+
+#[test]
+fn test_strip_annotations() {
+    assert_eq!(
+        "public void run(String name)",
+        strip_annotations("@Override @SuppressWarnings(value = {\"a(b)\"}) public void run(String name)").as_str()
+    );
+    assert_eq!("public @interface Marker", strip_annotations("public @interface Marker").as_str());
+    assert_eq!(
+        "public int count",
+        strip_annotations("@java.lang.Deprecated public\n    int count").as_str()
+    );
+}
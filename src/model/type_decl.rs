@@ -0,0 +1,206 @@
+use model::deprecation::Deprecation;
+use model::exception::Exception;
+use model::member::Member;
+use model::method::Method;
+use model::revision::Revision;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// The kind of a java type declaration
+pub enum TypeKind {
+    Class,
+    Interface,
+    Enumeration,
+    Record,
+    Annotation,
+}
+
+impl TypeKind {
+    /// The name of the kind used in page titles
+    pub fn title(&self) -> &'static str {
+        match self {
+            TypeKind::Class => "Class",
+            TypeKind::Interface => "Interface",
+            TypeKind::Enumeration => "Enum",
+            TypeKind::Record => "Record",
+            TypeKind::Annotation => "Annotation",
+        }
+    }
+
+    /// The name of the kind used for the headings of lists of types
+    pub fn plural(&self) -> &'static str {
+        match self {
+            TypeKind::Class => "Classes",
+            TypeKind::Interface => "Interfaces",
+            TypeKind::Enumeration => "Enums",
+            TypeKind::Record => "Records",
+            TypeKind::Annotation => "Annotations",
+        }
+    }
+
+    /// The name of the kind used in running text
+    pub fn label(&self) -> &'static str {
+        match self {
+            TypeKind::Class => "class",
+            TypeKind::Interface => "interface",
+            TypeKind::Enumeration => "enum",
+            TypeKind::Record => "record",
+            TypeKind::Annotation => "annotation",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Struct representing a constant of an enum
+pub struct EnumField {
+    pub name: String,
+    pub value: String,
+    pub desc: String,
+    pub deprecation: Option<Deprecation>,
+    pub since: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Struct containing the documentation information of a java type declaration.
+/// Classes, interfaces and enums share this struct and are told apart by `kind`.
+/// A nested type is named after the types enclosing it, e.g. `Order.Builder`.
+pub struct TypeDecl {
+    pub kind: TypeKind,
+    pub file_path: String,
+    pub signature: String,
+    pub package_name: String,
+    pub deprecation: Option<Deprecation>,
+    pub since: String,
+    /// The commit that last changed the file of the type, read from the git history
+    pub last_change: Option<Revision>,
+    pub license: String,
+    pub parent: String,
+    pub access: String,
+    pub version: String,
+    pub author: String,
+    pub name: String,
+    pub description: String,
+    pub see: Vec<String>,
+    pub exceptions: Vec<Exception>,
+    pub interfaces: Vec<String>,
+    pub dependencies: Vec<String>,
+    pub fields: Vec<EnumField>,
+    pub modifiers: Vec<String>,
+    pub methods: Vec<Method>,
+    pub variables: Vec<Member>,
+    /// The name of the type declaring this nested type, empty for a top-level type
+    pub enclosing: String,
+    /// The names of the types declared inside this type
+    pub nested: Vec<String>,
+    /// The nested types found by the parser, which `Project::add_type` moves into the project
+    #[serde(skip)]
+    pub nested_types: Vec<TypeDecl>,
+}
+
+impl TypeDecl {
+    pub fn new(kind: TypeKind) -> TypeDecl {
+        TypeDecl {
+            kind: kind,
+            package_name: String::new(),
+            file_path: String::new(),
+            signature: String::new(),
+            dependencies: Vec::new(),
+            deprecation: None,
+            since: String::new(),
+            last_change: None,
+            license: String::new(),
+            parent: String::new(),
+            interfaces: Vec::new(),
+            access: String::new(),
+            version: String::new(),
+            author: String::new(),
+            name: String::new(),
+            see: Vec::new(),
+            exceptions: Vec::new(),
+            description: String::new(),
+            fields: Vec::new(),
+            modifiers: Vec::new(),
+            variables: Vec::new(),
+            methods: Vec::new(),
+            enclosing: String::new(),
+            nested: Vec::new(),
+            nested_types: Vec::new(),
+        }
+    }
+    /// Gets the name of the type without the names of the types enclosing it
+    pub fn simple_name(&self) -> &str {
+        self.name.trim().rsplit('.').next().unwrap_or("")
+    }
+    pub fn ch_kind(&mut self, value: TypeKind) {
+        self.kind = value;
+    }
+    pub fn ch_file_path(&mut self, value: String) {
+        self.file_path = value;
+    }
+    pub fn ch_access(&mut self, value: String) {
+        self.access = value;
+    }
+    pub fn ch_signature(&mut self, value: String) {
+        self.signature = value;
+    }
+    pub fn ch_license(&mut self, value: String) {
+        self.license = value;
+    }
+    pub fn ch_package_name(&mut self, value: String) {
+        self.package_name = value;
+    }
+    pub fn ch_name(&mut self, value: String) {
+        self.name = value;
+    }
+    pub fn ch_description(&mut self, value: String) {
+        self.description = value;
+    }
+    pub fn ch_fields(&mut self, value: Vec<EnumField>) {
+        self.fields = value;
+    }
+    pub fn ch_parent(&mut self, value: String) {
+        self.parent = value;
+    }
+    pub fn ch_version(&mut self, value: String) {
+        self.version = value;
+    }
+    pub fn ch_deprecation(&mut self, value: Option<Deprecation>) {
+        self.deprecation = value;
+    }
+    pub fn ch_last_change(&mut self, value: Option<Revision>) {
+        self.last_change = value;
+    }
+    pub fn ch_since(&mut self, value: String) {
+        self.since = value;
+    }
+    pub fn ch_author(&mut self, value: String) {
+        self.author = value;
+    }
+    pub fn ch_enclosing(&mut self, value: String) {
+        self.enclosing = value;
+    }
+    pub fn add_method(&mut self, value: Method) {
+        self.methods.push(value);
+    }
+    pub fn add_dependency(&mut self, value: String) {
+        self.dependencies.push(value);
+    }
+    pub fn add_variable(&mut self, value: Member) {
+        self.variables.push(value);
+    }
+    pub fn add_interface(&mut self, value: String) {
+        self.interfaces.push(value);
+    }
+    pub fn add_modifier(&mut self, value: String) {
+        self.modifiers.push(value);
+    }
+    pub fn add_exception(&mut self, value: Exception) {
+        self.exceptions.push(value);
+    }
+    pub fn add_see(&mut self, value: String) {
+        self.see.push(value);
+    }
+    pub fn add_nested_type(&mut self, value: TypeDecl) {
+        self.nested.push(value.name.clone());
+        self.nested_types.push(value);
+    }
+}
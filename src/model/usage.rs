@@ -0,0 +1,206 @@
+use std::collections::HashMap;
+use std::ptr;
+
+use model::anchor::field_anchor;
+use model::anchor::method_anchor;
+use model::member::Member;
+use model::method::Method;
+use model::project::Project;
+use model::type_decl::TypeDecl;
+use model::visitor::walk_project;
+use model::visitor::Visitor;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// The way a type is referenced from another declaration
+pub enum UsageKind {
+    Field,
+    Parameter,
+    Return,
+    Exception,
+    Parent,
+    Interface,
+    Import,
+}
+
+impl UsageKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            UsageKind::Field => "field type",
+            UsageKind::Parameter => "parameter type",
+            UsageKind::Return => "return type",
+            UsageKind::Exception => "thrown exception",
+            UsageKind::Parent => "parent",
+            UsageKind::Interface => "implemented interface",
+            UsageKind::Import => "import",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Struct representing a single reference to a project type from another type
+pub struct Usage {
+    pub kind: UsageKind,
+    pub type_name: String,
+    pub package_name: String,
+    pub member: String,
+    /// The anchor id of the using member on its type page, empty for the type itself
+    #[serde(default)]
+    pub anchor: String,
+}
+
+/// Reverse reference index over a project. Maps the qualified name of each
+/// project type to the places where other types use it.
+pub struct UsageIndex {
+    pub usages: HashMap<String, Vec<Usage>>,
+    names: HashMap<String, Vec<String>>,
+}
+
+/// Builds the qualified name used as the key of the usage index
+pub fn qualified_name(package: &str, name: &str) -> String {
+    if package.trim() == "" {
+        name.trim().to_string()
+    } else {
+        format!("{}.{}", package.trim(), name.trim())
+    }
+}
+
+/// Splits a type expression like `Map<String, List<Order>>` into the names it contains
+fn type_names(type_str: &str) -> Vec<String> {
+    type_str
+        .split(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$' || c == '.'))
+        .filter(|part| *part != "")
+        .map(|part| part.trim_matches('.').to_string())
+        .collect()
+}
+
+impl UsageIndex {
+    /// Creates the usage index for all the type declarations of a project
+    ///
+    /// # Arguments
+    ///
+    /// * `proj` - The project to index
+    pub fn new(proj: &Project) -> UsageIndex {
+        let mut index = UsageIndex {
+            usages: HashMap::new(),
+            names: HashMap::new(),
+        };
+
+        for decl in proj.types.iter() {
+            let simple = index.names.entry(decl.simple_name().to_string()).or_insert(Vec::new());
+            simple.push(qualified_name(&decl.package_name, &decl.name));
+        }
+
+        walk_project(&mut index, proj);
+
+        index
+    }
+
+    /// Returns the usages of a type, or an empty list if it is not used anywhere
+    ///
+    /// # Arguments
+    ///
+    /// * `package` - The package of the type
+    /// * `name` - The name of the type
+    pub fn get(&self, package: &str, name: &str) -> Vec<Usage> {
+        match self.usages.get(&qualified_name(package, name)) {
+            Some(usages) => usages.clone(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Resolves a type name used in a declaration to the qualified name of a project type.
+    /// Qualified names are matched directly, simple names are looked up through the
+    /// imports and package of the using type before falling back to a unique match. A
+    /// nested type is also found by its name in its enclosing type, e.g. `Order.Builder`.
+    fn resolve(&self, name: &str, package: &str, imports: &Vec<String>) -> Option<String> {
+        if name.contains(".") {
+            let simple = name.split(".").last().unwrap_or("");
+            let local = qualified_name(package, name);
+            return match self.names.get(simple) {
+                Some(candidates) => candidates.iter().find(|c| c.as_str() == name || **c == local).cloned(),
+                None => None,
+            };
+        }
+
+        let candidates = match self.names.get(name) {
+            Some(c) => c,
+            None => return None,
+        };
+
+        for c in candidates {
+            if imports.iter().any(|i| i.trim() == c.as_str()) {
+                return Some(c.clone());
+            }
+        }
+        let local = qualified_name(package, name);
+        if candidates.contains(&local) {
+            return Some(local);
+        }
+        if candidates.len() == 1 {
+            return Some(candidates[0].clone());
+        }
+
+        None
+    }
+
+    fn add_usage(&mut self, decl: &TypeDecl, type_str: &str, kind: UsageKind, member: &str, anchor: &str) {
+        let own_name = qualified_name(&decl.package_name, &decl.name);
+
+        for name in type_names(type_str) {
+            if let Some(target) = self.resolve(&name, &decl.package_name, &decl.dependencies) {
+                if target == own_name {
+                    continue;
+                }
+
+                let usages = self.usages.entry(target).or_insert(Vec::new());
+                let duplicate = usages.iter().any(|u| {
+                    u.kind == kind && u.anchor == anchor && u.type_name == decl.name.trim()
+                        && u.package_name == decl.package_name.trim()
+                });
+
+                if !duplicate {
+                    usages.push(Usage {
+                        kind: kind.clone(),
+                        type_name: decl.name.trim().to_string(),
+                        package_name: decl.package_name.trim().to_string(),
+                        member: member.to_string(),
+                        anchor: anchor.to_string(),
+                    });
+                }
+            }
+        }
+    }
+}
+
+impl Visitor for UsageIndex {
+    fn visit_type(&mut self, decl: &TypeDecl) {
+        for dep in decl.dependencies.iter() {
+            self.add_usage(decl, dep, UsageKind::Import, "", "");
+        }
+        self.add_usage(decl, &decl.parent, UsageKind::Parent, "", "");
+        for inter in decl.interfaces.iter() {
+            self.add_usage(decl, inter, UsageKind::Interface, "", "");
+        }
+        for exception in decl.exceptions.iter() {
+            self.add_usage(decl, &exception.exception_type, UsageKind::Exception, "", "");
+        }
+    }
+
+    fn visit_member(&mut self, decl: &TypeDecl, member: &Member) {
+        let anchor = field_anchor(member);
+        self.add_usage(decl, &member.var_type, UsageKind::Field, &member.name, &anchor);
+    }
+
+    fn visit_method(&mut self, decl: &TypeDecl, method: &Method) {
+        let index = decl.methods.iter().position(|m| ptr::eq(m, method)).unwrap_or(0);
+        let anchor = method_anchor(&decl.methods, index);
+        self.add_usage(decl, &method.return_type, UsageKind::Return, &method.name, &anchor);
+
+        for param in method.parameters.iter() {
+            self.add_usage(decl, &param.var_type, UsageKind::Parameter, &method.name, &anchor);
+        }
+        for exception in method.exceptions.iter() {
+            self.add_usage(decl, &exception.exception_type, UsageKind::Exception, &method.name, &anchor);
+        }
+    }
+}
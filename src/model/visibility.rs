@@ -0,0 +1,74 @@
+use model::type_decl::TypeDecl;
+use model::type_decl::TypeKind;
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Serialize, Deserialize)]
+/// The java access levels ordered from least to most visible
+pub enum Visibility {
+    Private,
+    Package,
+    Protected,
+    Public,
+}
+
+impl Visibility {
+    /// Gets the visibility for the name passed to the `--visibility` option
+    pub fn from_name(name: &str) -> Option<Visibility> {
+        match name {
+            "public" => Some(Visibility::Public),
+            "protected" => Some(Visibility::Protected),
+            "package" => Some(Visibility::Package),
+            "private" => Some(Visibility::Private),
+            _ => None,
+        }
+    }
+
+    /// Gets the name of the visibility used in generated documents
+    pub fn name(&self) -> &'static str {
+        match self {
+            Visibility::Public => "public",
+            Visibility::Protected => "protected",
+            Visibility::Package => "package-private",
+            Visibility::Private => "private",
+        }
+    }
+
+    /// Gets the visibility of a declaration from its access modifier.
+    /// Declarations without an access modifier are package-private.
+    pub fn from_access(access: &str) -> Visibility {
+        match access.trim() {
+            "public" => Visibility::Public,
+            "protected" => Visibility::Protected,
+            "private" => Visibility::Private,
+            _ => Visibility::Package,
+        }
+    }
+
+    /// Gets the visibility of a type declaration
+    pub fn of_type(decl: &TypeDecl) -> Visibility {
+        Visibility::from_access(decl.access.as_str())
+    }
+
+    /// Gets the visibility of a member of a type declaration. Members without an
+    /// access modifier are public inside interfaces and annotations. A member is never
+    /// more visible than the type declaring it.
+    ///
+    /// # Arguments
+    ///
+    /// * `decl` - The type declaring the member
+    /// * `access` - The access modifier of the member
+    pub fn of_member(decl: &TypeDecl, access: &str) -> Visibility {
+        let implicit_public = decl.kind == TypeKind::Interface || decl.kind == TypeKind::Annotation;
+        let member = if implicit_public && access.trim() == "" {
+            Visibility::Public
+        } else {
+            Visibility::from_access(access)
+        };
+        let owner = Visibility::of_type(decl);
+
+        if owner < member {
+            owner
+        } else {
+            member
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use model::member::Member;
+use model::method::Method;
+use model::project::Project;
+use model::type_decl::EnumField;
+use model::type_decl::TypeDecl;
+
+/// Trait for traversing the type declarations of a project and their members.
+/// Every method has an empty default so implementors only handle what they need.
+pub trait Visitor {
+    /// Called before the members of a type declaration are visited
+    fn visit_type(&mut self, _decl: &TypeDecl) {}
+    /// Called for each member variable of a type declaration
+    fn visit_member(&mut self, _decl: &TypeDecl, _member: &Member) {}
+    /// Called for each method of a type declaration
+    fn visit_method(&mut self, _decl: &TypeDecl, _method: &Method) {}
+    /// Called for each constant of an enum
+    fn visit_enum_field(&mut self, _decl: &TypeDecl, _field: &EnumField) {}
+    /// Called after all the members of a type declaration were visited
+    fn leave_type(&mut self, _decl: &TypeDecl) {}
+}
+
+/// Walks a single type declaration, calling the visitor for the declaration and its members
+///
+/// # Arguments
+///
+/// * `visitor` - The visitor to call
+/// * `decl` - The type declaration to walk
+pub fn walk_type<V: Visitor>(visitor: &mut V, decl: &TypeDecl) {
+    visitor.visit_type(decl);
+
+    for field in decl.fields.iter() {
+        visitor.visit_enum_field(decl, field);
+    }
+    for member in decl.variables.iter() {
+        visitor.visit_member(decl, member);
+    }
+    for method in decl.methods.iter() {
+        visitor.visit_method(decl, method);
+    }
+
+    visitor.leave_type(decl);
+}
+
+/// Walks every type declaration of a project
+///
+/// # Arguments
+///
+/// * `visitor` - The visitor to call
+/// * `proj` - The project to walk
+pub fn walk_project<V: Visitor>(visitor: &mut V, proj: &Project) {
+    for decl in proj.types.iter() {
+        walk_type(visitor, decl);
+    }
+}
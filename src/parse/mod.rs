@@ -2,87 +2,90 @@
 pub mod parse {
     //! A module which handles the parsing for java files
 
+    use error::error::LojidocError;
     use grammar::grammar::*;
-    use model::model::Class;
+    use model::model::strip_annotations;
+    use model::model::Deprecation;
     use model::model::Doc;
     use model::model::Exception;
     use model::model::Member;
     use model::model::Method;
-    use model::model::Object;
-    use model::model::ObjectState;
-    use model::model::ObjectType;
     use model::model::EnumField;
     use model::model::Param;
+    use model::model::TypeDecl;
+    use model::model::TypeKind;
 
     use std::fs::File;
     use std::io::BufReader;
     use std::io::Read;
+    use std::mem;
     use std::path::Path;
 
+    /// Adds the text following a javadoc tag to the documentation. The text of the tags
+    /// which are not documented is dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `doc` - The documentation parsed so far
+    /// * `state` - The tag the text belongs to
+    /// * `text` - The words following the tag
+    fn add_tag_text(doc: &mut Doc, state: &JdocState, text: String) {
+        match state {
+            JdocState::JdocReturn => doc.return_desc = text,
+            JdocState::Param => {
+                let word_parts: Vec<&str> = text.split(" ").collect();
+
+                if word_parts.len() > 1 {
+                    doc.params.push(Param {
+                        var_type: String::new(),
+                        name: word_parts[0].to_string(),
+                        desc: word_parts[1..].join(" "),
+                    });
+                } else if word_parts.len() == 1 {
+                    doc.params.push(Param {
+                        var_type: String::new(),
+                        name: word_parts[0].to_string(),
+                        desc: String::new(),
+                    });
+                }
+            }
+            JdocState::Author => doc.author = text,
+            JdocState::Deprecated => doc.deprecated = text,
+            JdocState::Since => doc.since = text.trim().to_string(),
+            JdocState::See => doc.see.push(text.trim().to_string()),
+            JdocState::Exception => {
+                let word_parts: Vec<&str> = text.split(" ").collect();
+
+                if doc.exceptions.len() > 0 {
+                    doc.exceptions.push(Exception {
+                        exception_type: word_parts[0].to_string(),
+                        desc: word_parts[1..].join(""),
+                    });
+                }
+            }
+            JdocState::Version => doc.version = text,
+            JdocState::Desc => doc.description = text,
+            _ => (),
+        }
+    }
+
     /// Handles token streams for javadoc comments and returns a `Doc` struct
-    /// containing the information parsed from the javadoc comment.
+    /// containing the information parsed from the javadoc comment. The text of
+    /// every tag, including the last one, is added when the tag ends.
     ///
     /// # Arguments
     ///
     /// * `tokens` - A vector of tokens from the javadoc comment
     fn get_doc(tokens: &Vec<JdocToken>) -> Doc {
-        let mut return_str = String::from("");
-        let mut desc = String::from("");
-        let mut parameters: Vec<Param> = Vec::new();
-        let mut author = String::new();
-        let mut version = String::new();
-        let mut link = String::new();
-        let mut deprecated = String::new();
-        let mut exceptions: Vec<Exception> = Vec::new();
+        let mut doc = Doc::new();
         let mut state = JdocState::Desc;
         let mut word_buf = String::new();
 
         for i in 0..tokens.len() {
             match tokens[i].clone() {
                 JdocToken::Keyword(key) => {
-                    let new_desc = word_buf.clone();
                     if i != 0 {
-                        match state {
-                            JdocState::JdocReturn => {
-                                return_str = new_desc;
-                            }
-                            JdocState::Param => {
-                                let word_parts: Vec<&str> = new_desc.split(" ").collect();
-
-                                if word_parts.len() > 1 {
-                                    parameters.push(Param {
-                                        var_type: String::new(),
-                                        name: word_parts[0].to_string(),
-                                        desc: word_parts[1..].join(" "),
-                                    });
-                                } else if word_parts.len() == 1 {
-                                    parameters.push(Param {
-                                        var_type: String::new(),
-                                        name: word_parts[0].to_string(),
-                                        desc: String::new(),
-                                    });
-                                }
-                            }
-                            JdocState::Author => author = new_desc,
-                            JdocState::Deprecated => deprecated = new_desc,
-                            JdocState::Since => version = new_desc,
-                            JdocState::Link => link = new_desc,
-                            JdocState::See => link = new_desc,
-                            JdocState::Exception => {
-                                let word_parts: Vec<&str> = new_desc.split(" ").collect();
-
-                                if exceptions.len() > 0 {
-                                    exceptions.push(Exception {
-                                        exception_type: word_parts[0].to_string(),
-                                        desc: word_parts[1..].join(""),
-                                    });
-                                }
-                            }
-                            JdocState::Version => version = new_desc,
-                            JdocState::Desc => desc = new_desc,
-                            _ => println!("Code javadoc field not supported"),
-                        }
-
+                        add_tag_text(&mut doc, &state, word_buf.clone());
                         word_buf.clear();
                     }
 
@@ -105,7 +108,7 @@ pub mod parse {
                         "@serialField" => state = JdocState::SerialField,
                         "@value" => state = JdocState::Value,
                         "@version" => state = JdocState::Version,
-                        _ => println!("Unsupported javadoc keyword used"),
+                        _ => (),
                     }
                 }
                 JdocToken::Symbol(key) => {
@@ -116,16 +119,11 @@ pub mod parse {
             }
         }
 
-        Doc {
-            params: parameters,
-            description: desc,
-            return_desc: return_str,
-            author: author,
-            version: version,
-            exceptions: exceptions,
-            deprecated: deprecated,
-            see: link,
+        if tokens.len() > 0 {
+            add_tag_text(&mut doc, &state, word_buf);
         }
+
+        doc
     }
 
     /// Enum that represents the state of parsing a object declaration
@@ -135,22 +133,33 @@ pub mod parse {
         Exception,
         Parent,
         ClassName,
+        Component,
         Other,
     }
 
 
-    /// Handles token streams for object declarations and modifies the `Class` struct
+    /// Handles token streams for object declarations and modifies the `TypeDecl` struct
     /// which is passed to the function.
     ///
-    /// This function is used for class, interface, and enum declarations.
+    /// This function is used for class, interface, enum, record and annotation declarations.
+    /// The components of a record header are added as its member variables.
     ///
     /// # Arguments
     ///
     /// * `gram_parts` - A vector of tokens from the object's declaration
     /// * `java_doc` - The java doc struct with the documentation for the class
-    /// * `class` - The Class struct to be modified with the new information
-    fn get_object(gram_parts: Vec<Stream>, java_doc: &Doc, sign: String, ob: &mut Object) {
+    /// * `deprecation` - The deprecation of the object
+    /// * `ob` - The TypeDecl struct to be modified with the new information
+    fn get_object(
+        gram_parts: Vec<Stream>,
+        java_doc: &Doc,
+        sign: String,
+        deprecation: Option<Deprecation>,
+        ob: &mut TypeDecl,
+    ) {
         let mut parse_state = ObjectParseState::Other;
+        let mut record = false;
+        let mut component_type = String::new();
 
         for i in 0..gram_parts.len() {
             match gram_parts[i].clone() {
@@ -164,26 +173,47 @@ pub mod parse {
                         }),
                     ObjectParseState::ClassName => ob.ch_name(var),
                     ObjectParseState::Parent => ob.ch_parent(var),
+                    ObjectParseState::Component => {
+                        let mut component = Member::new();
+                        component.ch_access(String::from("public"));
+                        component.add_modifier(String::from("final"));
+                        component.ch_type(component_type.clone());
+                        component.ch_signature(format!("{} {}", component_type, var));
+                        for param in java_doc.params.iter().filter(|p| p.name == var) {
+                            component.desc = param.desc.clone();
+                        }
+                        component.ch_name(var);
+                        ob.add_variable(component);
+                    }
                     ObjectParseState::Other => (),
                     }
                 }
-                Stream::Object(_) => parse_state = ObjectParseState::ClassName,
+                Stream::Type(var_type) if record => {
+                    component_type = var_type;
+                    parse_state = ObjectParseState::Component;
+                }
+                Stream::Object(key) => {
+                    record = key == "record";
+                    parse_state = ObjectParseState::ClassName;
+                }
                 Stream::Access(key) => ob.ch_access(key),
                 Stream::Modifier(key) => ob.add_modifier(key),
                 Stream::Exception => parse_state = ObjectParseState::Exception,
                 Stream::Implement => parse_state = ObjectParseState::Implement,
                 Stream::Parent => parse_state = ObjectParseState::Parent,
-                _ => {
-                    println!("Class pattern not supported {:?}", gram_parts[i]);
-                    println!("{:?}", gram_parts);
-                },
+                _ => (),
             }
         }
 
         ob.ch_signature(sign.clone());
         ob.ch_description(java_doc.description.clone());
         ob.ch_author(java_doc.author.clone());
+        ob.ch_deprecation(deprecation);
         ob.ch_version(java_doc.version.clone());
+        ob.ch_since(java_doc.since.clone());
+        for see in java_doc.see.iter() {
+            ob.add_see(see.clone());
+        }
     }
 
     /// Enum that represents the state of parsing a method declaration
@@ -202,8 +232,16 @@ pub mod parse {
     ///
     /// * `gram_parts` - A vector of tokens from the method's declaration
     /// * `_java_doc` - The java doc struct with the documentation for the method
-    fn get_method(gram_parts: Vec<Stream>, java_doc: &Doc, line_num: String, signature: String) -> Method {
+    fn get_method(
+        gram_parts: Vec<Stream>,
+        java_doc: &Doc,
+        line_num: String,
+        signature: String,
+        deprecation: Option<Deprecation>,
+    ) -> Method {
         let mut method = Method::new();
+        method.ch_deprecation(deprecation);
+        method.ch_since(java_doc.since.clone());
         let mut param_type = String::new();
         let mut parse_state = MethodParseState::Other;
 
@@ -247,19 +285,30 @@ pub mod parse {
                 Stream::Access(key) => method.ch_privacy(key),
                 Stream::Modifier(key) => method.add_modifier(key),
                 Stream::Exception => parse_state = MethodParseState::Exception,
-                _ => println!("Method pattern not supported"),
+                _ => (),
             }
         }
         method.ch_line_num(line_num);
         method.ch_signature(signature);
 
-        if java_doc.return_desc != "" {
-            method.ch_return_type(java_doc.return_desc.clone());
+        // A constructor has no name in the token stream, so it is named after the last
+        // word before its parameters, the simple name of its type
+        if method.name == "" {
+            let declaration = strip_annotations(&method.signature);
+            if let Some(i) = declaration.find('(') {
+                let name = declaration[..i].trim().rsplit(' ').next().unwrap_or("");
+                method.ch_method_name(name.to_string());
+                method.ch_return_type(String::new());
+            }
         }
+        method.ch_return_desc(java_doc.return_desc.clone());
 
         if java_doc.description != "" {
             method.ch_description(java_doc.description.clone());
         }
+        for see in java_doc.see.iter() {
+            method.add_see(see.clone());
+        }
 
         let n_params: Vec<Param> =
             match_params(&mut method, &java_doc.params);
@@ -274,9 +323,22 @@ pub mod parse {
     /// # Arguments
     ///
     /// * `gram_parts` - A vector of tokens in the member variable expression
-    fn get_var(gram_parts: Vec<Stream>, line_num: String, signature: String) -> Member {
+    /// * `java_doc` - The java doc struct with the documentation for the member variable
+    /// * `deprecation` - The deprecation of the member variable
+    fn get_var(
+        gram_parts: Vec<Stream>,
+        java_doc: &Doc,
+        line_num: String,
+        signature: String,
+        deprecation: Option<Deprecation>,
+    ) -> Member {
         let mut member = Member::new();
         let mut member_name = false;
+        member.ch_line_number(line_num);
+        member.ch_signature(signature);
+        member.ch_deprecation(deprecation);
+        member.ch_desc(java_doc.description.clone());
+        member.ch_since(java_doc.since.clone());
 
         for i in 0..gram_parts.len() {
             match gram_parts[i].clone() {
@@ -304,37 +366,39 @@ pub mod parse {
                 }
                 Stream::Access(key) => member.ch_access(key),
                 Stream::Modifier(key) => member.add_modifier(key),
-                _ => println!("Member variable pattern not supported"),
+                _ => (),
             }
         }
-        member.ch_line_number(line_num);
-        member.ch_signature(signature);
-
-
         member
     }
-    /// Handles token streams for member variables and returns a `Member` struct
-    /// Containing the member variable's data
+    /// Adds the enum constant named by the last pending symbol to the constants of an
+    /// enum. The value of a constant is its ordinal. The javadoc and the `@Deprecated`
+    /// annotation of the constant are consumed.
     ///
     /// # Arguments
     ///
-    /// * `gram_parts` - A vector of tokens in the member variable expression
-    fn get_enum_fields(gram_parts: Vec<Stream>) -> Vec<EnumField> {
-        let mut fields: Vec<EnumField>  = Vec::new();
-
-        for i in 0..gram_parts.len() {
-            match gram_parts[i].clone() {
-                Stream::Variable(var) => {
-                    fields.push(EnumField {
-                        name: var,
-                        value: i.to_string(),
-                    })
-                }
-                _ => println!("Enumeration pattern not supported"),
-            }
+    /// * `symbols` - The pending symbols, cleared afterwards
+    /// * `java_doc` - The javadoc of the constant
+    /// * `deprecated` - The `@Deprecated` annotation of the constant
+    /// * `constants` - The constants of the enum parsed so far
+    fn add_enum_constant(
+        symbols: &mut Vec<String>,
+        java_doc: &mut Doc,
+        deprecated: &mut Option<Deprecation>,
+        constants: &mut Vec<EnumField>,
+    ) {
+        if let Some(name) = symbols.pop() {
+            let ordinal = constants.len();
+            constants.push(EnumField {
+                name,
+                value: ordinal.to_string(),
+                desc: java_doc.description.clone(),
+                deprecation: get_deprecation(java_doc, deprecated.take()),
+                since: java_doc.since.clone(),
+            });
+            *java_doc = Doc::new();
         }
-
-        fields
+        symbols.clear();
     }
 
     pub fn match_params(method: &Method, jparams: &Vec<Param>) -> Vec<Param> {
@@ -394,29 +458,127 @@ pub mod parse {
         }
     }
 
+    /// Creates the signature of a declaration from the text of its statement by removing
+    /// the comments and collapsing the whitespace. The braces of inline javadoc tags like
+    /// `{@link}` end a statement too, so the text up to an unmatched `*/` is dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `statement` - The text of the statement up to its `;` or `{`
+    fn clean_signature(statement: &str) -> String {
+        let mut text = String::new();
+        let mut chars = statement.chars().peekable();
+        let mut quote: Option<char> = None;
+
+        while let Some(ch) = chars.next() {
+            match (quote, ch) {
+                (Some(q), _) => {
+                    text.push(ch);
+                    if ch == '\\' {
+                        if let Some(escaped) = chars.next() {
+                            text.push(escaped);
+                        }
+                    } else if ch == q {
+                        quote = None;
+                    }
+                }
+                (None, '"') | (None, '\'') => {
+                    quote = Some(ch);
+                    text.push(ch);
+                }
+                (None, '/') if chars.peek() == Some(&'*') => {
+                    let mut last = ' ';
+                    while let Some(c) = chars.next() {
+                        if last == '*' && c == '/' {
+                            break;
+                        }
+                        last = c;
+                    }
+                    text.push(' ');
+                }
+                (None, '*') if chars.peek() == Some(&'/') => {
+                    chars.next();
+                    text.clear();
+                }
+                (None, '/') if chars.peek() == Some(&'/') => {
+                    while let Some(c) = chars.next() {
+                        if c == '\n' {
+                            break;
+                        }
+                    }
+                    text.push(' ');
+                }
+                _ => text.push(ch),
+            }
+        }
+
+        text.split_whitespace().collect::<Vec<&str>>().join(" ")
+    }
+
+    /// Determines whether the text before a `{` is inside a comment, so the brace belongs
+    /// to an inline javadoc tag
+    fn in_comment(statement: &str) -> bool {
+        let line = statement.rsplit('\n').next().unwrap_or("");
+        let block = match (statement.rfind("/*"), statement.rfind("*/")) {
+            (Some(start), Some(end)) => start > end,
+            (Some(_), None) => true,
+            _ => false,
+        };
+
+        block || line.contains("//")
+    }
+
+    /// Determines whether the text before a `{` declares a class, interface, enum, record
+    /// or annotation. The parentheses are removed first, so a `record` keyword has to be
+    /// followed by the name of the record and an argument like `Foo.class` is skipped.
+    fn declares_type(statement: &str) -> bool {
+        let mut text = String::new();
+        let mut depth = 0;
+
+        for ch in clean_signature(statement).chars() {
+            match ch {
+                '(' => depth += 1,
+                ')' => depth -= 1,
+                _ if depth == 0 => text.push(ch),
+                _ => (),
+            }
+        }
+        let words: Vec<&str> = text.split_whitespace().collect();
+
+        words.iter().enumerate().any(|(i, word)| match *word {
+            "class" | "interface" | "enum" | "@interface" => true,
+            "record" => i + 1 < words.len(),
+            _ => false,
+        })
+    }
+
     pub fn lex_contents(content: &String) -> Vec<Token> {
         let mut tokens: Vec<Token> = Vec::new();
         let mut curr_token = String::new();
-        let mut block_depth = 0;
+        // The declarations are lexed as long as every enclosing block is a type body
+        let mut blocks: Vec<Block> = Vec::new();
         let mut line_number = 1;
         let mut blob = content.chars();
         let keywords = get_keywords();
         let mut curr_line = String::new();
+        let mut statement = String::new();
 
         tokens.push(Token::LineNumber(line_number.to_string()));
 
         loop {
+            let lexed = blocks.iter().all(|b| *b != Block::Body);
+
             match blob.next() {
                 Some(ch) => {
                     match ch {
                     ' ' | '\t' | '\r' => {
-                        if block_depth < 2 {
+                        if lexed {
                             push_token(&curr_token, &mut tokens, &keywords);
                         }
                         curr_token = String::new();
                     }
                     '\n' => {
-                        if block_depth < 2 {
+                        if lexed {
                             push_token(&curr_token, &mut tokens, &keywords);
                         }
 
@@ -427,56 +589,76 @@ pub mod parse {
                         curr_line = String::new();
                     }
                     ',' => {
-                        if block_depth < 2 {
+                        if lexed {
                             push_token(&curr_token, &mut tokens, &keywords);
                             tokens.push(Token::Join)
                         }
                         curr_token = String::new();
                     }
                     ';' => {
-                        if block_depth < 2 {
+                        if lexed {
                             push_token(&curr_token, &mut tokens, &keywords);
                             tokens.push(Token::ExpressionEnd(";".to_string()));
+                            tokens.push(Token::Sign(clean_signature(&statement)));
+                            statement = String::new();
                         }
                         curr_token = String::new();
                     }
                     '(' => {
-                        if block_depth < 2 {
+                        if lexed {
                             push_token(&curr_token, &mut tokens, &keywords);
                             tokens.push(Token::ParamStart);
                         }
                         curr_token = String::new();
                     }
                     ')' => {
-                        if block_depth < 2 {
+                        if lexed {
                             push_token(&curr_token, &mut tokens, &keywords);
                             tokens.push(Token::ParamEnd);
                         }
                         curr_token = String::new();
                     }
                     '{' => {
-                        if block_depth < 2 {
+                        let block = if in_comment(&statement) {
+                            Block::Comment
+                        } else if declares_type(&statement) {
+                            Block::Type
+                        } else {
+                            Block::Body
+                        };
+
+                        if lexed {
                             push_token(&curr_token, &mut tokens, &keywords);
                             tokens.push(Token::ExpressionEnd("{".to_string()));
+                            tokens.push(Token::Sign(clean_signature(&statement)));
+                            statement = String::new();
                         }
                         curr_token = String::new();
-                        block_depth = block_depth + 1;
+                        blocks.push(block);
                     }
                     '}' => {
-                        if block_depth < 2 {
+                        if lexed {
                             push_token(&curr_token, &mut tokens, &keywords);
                         }
+                        if blocks.pop() == Some(Block::Type) && lexed {
+                            tokens.push(Token::TypeEnd);
+                        }
                         curr_token = String::new();
-                        block_depth = block_depth - 1;
+                        statement = String::new();
                     }
                     _ => {
-                        if block_depth < 2 {
+                        if lexed {
                             curr_token.push_str(ch.to_string().as_str());
                         }
                     }
 
                 }
                 curr_line.push_str(ch.to_string().as_str());
+                // The text of the current statement outside of method bodies follows its
+                // end as the signature of the declaration
+                if lexed && ch != ';' && ch != '{' && ch != '}' {
+                    statement.push(ch);
+                }
 
                 },
                 None => break,
@@ -510,16 +692,75 @@ pub mod parse {
         };
     }
 
+    /// Combines the `@deprecated` javadoc tag and the `@Deprecated` annotation of a
+    /// declaration. Returns `None` when the declaration has neither.
+    ///
+    /// # Arguments
+    ///
+    /// * `java_doc` - The javadoc of the declaration
+    /// * `annotation` - The elements of the declaration's `@Deprecated` annotation
+    fn get_deprecation(java_doc: &Doc, annotation: Option<Deprecation>) -> Option<Deprecation> {
+        if annotation.is_none() && java_doc.deprecated.trim() == "" {
+            return None;
+        }
+
+        let mut deprecation = annotation.unwrap_or(Deprecation::new());
+        deprecation.ch_reason(java_doc.deprecated.trim().to_string());
+
+        Some(deprecation)
+    }
+
+    /// Determines whether the parser is in the body of an enum before the end of its
+    /// constants, where a symbol followed by a comma, the arguments or the body of a
+    /// constant is the name of a constant
+    fn in_enum_constants(object_kind: &Option<TypeKind>, object: &TypeDecl) -> bool {
+        *object_kind == Some(TypeKind::Enumeration) && object.name != "" && object.fields.len() == 0
+    }
+
+    /// Sets the kind of a declared type. The kind of a type declared in the body of another
+    /// type is kept until its body starts, so the kind of the enclosing type is not changed
+    /// while its declaration is parsed.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - The kind of the declared type
+    /// * `object` - The type being parsed
+    /// * `object_kind` - The kind of the type being parsed
+    /// * `nested_kind` - The kind of the nested type being declared
+    fn declare_kind(
+        kind: TypeKind,
+        object: &TypeDecl,
+        object_kind: &mut Option<TypeKind>,
+        nested_kind: &mut Option<TypeKind>,
+    ) {
+        if object.name == "" {
+            *object_kind = Some(kind);
+        } else {
+            *nested_kind = Some(kind);
+        }
+    }
+
     /// Constucts a syntax tree based on the stream of token from the lexing
-    /// Outputs a Class struct containing all the data for a java class
+    /// Outputs a TypeDecl struct containing all the data for a java class, interface or enum.
+    /// The types declared in its body are added to it as nested types.
+    /// Declarations the parser does not understand are skipped. A file which declares no
+    /// type and no package is a parse error.
     ///
     /// # Arguments
     ///
     /// * `tokens` - The list of tokens from the lexer
-    pub fn construct_ast(tokens: Vec<Token>) -> ObjectType {
+    pub fn construct_ast(tokens: Vec<Token>) -> Result<TypeDecl, LojidocError> {
         let mut annotation = false;
+        let mut deprecated: Option<Deprecation> = None;
+        let mut deprecated_args: Option<String> = None;
+        let mut default_value = false;
         let mut ignore = false;
-        let mut object = Object::new();
+        let mut object = TypeDecl::new(TypeKind::Class);
+        let mut object_kind: Option<TypeKind> = None;
+        let mut nested_kind: Option<TypeKind> = None;
+        let mut constants: Vec<EnumField> = Vec::new();
+        let mut constant_args = 0;
+        let mut outer_types: Vec<(TypeDecl, Option<TypeKind>)> = Vec::new();
         let mut in_object = false;
         let mut parse_state = ParseState::Other;
         let mut doc = false;
@@ -533,11 +774,33 @@ pub mod parse {
         let mut line_num = String::new();
         let mut signature = String::new();
 
-        for token in tokens.clone() {
+        for (i, token) in tokens.clone().into_iter().enumerate() {
             if ignore {
-                match token.clone() {
-                    Token::ParamEnd => ignore = false,
-                    _ => continue,
+                // The elements of a `@Deprecated` annotation are kept, every other
+                // annotation's are skipped
+                match (token.clone(), deprecated_args.as_mut()) {
+                    (Token::ParamEnd, _) => ignore = false,
+                    (Token::Symbol(word), Some(args)) | (Token::Keyword(word), Some(args)) => {
+                        args.push_str(format!("{} ", word).as_str());
+                    }
+                    (Token::Join, Some(args)) => args.push_str(","),
+                    _ => (),
+                }
+                if !ignore {
+                    if let Some(args) = deprecated_args.take() {
+                        deprecated = Some(Deprecation::from_annotation(&args));
+                    }
+                }
+
+                continue;
+            }
+            if constant_args > 0 {
+                // The arguments of an enum constant are skipped
+                match token {
+                    Token::ParamStart => constant_args += 1,
+                    Token::ParamEnd => constant_args -= 1,
+                    Token::LineNumber(num) => line_num = num,
+                    _ => (),
                 }
 
                 continue;
@@ -558,7 +821,7 @@ pub mod parse {
                     match key.as_ref() {
                         "class" => {
                             if !doc && !comment {
-                                object.ch_state(ObjectState::Class);
+                                declare_kind(TypeKind::Class, &object, &mut object_kind, &mut nested_kind);
                                 gram_parts.push(Stream::Object(key.to_string()));
                                 parse_state = ParseState::Class;
                             }
@@ -566,7 +829,7 @@ pub mod parse {
                         }
                         "interface" => {
                             if !doc && !comment {
-                                object.ch_state(ObjectState::Interface);
+                                declare_kind(TypeKind::Interface, &object, &mut object_kind, &mut nested_kind);
                                 gram_parts.push(Stream::Object(key.to_string()));
                                 parse_state = ParseState::Interface;
                             }
@@ -574,12 +837,15 @@ pub mod parse {
                         }
                         "enum" => {
                             if !doc && !comment {
-                                object.ch_state(ObjectState::Enumeration);
+                                declare_kind(TypeKind::Enumeration, &object, &mut object_kind, &mut nested_kind);
                                 gram_parts.push(Stream::Object(key.to_string()));
                                 parse_state = ParseState::Enum;
                             }
                             in_object = true;
                         }
+                        "default" if object_kind == Some(TypeKind::Annotation) && !doc && !comment => {
+                            default_value = true;
+                        }
                         "package" => {
                             if comment_buf != "" {
                                 object.ch_license(comment_buf.clone());
@@ -599,8 +865,6 @@ pub mod parse {
                                 doc_tokens.push(JdocToken::Keyword(key.clone()));
                             } else if doc {
                                 doc_tokens.push(JdocToken::Symbol(key.clone()));
-                            } else if !comment && !doc {
-                                println!("Keyword not supported: {}", key);
                             }
                         }
                     }
@@ -631,6 +895,27 @@ pub mod parse {
                             comment_buf = String::new();
                             comment = true;
                         }
+                        "@interface" if !doc && !comment => {
+                            declare_kind(TypeKind::Annotation, &object, &mut object_kind, &mut nested_kind);
+                            gram_parts.push(Stream::Object(word.to_string()));
+                            parse_state = ParseState::Annotation;
+                            in_object = true;
+                        }
+                        // In the body of a type `record` is only a declaration when it is
+                        // followed by a name and the components
+                        "record"
+                            if !doc
+                                && !comment
+                                && (!in_object
+                                    || (symbols.len() == 0
+                                        && tokens.get(i + 2) == Some(&Token::ParamStart)
+                                        && matches!(tokens.get(i + 1), Some(Token::Symbol(_))))) =>
+                        {
+                            declare_kind(TypeKind::Record, &object, &mut object_kind, &mut nested_kind);
+                            gram_parts.push(Stream::Object(word.to_string()));
+                            parse_state = ParseState::Record;
+                            in_object = true;
+                        }
                         _ => {
                             if word.contains("//") {
                                 comment = true;
@@ -641,9 +926,15 @@ pub mod parse {
                                     doc_tokens.push(JdocToken::Symbol(word.clone()));
                                 }
                             } else if word.contains("@") && !doc {
+                                if !comment && (word == "@Deprecated" || word == "@java.lang.Deprecated") {
+                                    deprecated = Some(Deprecation::new());
+                                    deprecated_args = Some(String::new());
+                                } else {
+                                    deprecated_args = None;
+                                }
                                 annotation = true;
                                 continue;
-                            } else if !comment {
+                            } else if !comment && !default_value {
                                 symbols.push(word.to_string());
                             }
                         }
@@ -662,6 +953,8 @@ pub mod parse {
                         let temp_sym = symbols.clone();
                         gram_parts.push(Stream::Type(temp_sym[..temp_sym.len() - 1].join(" ")));
                         gram_parts.push(Stream::Variable(temp_sym[temp_sym.len() - 1].clone()));
+                    } else if symbols.len() == 1 && in_enum_constants(&object_kind, &object) {
+                        add_enum_constant(&mut symbols, &mut jdoc, &mut deprecated, &mut constants);
                     }
 
                     if comment {
@@ -674,6 +967,9 @@ pub mod parse {
                     if annotation {
                         ignore = true;
                         annotation = false;
+                    } else if symbols.len() > 0 && !doc && !comment && in_enum_constants(&object_kind, &object) {
+                        add_enum_constant(&mut symbols, &mut jdoc, &mut deprecated, &mut constants);
+                        constant_args = 1;
                     } else {
                         let temp_sym = symbols.clone();
                         if temp_sym.len() == 1 {
@@ -704,7 +1000,23 @@ pub mod parse {
                     }
                     symbols.clear();
                 }
+                Token::ExpressionEnd(_) if doc => {
+                    // The braces of inline javadoc tags like `{@link}` are part of the javadoc
+                }
                 Token::ExpressionEnd(end) => {
+                    // The lexer follows the end of a statement with its signature
+                    if let Some(Token::Sign(sign)) = tokens.get(i + 1) {
+                        signature = sign.clone();
+                    }
+
+                    if in_enum_constants(&object_kind, &object) && !doc && !comment {
+                        add_enum_constant(&mut symbols, &mut jdoc, &mut deprecated, &mut constants);
+                        // The body of an enum constant
+                        if end == "{" {
+                            continue;
+                        }
+                    }
+
                     // For any symbols not included add them to the stream for parsing
                     if symbols.len() == 1 {
                         gram_parts.push(Stream::Variable(symbols[0].clone()));
@@ -714,99 +1026,194 @@ pub mod parse {
                     }
 
                     let mut temp_gram = gram_parts.clone();
+                    let deprecation = get_deprecation(&jdoc, deprecated.take());
+                    deprecated_args = None;
 
                     match end.as_ref() {
                         ";" => {
                             if !in_object {
                                 if temp_gram.len() > 1 {
                                     match temp_gram[0].clone() {
-                                        Stream::Import => match temp_gram[1].clone() {
-                                            Stream::Variable(key) => object.add_dependency(key),
-                                            _ => println!("Pattern not supported"),
-                                        },
-                                        Stream::Package => match temp_gram[1].clone() {
-                                            Stream::Variable(key) => object.ch_package_name(key),
-                                            _ => println!("Pattern not supported"),
-                                        },
-                                        _ => object
-                                            .add_variable(get_var(temp_gram, line_num.clone(), signature.clone())),
+                                        Stream::Import => {
+                                            if let Stream::Variable(key) = temp_gram[1].clone() {
+                                                object.add_dependency(key)
+                                            }
+                                        }
+                                        Stream::Package => {
+                                            if let Stream::Variable(key) = temp_gram[1].clone() {
+                                                // The javadoc of a package declaration documents the
+                                                // package, which is only allowed in package-info.java
+                                                object.ch_description(jdoc.description.clone());
+                                                object.ch_package_name(key)
+                                            }
+                                        }
+                                        _ => object.add_variable(get_var(
+                                            temp_gram,
+                                            &jdoc,
+                                            line_num.clone(),
+                                            signature.clone(),
+                                            deprecation,
+                                        )),
                                     }
                                 }
                             } else {
-                                match object.state {
-                                    ObjectState::Class => {
-                                        object.add_variable(get_var(temp_gram, line_num.clone(), signature.clone()))
-                                    }
-                                    ObjectState::Enumeration => {
-                                        object.ch_fields(get_enum_fields(temp_gram))
+                                match object_kind {
+                                    Some(TypeKind::Class) | Some(TypeKind::Record) => object.add_variable(get_var(
+                                        temp_gram,
+                                        &jdoc,
+                                        line_num.clone(),
+                                        signature.clone(),
+                                        deprecation,
+                                    )),
+                                    Some(TypeKind::Enumeration) if object.fields.len() == 0 => {
+                                        object.ch_fields(mem::take(&mut constants))
                                     }
+                                    Some(TypeKind::Enumeration) => object.add_variable(get_var(
+                                        temp_gram,
+                                        &jdoc,
+                                        line_num.clone(),
+                                        signature.clone(),
+                                        deprecation,
+                                    )),
+                                    // The end of an annotation element with an array default value
+                                    _ if temp_gram.len() == 0 => (),
                                     _ => object.add_method(get_method(
                                         temp_gram,
                                         &jdoc,
                                         line_num.clone(),
                                         signature.clone(),
+                                        deprecation,
                                     )),
                                 }
                             }
                         }
                         "{" => match parse_state {
-                            ParseState::Interface | ParseState::Class | ParseState::Enum => {
-                                get_object(temp_gram.clone(), &jdoc, signature.clone(), &mut object)
+                            ParseState::Interface
+                            | ParseState::Class
+                            | ParseState::Enum
+                            | ParseState::Record
+                            | ParseState::Annotation => {
+                                if object.name != "" {
+                                    // A type declared in the body of another type is parsed
+                                    // until its closing brace and then added to it
+                                    let mut nested = TypeDecl::new(TypeKind::Class);
+                                    nested.ch_package_name(object.package_name.clone());
+                                    nested.dependencies = object.dependencies.clone();
+                                    nested.ch_enclosing(object.name.clone());
+                                    outer_types.push((mem::replace(&mut object, nested), object_kind.take()));
+                                    object_kind = nested_kind.take();
+                                }
+                                get_object(temp_gram.clone(), &jdoc, signature.clone(), deprecation, &mut object);
+                                if object.enclosing != "" {
+                                    let name = format!("{}.{}", object.enclosing, object.name.trim());
+                                    object.ch_name(name);
+                                }
                             }
                             ParseState::Other => {
-                                object.add_method(get_method(temp_gram, &jdoc, line_num.clone(), signature.clone()))
+                                object.add_method(get_method(
+                                    temp_gram,
+                                    &jdoc,
+                                    line_num.clone(),
+                                    signature.clone(),
+                                    deprecation,
+                                ))
                             }
                         },
                         _ => {
                             if comment {
                                 comment = false;
                             } else if !doc {
-                                panic!("Expression end not allowed");
+                                return Err(LojidocError::Parse(format!("Unexpected end of statement {}", end)));
                             }
                         }
                     }
 
                     parse_state = ParseState::Other;
+                    default_value = false;
                     jdoc = Doc::new();
                     gram_parts.clear();
                     symbols.clear();
                 }
+                Token::TypeEnd => {
+                    if let Some((outer, outer_kind)) = outer_types.pop() {
+                        if in_enum_constants(&object_kind, &object) {
+                            add_enum_constant(&mut symbols, &mut jdoc, &mut deprecated, &mut constants);
+                            object.ch_fields(mem::take(&mut constants));
+                        }
+                        if let Some(kind) = object_kind {
+                            object.ch_kind(kind);
+                        }
+
+                        let nested = mem::replace(&mut object, outer);
+                        object_kind = outer_kind;
+                        object.add_nested_type(nested);
+
+                        parse_state = ParseState::Other;
+                        jdoc = Doc::new();
+                        gram_parts.clear();
+                        symbols.clear();
+                    }
+                }
                 Token::LineNumber(num) => line_num = num,
                 Token::Sign(line) => signature = line,
             }
         }
 
-        match object.state {
-            ObjectState::Class => return ObjectType::Class(object.to_class()),
-            ObjectState::Interface => return ObjectType::Interface(object.to_interface()),
-            ObjectState::Enumeration => return ObjectType::Enumeration(object.to_enumeration()),
-            ObjectState::Unset => {
-                println!("Java file type not supported. Supported types: class, interface, enum");
-                println!("{:?}", tokens);
-                return ObjectType::Class(object.to_class());
+        // The constants of an enum without other members are not followed by a semicolon
+        if in_enum_constants(&object_kind, &object) {
+            add_enum_constant(&mut symbols, &mut jdoc, &mut deprecated, &mut constants);
+            object.ch_fields(constants);
+        }
+
+        match object_kind {
+            Some(kind) => object.ch_kind(kind),
+            // A package declaration without a type, the contents of package-info.java
+            None if object.package_name != "" && object.name == "" => (),
+            None => {
+                return Err(LojidocError::Parse(String::from(
+                    "No type declared. Supported types: class, interface, enum, record, annotation",
+                )))
             }
         }
+
+        Ok(object)
+    }
+
+    /// Lexes and parses the contents of a java file and returns the object
+    /// declared in it.
+    ///
+    /// # Arguments
+    ///
+    /// * `contents` - The java source code
+    pub fn parse_contents(contents: &str) -> Result<TypeDecl, LojidocError> {
+        let tokens = lex_contents(&contents.to_string());
+        construct_ast(tokens)
     }
 
-    /// Root function of the module. Calls the lex and parse functions and returns
-    /// a `Class` struct.
+    /// Root function of the module. Reads the java file, calls the lex and parse
+    /// functions and returns a `TypeDecl` struct.
     ///
     /// # Arguments
     ///
     /// * `path` - The path of the java file
-    /// * `lint` - A bool representing whether the class's javadoc comments should be linted
-    pub fn parse_file(path: &Path, _lint: bool) -> ObjectType {
-        let file = File::open(path).expect("Could not open file");
+    pub fn parse_file(path: &Path) -> Result<TypeDecl, LojidocError> {
         let mut contents = String::new();
-        let mut buf = BufReader::new(file);
-        let res = buf.read_to_string(&mut contents);
-        if res.is_ok() {
-            let tokens = lex_contents(&contents);
-            construct_ast(tokens)
-        } else {
-            println!("Unable to read file");
-            ObjectType::Class(Class::new())
-        }
+        BufReader::new(File::open(path)?).read_to_string(&mut contents)?;
+
+        parse_source(&contents, path.to_str().unwrap_or(""))
+    }
+
+    /// Parses the contents of a java file and names the file in the parse errors
+    ///
+    /// # Arguments
+    ///
+    /// * `contents` - The java source code
+    /// * `file` - The path of the java file
+    pub fn parse_source(contents: &str, file: &str) -> Result<TypeDecl, LojidocError> {
+        parse_contents(contents).map_err(|err| match err {
+            LojidocError::Parse(msg) => LojidocError::Parse(format!("{}: {}", file, msg)),
+            err => err,
+        })
     }
 }
 
@@ -1,3 +1,4 @@
+use error::error::LojidocError;
 use grammar::grammar::Token;
 use model::model::*;
 use parse::parse::*;
@@ -19,6 +20,7 @@ fn test_method_lex() {
     assert_eq!(Token::Symbol(String::from("args")), tokens[8]);
     assert_eq!(Token::ParamEnd, tokens[9]);
     assert_eq!(Token::ExpressionEnd(String::from("{")), tokens[10]);
+    assert_eq!(Token::Sign(String::from("public final static void main(String[] args)")), tokens[11]);
 }
 
 #[test]
@@ -172,3 +174,280 @@ fn test_param_match() {
         String::from("Map<List<Object>, Map<String, List<String>>>")
     );
 }
+
+#[test]
+fn test_parse_contents() {
+    let j_class = "package com.stuff;
+
+                   /**
+                    * A sample class
+                    */
+                   public class Sample {
+                   }";
+
+    let class = parse_contents(j_class).unwrap();
+
+    assert_eq!(TypeKind::Class, class.kind);
+    assert_eq!("Sample", class.name.as_str());
+    assert_eq!("com.stuff", class.package_name.as_str());
+    assert_eq!("public", class.access.as_str());
+}
+
+#[test]
+fn test_parse_errors() {
+    match parse_contents("public void run() {\n}\n") {
+        Err(LojidocError::Parse(msg)) => assert!(msg.starts_with("No type declared")),
+        _ => panic!("a file without a type declaration must not parse"),
+    }
+    match parse_source("int count;\n", "src/Broken.java") {
+        Err(LojidocError::Parse(msg)) => assert!(msg.starts_with("src/Broken.java: ")),
+        _ => panic!("a file without a type declaration must not parse"),
+    }
+}
+
+#[test]
+fn test_trailing_javadoc_tags() {
+    let decl = parse_contents(
+        "package a;\n/**\n * Tool\n *\n * @author Sam\n * @see Other\n * @see Helper\n */\npublic class Tool {\n}\n",
+    ).unwrap();
+
+    assert_eq!(decl.author.trim(), "Sam");
+    assert_eq!(decl.see, vec!["Other".to_string(), "Helper".to_string()]);
+}
+
+#[test]
+fn test_last_javadoc_tag() {
+    let decl = parse_contents(
+        "package a;\npublic class Tool {\n    /** Gets the count */\n    public int count() {\n    }\n\n    /**\n     * Adds two numbers\n     * @param a The first number\n     * @return The sum\n     */\n    public int add(int a) {\n    }\n\n    /**\n     * Resets the tool\n     * @param force Whether to skip the checks\n     */\n    public void reset(boolean force) {\n    }\n}\n",
+    ).unwrap();
+
+    assert_eq!(decl.methods[0].description.trim(), "Gets the count");
+    assert_eq!(decl.methods[1].description.trim(), "Adds two numbers");
+    assert_eq!(decl.methods[1].return_type, "int");
+    assert_eq!(decl.methods[1].return_desc.trim(), "The sum");
+    assert_eq!(decl.methods[1].parameters[0].desc.trim(), "The first number");
+    assert_eq!(decl.methods[2].parameters[0].desc.trim(), "Whether to skip the checks");
+    assert_eq!(decl.methods[2].see.len(), 0);
+}
+
+#[test]
+fn test_declaration_signatures() {
+    let decl = parse_contents(
+        "package a;\n\n/** A {@link Order} repository */\npublic class Repo\n        implements Store {\n    \
+         /** The size, see {@link #find} */\n    public static final int SIZE = 10; // rows\n\n    \
+         /** Finds the orders */\n    public List<Order> find(String name, /* unused */\n                            \
+         int limit) throws IOException {\n        return null;\n    }\n}\n",
+    ).unwrap();
+
+    assert_eq!(decl.signature, "public class Repo implements Store");
+    assert_eq!(decl.variables[0].signature, "public static final int SIZE = 10");
+    assert_eq!(decl.methods.len(), 1);
+    assert_eq!(decl.methods[0].signature, "public List<Order> find(String name, int limit) throws IOException");
+}
+
+#[test]
+fn test_member_line_numbers() {
+    let decl = parse_contents("package a;\npublic class Tool {\n    private long id;\n\n    public int count = 0;\n}\n").unwrap();
+
+    assert_eq!(decl.variables[0].line_num, "3");
+    assert_eq!(decl.variables[1].line_num, "5");
+}
+
+#[test]
+fn test_record_and_annotation() {
+    let record = parse_contents(
+        "package a;\n/**\n * A point\n *\n * @param x The horizontal position\n */\n\
+         public record Point(int x, int y) implements Shape {\n    public double length() {\n        return 0;\n    }\n}\n",
+    ).unwrap();
+
+    assert_eq!(TypeKind::Record, record.kind);
+    assert_eq!("Point", record.name.as_str());
+    assert_eq!(vec!["Shape".to_string()], record.interfaces);
+    assert_eq!(record.variables.len(), 2);
+    assert_eq!(record.variables[0].var_type, "int");
+    assert_eq!(record.variables[0].desc.trim(), "The horizontal position");
+    assert_eq!(record.variables[1].name, "y");
+    assert_eq!(record.methods[0].name, "length");
+
+    let annotation = parse_contents(
+        "package a;\npublic @interface Audited {\n    String value() default \"\";\n    int level();\n}\n",
+    ).unwrap();
+
+    assert_eq!(TypeKind::Annotation, annotation.kind);
+    assert_eq!("Audited", annotation.name.as_str());
+    assert_eq!(annotation.methods[0].name, "value");
+    assert_eq!(annotation.methods[1].name, "level");
+}
+
+#[test]
+fn test_record_components_and_annotation_defaults() {
+    let record = parse_contents(
+        "package a;\npublic record Page<T>(List<T> items, long total) {\n    public Page {\n        \
+         if (total < 0) {\n            throw new IllegalArgumentException();\n        }\n    }\n\n    \
+         public boolean isEmpty() {\n        return items.isEmpty();\n    }\n}\n",
+    ).unwrap();
+
+    assert_eq!(TypeKind::Record, record.kind);
+    assert_eq!(record.variables.len(), 2);
+    assert_eq!(record.variables[0].var_type, "List<T>");
+    assert_eq!(record.variables[0].name, "items");
+    assert_eq!(record.variables[1].var_type, "long");
+    assert_eq!(record.methods.last().unwrap().name, "isEmpty");
+
+    let annotation = parse_contents(
+        "package a;\npublic @interface Tags {\n    String[] value() default {\"a\", \"b\"};\n    \
+         Class<?> type() default Object.class;\n}\n",
+    ).unwrap();
+
+    assert_eq!(annotation.methods.len(), 2);
+    assert_eq!(annotation.methods[0].return_type, "String[]");
+    assert_eq!(annotation.methods[1].name, "type");
+}
+
+#[test]
+fn test_package_info() {
+    let decl = parse_contents("/**\n * The model of the shop\n */\npackage com.shop.model;\n").unwrap();
+
+    assert_eq!("", decl.name.as_str());
+    assert_eq!("com.shop.model", decl.package_name.as_str());
+    assert_eq!("The model of the shop", decl.description.trim());
+}
+
+#[test]
+fn test_enum_constants() {
+    let plain = parse_contents("package a;\npublic enum Status {\n    OPEN, PAID,\n    CLOSED\n}\n").unwrap();
+    let names: Vec<&str> = plain.fields.iter().map(|f| f.name.as_str()).collect();
+
+    assert_eq!(names, vec!["OPEN", "PAID", "CLOSED"]);
+    assert_eq!(plain.fields[2].value, "2");
+
+    let members = parse_contents(
+        "package a;\npublic enum Level {\n    LOW, HIGH;\n\n    private int weight;\n\n    public int getWeight() {\n        return weight;\n    }\n}\n",
+    ).unwrap();
+
+    assert_eq!(members.fields.len(), 2);
+    assert_eq!(members.variables[0].name, "weight");
+    assert_eq!(members.methods[0].name, "getWeight");
+}
+
+#[test]
+fn test_enum_constant_arguments() {
+    let decl = parse_contents(
+        "package a;\npublic enum Level {\n    /** The lowest level */\n    LOW(1, \"low\"),\n    /** The middle level */\n    \
+         MEDIUM(Math.max(1, 2), \"medium\"),\n    HIGH(3, \"high\") {\n        int boost() {\n            return 1;\n        }\n    };\n\n    \
+         private final int weight;\n\n    public int getWeight() {\n        return weight;\n    }\n}\n",
+    ).unwrap();
+    let names: Vec<&str> = decl.fields.iter().map(|f| f.name.as_str()).collect();
+
+    assert_eq!(names, vec!["LOW", "MEDIUM", "HIGH"]);
+    assert_eq!(decl.fields[2].value, "2");
+    assert_eq!(decl.variables.len(), 1);
+    assert_eq!(decl.variables[0].name, "weight");
+    assert_eq!(decl.methods.len(), 1);
+    assert_eq!(decl.methods[0].name, "getWeight");
+}
+
+#[test]
+fn test_deprecation() {
+    let decl = parse_contents(
+        "package a;\n/**\n * An order\n * @deprecated Use Purchase instead\n */\n@Deprecated(since = \"1.2\", forRemoval = true)\npublic class Order {\n    /** The total @deprecated Use amount */\n    @Deprecated\n    public int total;\n\n    /**\n     * Pays the order\n     * @deprecated\n     */\n    @java.lang.Deprecated(since = \"2.0\")\n    public void pay() {\n    }\n\n    public void ship() {\n    }\n}\n",
+    ).unwrap();
+
+    let deprecation = decl.deprecation.unwrap();
+    assert_eq!(deprecation.reason.trim(), "Use Purchase instead");
+    assert_eq!(deprecation.since, "1.2");
+    assert!(deprecation.for_removal);
+
+    let member = decl.variables[0].deprecation.clone().unwrap();
+    assert!(!member.for_removal);
+    assert_eq!(member.since, "");
+
+    let method = decl.methods[0].deprecation.clone().unwrap();
+    assert_eq!(method.since, "2.0");
+    assert_eq!(decl.methods[1].deprecation, None);
+}
+
+#[test]
+fn test_enum_constant_deprecation() {
+    let decl = parse_contents(
+        "package a;\npublic enum Status {\n    OPEN,\n    /** @deprecated Use CLOSED */\n    @Deprecated(since = \"2.1\")\n    \
+         CANCELLED(3),\n    @Deprecated\n    PAID,\n    CLOSED\n}\n",
+    ).unwrap();
+
+    assert_eq!(decl.fields.len(), 4);
+    assert_eq!(decl.fields[0].deprecation, None);
+
+    let cancelled = decl.fields[1].deprecation.clone().unwrap();
+    assert_eq!(cancelled.since, "2.1");
+    assert_eq!(cancelled.reason, "Use CLOSED");
+    assert!(decl.fields[2].deprecation.is_some());
+    assert_eq!(decl.fields[3].deprecation, None);
+}
+
+#[test]
+fn test_since() {
+    let decl = parse_contents(
+        "package a;\n/**\n * An order\n * @version 4.1\n * @since 3.2\n */\npublic class Order {\n    /** The id @since 3.3 */\n    private long id;\n\n    /**\n     * Cancels the order\n     * @since 3.4\n     */\n    public void cancel() {\n    }\n}\n",
+    ).unwrap();
+
+    assert_eq!(decl.version.trim(), "4.1");
+    assert_eq!(decl.since, "3.2");
+    assert_eq!(decl.variables[0].since, "3.3");
+    assert_eq!(decl.methods[0].since, "3.4");
+
+    let status = parse_contents(
+        "package a;\npublic enum Status {\n    OPEN,\n    /**\n     * Closed for good\n     * @since 3.5\n     */\n    CLOSED\n}\n",
+    ).unwrap();
+
+    assert_eq!(status.fields[0].since, "");
+    assert_eq!(status.fields[1].since, "3.5");
+    assert_eq!(status.fields[1].desc.trim(), "Closed for good");
+}
+
+#[test]
+fn test_constructor_names() {
+    let decl = parse_contents(
+        "package a;\npublic class Order {\n    /** Creates an order */\n    @Inject\n    public Order(@Named(\"id\") long id) {\n    }\n\n    \
+         protected <T> Order(T item, int count) throws Exception {\n    }\n\n    public static class Builder {\n        \
+         Builder() {\n        }\n    }\n}\n",
+    ).unwrap();
+
+    let names: Vec<&str> = decl.methods.iter().map(|m| m.name.as_str()).collect();
+    assert_eq!(names, vec!["Order", "Order"]);
+    assert_eq!(decl.methods[0].return_type, "");
+    assert_eq!(decl.methods[0].description.trim(), "Creates an order");
+    assert_eq!(decl.methods[1].parameters.len(), 2);
+    assert_eq!(decl.nested_types[0].methods[0].name, "Builder");
+}
+
+#[test]
+fn test_nested_types() {
+    let decl = parse_contents(
+        "package a;\npublic class Outer {\n    public int count;\n\n    private static class Helper {\n        public int value;\n\n        public void run() {\n        }\n    }\n\n    public enum Mode {\n        FAST, SLOW\n    }\n\n    public record Point(int x, int y) {\n    }\n\n    public int getCount() {\n        return count;\n    }\n}\n",
+    ).unwrap();
+
+    assert_eq!(TypeKind::Class, decl.kind);
+    assert_eq!("Outer", decl.name.as_str());
+    assert_eq!(decl.variables[0].name, "count");
+    assert_eq!(decl.methods.len(), 1);
+    assert_eq!(decl.methods[0].name, "getCount");
+    assert_eq!(decl.nested, vec!["Outer.Helper", "Outer.Mode", "Outer.Point"]);
+
+    let helper = &decl.nested_types[0];
+    assert_eq!("Helper", helper.simple_name());
+    assert_eq!("Outer", helper.enclosing.as_str());
+    assert_eq!("a", helper.package_name.as_str());
+    assert_eq!("private", helper.access.trim());
+    assert_eq!(helper.variables[0].name, "value");
+    assert_eq!(helper.methods[0].name, "run");
+
+    let mode = &decl.nested_types[1];
+    assert_eq!(TypeKind::Enumeration, mode.kind);
+    let names: Vec<&str> = mode.fields.iter().map(|f| f.name.as_str()).collect();
+    assert_eq!(names, vec!["FAST", "SLOW"]);
+
+    let point = &decl.nested_types[2];
+    assert_eq!(TypeKind::Record, point.kind);
+    assert_eq!(point.variables.len(), 2);
+}
+
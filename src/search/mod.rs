@@ -0,0 +1,114 @@
+pub mod search {
+    //! Module that creates the client-side search index of the generated documentation
+    //!
+    //! The index lists every type, method, member variable and enum constant with its
+    //! qualified name, the first sentence of its description and the url of its page.
+    //! It is written as `search-index.json` for other tools and as `search-index.js`,
+    //! which the `lojidoc-search.js` widget loads without a web server.
+
+    use std::fs;
+    use std::fs::File;
+    use std::io;
+    use std::io::prelude::*;
+
+    use serde_json;
+
+    use model::model::field_anchor;
+    use model::model::first_sentence;
+    use model::model::method_anchor;
+    use model::model::LinkFn;
+    use model::model::Project;
+    use model::usage::qualified_name;
+
+    /// The search widget, which matches names by prefix and by camel case humps
+    pub const SEARCH_SCRIPT: &'static str = include_str!("search.js");
+
+    #[derive(Debug, Clone, Serialize)]
+    /// A searchable type or member
+    pub struct SearchEntry {
+        pub name: String,
+        pub qualified_name: String,
+        pub kind: String,
+        pub summary: String,
+        pub url: String,
+    }
+
+    /// Creates the search index of a project
+    ///
+    /// # Arguments
+    ///
+    /// * `proj` - The documented project
+    /// * `url` - Creates the urls of the type pages
+    pub fn build_index(proj: &Project, url: &LinkFn) -> Vec<SearchEntry> {
+        let mut entries = Vec::new();
+
+        for decl in proj.types.iter() {
+            let type_name = qualified_name(&decl.package_name, &decl.name);
+
+            entries.push(SearchEntry {
+                name: decl.name.trim().to_string(),
+                qualified_name: type_name.clone(),
+                kind: decl.kind.label().to_string(),
+                summary: first_sentence(&decl.description),
+                url: url(decl, ""),
+            });
+
+            for field in decl.fields.iter() {
+                entries.push(SearchEntry {
+                    name: field.name.clone(),
+                    qualified_name: format!("{}.{}", type_name, field.name),
+                    kind: String::from("constant"),
+                    summary: first_sentence(&field.desc),
+                    url: url(decl, ""),
+                });
+            }
+            for member in decl.variables.iter() {
+                entries.push(SearchEntry {
+                    name: member.name.clone(),
+                    qualified_name: format!("{}.{}", type_name, member.name),
+                    kind: String::from("field"),
+                    summary: first_sentence(&member.desc),
+                    url: url(decl, &field_anchor(member)),
+                });
+            }
+            for (i, method) in decl.methods.iter().enumerate() {
+                if method.name == "" {
+                    continue;
+                }
+
+                entries.push(SearchEntry {
+                    name: method.name.clone(),
+                    qualified_name: format!("{}.{}", type_name, method.name),
+                    kind: String::from(if method.name == decl.simple_name() { "constructor" } else { "method" }),
+                    summary: first_sentence(&method.description),
+                    url: url(decl, &method_anchor(&decl.methods, i)),
+                });
+            }
+        }
+        entries.sort_by(|a, b| a.qualified_name.cmp(&b.qualified_name));
+
+        entries
+    }
+
+    /// Writes `search-index.json`, `search-index.js` and the `lojidoc-search.js` widget
+    /// into a directory
+    ///
+    /// # Arguments
+    ///
+    /// * `entries` - The search index
+    /// * `dir` - The directory the urls of the index are relative to
+    pub fn write_search_index(entries: &Vec<SearchEntry>, dir: &str) -> io::Result<()> {
+        let json = serde_json::to_string(entries)?;
+
+        fs::create_dir_all(dir)?;
+        File::create(format!("{}/search-index.json", dir))?.write_all(json.as_bytes())?;
+        File::create(format!("{}/search-index.js", dir))?
+            .write_all(format!("window.LOJIDOC_SEARCH_INDEX = {};\n", json).as_bytes())?;
+        File::create(format!("{}/lojidoc-search.js", dir))?.write_all(SEARCH_SCRIPT.as_bytes())?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test;
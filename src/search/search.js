@@ -0,0 +1,115 @@
+// Lojidoc search widget. The index is read from window.LOJIDOC_SEARCH_INDEX, which
+// search-index.js defines, and the urls in it are relative to the directory of this script.
+(function () {
+    var script = document.currentScript;
+    var base = script ? script.src.replace(/[^\/]*$/, '') : '';
+
+    function escapeRegExp(text) {
+        return text.replace(/[.*+?^${}()|[\]\\]/g, '\\$&');
+    }
+
+    // Every character of the query has to follow the previous one in the same hump of the
+    // name, while an upper case character may skip to the next hump: `OSv` matches `OrderService`.
+    function camelCasePattern(query) {
+        return new RegExp('^' + query.split('').map(escapeRegExp).join('[^A-Z]*'));
+    }
+
+    function score(entry, lower, camel) {
+        var name = entry.name.toLowerCase();
+
+        if (name === lower) {
+            return 0;
+        }
+        if (name.indexOf(lower) === 0) {
+            return 1;
+        }
+        if (camel && camel.test(entry.name)) {
+            return 2;
+        }
+        if (entry.qualified_name.toLowerCase().indexOf(lower) === 0) {
+            return 3;
+        }
+        return -1;
+    }
+
+    function search(query) {
+        var lower = query.toLowerCase();
+        var camel = /[A-Z]/.test(query) ? camelCasePattern(query) : null;
+        var results = [];
+
+        (window.LOJIDOC_SEARCH_INDEX || []).forEach(function (entry) {
+            var s = score(entry, lower, camel);
+            if (s >= 0) {
+                results.push({ entry: entry, score: s });
+            }
+        });
+        results.sort(function (a, b) {
+            return a.score - b.score || a.entry.qualified_name.localeCompare(b.entry.qualified_name);
+        });
+
+        return results.slice(0, 50).map(function (r) { return r.entry; });
+    }
+
+    function render(list, entries) {
+        list.innerHTML = '';
+        entries.forEach(function (entry) {
+            var item = document.createElement('li');
+            var link = document.createElement('a');
+            var detail = document.createElement('span');
+
+            link.href = base + entry.url;
+            link.textContent = entry.name;
+            detail.textContent = ' ' + entry.kind + ' ' + entry.qualified_name;
+            item.appendChild(link);
+            item.appendChild(detail);
+            if (entry.summary) {
+                var summary = document.createElement('div');
+                summary.textContent = entry.summary;
+                item.appendChild(summary);
+            }
+            list.appendChild(item);
+        });
+    }
+
+    function init() {
+        var container = document.getElementById('lojidoc-search');
+        if (!container) {
+            container = document.createElement('div');
+            container.id = 'lojidoc-search';
+            var main = document.querySelector('main') || document.body;
+            main.insertBefore(container, main.firstChild);
+        }
+
+        var style = document.createElement('style');
+        style.textContent = '#lojidoc-search input { width: 100%; box-sizing: border-box; padding: 4px 8px; }' +
+            '#lojidoc-search ul { list-style: none; margin: 4px 0; padding: 0; max-height: 60vh; overflow-y: auto; }' +
+            '#lojidoc-search li { padding: 4px 0; font-size: 14px; }' +
+            '#lojidoc-search li span, #lojidoc-search li div { color: #586069; font-size: 12px; }';
+        document.head.appendChild(style);
+
+        var input = document.createElement('input');
+        var list = document.createElement('ul');
+        input.type = 'search';
+        input.placeholder = 'Search types and members';
+        container.appendChild(input);
+        container.appendChild(list);
+
+        input.addEventListener('input', function () {
+            var query = input.value.trim();
+            render(list, query === '' ? [] : search(query));
+        });
+        input.addEventListener('keydown', function (event) {
+            var first = list.querySelector('a');
+            if (event.key === 'Enter' && first) {
+                window.location.href = first.href;
+            }
+        });
+    }
+
+    window.lojidocSearch = search;
+    if (document.readyState === 'loading') {
+        document.addEventListener('DOMContentLoaded', init);
+    } else {
+        init();
+    }
+})();
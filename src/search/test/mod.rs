@@ -0,0 +1,28 @@
+use model::model::*;
+use search::search::*;
+
+#[test]
+fn test_build_index() {
+    let mut proj = Project::new();
+    let mut decl = TypeDecl::new(TypeKind::Interface);
+    decl.ch_name("OrderService".to_string());
+    decl.ch_package_name("com.shop".to_string());
+    decl.ch_description("Finds orders. Thread safe.".to_string());
+
+    let mut method = Method::new();
+    method.ch_method_name("find".to_string());
+    method.ch_description("Finds an order".to_string());
+    decl.add_method(method.clone());
+    decl.add_method(method);
+    proj.add_type(decl);
+
+    let entries = build_index(&proj, &|decl, anchor| format!("{}.html#{}", decl.name, anchor));
+
+    assert_eq!(entries.len(), 3);
+    assert_eq!(entries[0].qualified_name, "com.shop.OrderService");
+    assert_eq!(entries[0].kind, "interface");
+    assert_eq!(entries[0].summary, "Finds orders.");
+    assert_eq!(entries[1].qualified_name, "com.shop.OrderService.find");
+    assert_eq!(entries[1].url, "OrderService.html#method.find");
+    assert_eq!(entries[2].url, "OrderService.html#method.find-1");
+}
@@ -0,0 +1,316 @@
+pub mod site {
+    //! Module that adapts the markdown pages to the conventions of static site generators
+    //!
+    //! Each profile adds YAML front matter to the pages, writes the navigation file the
+    //! generator reads and links between pages the way the generator publishes them.
+    //! The navigation files expect the destination directory to be the root of the
+    //! generator's content directory. For MkDocs that is the `docs_dir`, and the `nav`
+    //! key is written into the `mkdocs.yml` next to it.
+
+    use std::collections::HashMap;
+    use std::fs;
+    use std::fs::File;
+    use std::io;
+    use std::io::prelude::*;
+    use std::path::Path;
+
+    use document::document::package_path;
+    use model::model::ApplicationDoc;
+    use model::model::Project;
+    use model::model::SiteProfile;
+    use model::model::TypeDecl;
+
+    /// Quotes a string as a YAML scalar
+    pub fn yaml_string(text: &str) -> String {
+        format!("\"{}\"", text.trim().replace("\\", "\\\\").replace("\"", "\\\""))
+    }
+
+    /// Creates the link from a page in one package to the page of a type the way
+    /// the site generator publishes it
+    ///
+    /// # Arguments
+    ///
+    /// * `site` - The site profile
+    /// * `from_package` - The package of the page containing the link, empty for the contents page
+    /// * `to_package` - The package of the linked type
+    /// * `name` - The name of the linked type
+    /// * `ext` - The extension of the generated pages
+    pub fn page_link(site: &SiteProfile, from_package: &str, to_package: &str, name: &str, ext: &str) -> String {
+        let path = package_path(from_package, to_package);
+
+        match site {
+            SiteProfile::Plain => format!("{}{}.{}", path, name, ext),
+            SiteProfile::Hugo => format!("{{{{< relref \"{}{}.{}\" >}}}}", path, name, ext),
+            SiteProfile::Jekyll => format!("{}{}.html", path, name),
+            SiteProfile::Mkdocs => format!("../{}{}/", path, name),
+            SiteProfile::Docusaurus => format!("{}{}", path, name),
+        }
+    }
+
+    /// The titles and the file names without the extension of the report pages written
+    /// for every project, listed after the packages in the navigation
+    pub const REPORT_PAGES: [(&str, &str); 3] = [
+        ("API by Version", "Versions"),
+        ("Deprecated API", "Deprecated"),
+        ("Index", "Index"),
+    ];
+
+    /// Gets the file name of the contents page
+    pub fn contents_file(site: &SiteProfile) -> &'static str {
+        match site {
+            SiteProfile::Hugo => "_index.md",
+            _ => "Contents.md",
+        }
+    }
+
+    /// Numbers the types of every package alphabetically, starting at 1. The number is
+    /// used as the weight of the page in the generator's navigation.
+    ///
+    /// # Arguments
+    ///
+    /// * `proj` - The documented project
+    pub fn type_weights(proj: &Project) -> HashMap<String, usize> {
+        let mut names: Vec<(String, String)> = proj
+            .types
+            .iter()
+            .map(|decl| (decl.package_name.trim().to_string(), decl.name.trim().to_string()))
+            .collect();
+        let mut weights = HashMap::new();
+        let mut weight = 0;
+        let mut package = String::new();
+
+        names.sort();
+        for (p, name) in names {
+            if p != package {
+                package = p.clone();
+                weight = 0;
+            }
+            weight += 1;
+            weights.insert(format!("{}.{}", p, name), weight);
+        }
+
+        weights
+    }
+
+    /// Generates the YAML front matter of the page of a type
+    ///
+    /// # Arguments
+    ///
+    /// * `site` - The site profile
+    /// * `decl` - The documented type
+    /// * `weight` - The position of the type in its package
+    pub fn front_matter(site: &SiteProfile, decl: &TypeDecl, weight: usize) -> String {
+        let mut doc = String::from("---\n");
+        let mut tags = Vec::new();
+
+        if decl.deprecation.is_some() {
+            tags.push(String::from("deprecated"));
+        }
+        if decl.version.trim() != "" {
+            tags.push(format!("since-{}", decl.version.trim()));
+        }
+
+        doc.push_str(format!("title: {}\n", yaml_string(&decl.name)).as_str());
+        doc.push_str(format!("package: {}\n", yaml_string(&decl.package_name)).as_str());
+        doc.push_str(format!("kind: {}\n", decl.kind.label()).as_str());
+        doc.push_str(format!("weight: {}\n", weight).as_str());
+
+        match site {
+            SiteProfile::Jekyll => doc.push_str("layout: default\n"),
+            SiteProfile::Docusaurus => {
+                doc.push_str(format!("sidebar_position: {}\n", weight).as_str());
+                doc.push_str("format: md\n");
+            }
+            _ => (),
+        }
+
+        if tags.len() > 0 {
+            doc.push_str("tags:\n");
+            for tag in tags {
+                doc.push_str(format!("  - {}\n", yaml_string(&tag)).as_str());
+            }
+        }
+        doc.push_str("---\n\n");
+
+        doc
+    }
+
+    /// Generates the YAML front matter of a page which does not document a type
+    ///
+    /// # Arguments
+    ///
+    /// * `site` - The site profile
+    /// * `title` - The title of the page
+    pub fn page_front_matter(site: &SiteProfile, title: &str) -> String {
+        match site {
+            SiteProfile::Plain => String::new(),
+            SiteProfile::Jekyll => format!("---\ntitle: {}\nlayout: default\n---\n\n", yaml_string(title)),
+            _ => format!("---\ntitle: {}\n---\n\n", yaml_string(title)),
+        }
+    }
+
+    /// Generates the YAML front matter of the contents page
+    pub fn contents_front_matter(site: &SiteProfile) -> String {
+        page_front_matter(site, "Application Contents")
+    }
+
+    /// Generates the YAML front matter of a report page from its first heading
+    pub fn report_front_matter(site: &SiteProfile, report: &str) -> String {
+        let title = report.lines().next().unwrap_or("").trim_start_matches('#');
+        page_front_matter(site, title)
+    }
+
+    /// Writes the navigation files of the site generator into the destination directory
+    ///
+    /// # Arguments
+    ///
+    /// * `site` - The site profile
+    /// * `app` - The packages and types of the documented project
+    /// * `dest` - The destination directory
+    pub fn write_nav(site: &SiteProfile, app: &ApplicationDoc, dest: &str) -> io::Result<()> {
+        match site {
+            SiteProfile::Plain => Ok(()),
+            SiteProfile::Hugo => write_hugo_sections(app, dest),
+            SiteProfile::Jekyll => {
+                fs::create_dir_all(format!("{}/_data", dest))?;
+                write_file(format!("{}/_data/navigation.yml", dest), gen_jekyll_nav(app))
+            }
+            SiteProfile::Mkdocs => write_mkdocs_config(app, dest),
+            SiteProfile::Docusaurus => write_file(format!("{}/sidebars.js", dest), gen_docusaurus_sidebar(app)),
+        }
+    }
+
+    fn write_file(path: String, contents: String) -> io::Result<()> {
+        let mut file = File::create(path)?;
+        file.write_all(contents.as_bytes())
+    }
+
+    /// The path of a type page from the destination directory without the extension
+    fn page_id(package: &str, name: &str) -> String {
+        format!("{}{}", package_path("", package), name)
+    }
+
+    /// Writes an `_index.md` section page for every package directory
+    fn write_hugo_sections(app: &ApplicationDoc, dest: &str) -> io::Result<()> {
+        for p in app.packages.iter() {
+            let dir = format!("{}/{}", dest, package_path("", &p.name));
+            let mut doc = format!("---\ntitle: {}\n---\n\n", yaml_string(&p.name));
+
+            for name in p.members.iter() {
+                doc.push_str(
+                    format!("- [{}]({{{{< relref \"{}.md\" >}}}})\n", name, name).as_str(),
+                );
+            }
+
+            fs::create_dir_all(dir.clone())?;
+            write_file(format!("{}_index.md", dir), doc)?;
+        }
+
+        Ok(())
+    }
+
+    /// Replaces the `nav` key of the contents of a `mkdocs.yml` file and keeps every
+    /// other key
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The contents of the `mkdocs.yml` file
+    /// * `nav` - The new `nav` section
+    pub fn merge_mkdocs_nav(config: &str, nav: &str) -> String {
+        let mut doc = String::new();
+        let mut in_nav = false;
+
+        for line in config.lines() {
+            let top_level = line != "" && !line.starts_with(' ') && !line.starts_with('-') && !line.starts_with('#');
+            if top_level {
+                in_nav = line.starts_with("nav:");
+            }
+            if !in_nav {
+                doc.push_str(line);
+                doc.push_str("
+");
+            }
+        }
+        doc.push_str(nav);
+
+        doc
+    }
+
+    /// Writes the `nav` key into the `mkdocs.yml` of the parent of the destination
+    /// directory. A new `mkdocs.yml` uses the destination directory as `docs_dir`.
+    fn write_mkdocs_config(app: &ApplicationDoc, dest: &str) -> io::Result<()> {
+        let dest = Path::new(dest);
+        let root = dest.parent().unwrap_or(Path::new(""));
+        let path = root.join("mkdocs.yml");
+
+        let config = if path.is_file() {
+            fs::read_to_string(&path)?
+        } else {
+            let docs_dir = dest.file_name().and_then(|d| d.to_str()).unwrap_or("docs");
+            format!("site_name: \"API Reference\"\ndocs_dir: {}\n", yaml_string(docs_dir))
+        };
+
+        let mut file = File::create(path)?;
+        file.write_all(merge_mkdocs_nav(&config, &gen_mkdocs_nav(app)).as_bytes())
+    }
+
+    /// Generates the `nav` section of `mkdocs.yml`
+    pub fn gen_mkdocs_nav(app: &ApplicationDoc) -> String {
+        let mut doc = String::from("nav:\n  - Application Contents: Contents.md\n");
+
+        for p in app.packages.iter() {
+            doc.push_str(format!("  - {}:\n", yaml_string(&p.name)).as_str());
+            for name in p.members.iter() {
+                doc.push_str(
+                    format!("    - {}: {}.md\n", yaml_string(name), page_id(&p.name, name)).as_str(),
+                );
+            }
+        }
+        for &(title, page) in REPORT_PAGES.iter() {
+            doc.push_str(format!("  - {}: {}.md\n", yaml_string(title), page).as_str());
+        }
+
+        doc
+    }
+
+    /// Generates a Jekyll data file listing the pages by package
+    pub fn gen_jekyll_nav(app: &ApplicationDoc) -> String {
+        let mut doc = String::from("- title: \"Application Contents\"\n  url: /Contents.html\n");
+
+        for p in app.packages.iter() {
+            doc.push_str(format!("- title: {}\n  children:\n", yaml_string(&p.name)).as_str());
+            for name in p.members.iter() {
+                doc.push_str(format!("    - title: {}\n", yaml_string(name)).as_str());
+                doc.push_str(format!("      url: /{}.html\n", page_id(&p.name, name)).as_str());
+            }
+        }
+        for &(title, page) in REPORT_PAGES.iter() {
+            doc.push_str(format!("- title: {}\n  url: /{}.html\n", yaml_string(title), page).as_str());
+        }
+
+        doc
+    }
+
+    /// Generates a Docusaurus `sidebars.js` with a category for every package
+    pub fn gen_docusaurus_sidebar(app: &ApplicationDoc) -> String {
+        let mut doc = String::from("module.exports = {\n  apiSidebar: [\n    'Contents',\n");
+
+        for p in app.packages.iter() {
+            doc.push_str("    {\n      type: 'category',\n");
+            doc.push_str(format!("      label: '{}',\n      items: [\n", p.name.trim()).as_str());
+            for name in p.members.iter() {
+                doc.push_str(format!("        '{}',\n", page_id(&p.name, name)).as_str());
+            }
+            doc.push_str("      ],\n    },\n");
+        }
+        for &(_, page) in REPORT_PAGES.iter() {
+            doc.push_str(format!("    '{}',\n", page).as_str());
+        }
+        doc.push_str("  ],\n};\n");
+
+        doc
+    }
+}
+
+#[cfg(test)]
+mod test;
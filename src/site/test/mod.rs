@@ -0,0 +1,67 @@
+use model::model::*;
+use site::site::*;
+
+#[test]
+fn test_page_link() {
+    let from = "com.shop.model";
+    let to = "com.shop.service";
+
+    assert_eq!(page_link(&SiteProfile::Plain, from, to, "Api", "md"), "../service/Api.md");
+    assert_eq!(page_link(&SiteProfile::Jekyll, from, to, "Api", "md"), "../service/Api.html");
+    assert_eq!(page_link(&SiteProfile::Mkdocs, from, to, "Api", "md"), "../../service/Api/");
+    assert_eq!(page_link(&SiteProfile::Docusaurus, from, to, "Api", "md"), "../service/Api");
+    assert_eq!(
+        page_link(&SiteProfile::Hugo, from, to, "Api", "md"),
+        "{{< relref \"../service/Api.md\" >}}"
+    );
+}
+
+#[test]
+fn test_front_matter() {
+    let mut decl = TypeDecl::new(TypeKind::Interface);
+    decl.ch_name("Api".to_string());
+    decl.ch_package_name("com.shop".to_string());
+    decl.ch_version("1.2".to_string());
+
+    assert_eq!(
+        front_matter(&SiteProfile::Hugo, &decl, 3),
+        "---\ntitle: \"Api\"\npackage: \"com.shop\"\nkind: interface\nweight: 3\ntags:\n  - \"since-1.2\"\n---\n\n"
+    );
+}
+
+#[test]
+fn test_mkdocs_nav() {
+    let mut app = ApplicationDoc::new();
+    app.add_package_class("com.shop".to_string(), String::new(), "Api".to_string());
+
+    assert_eq!(
+        gen_mkdocs_nav(&app),
+        "nav:\n  - Application Contents: Contents.md\n  - \"com.shop\":\n    - \"Api\": com/shop/Api.md\n  \
+         - \"API by Version\": Versions.md\n  - \"Deprecated API\": Deprecated.md\n  - \"Index\": Index.md\n"
+    );
+}
+
+#[test]
+fn test_report_front_matter() {
+    let report = "# Deprecated API\n\nNo deprecated elements.\n";
+
+    assert_eq!(report_front_matter(&SiteProfile::Plain, report), "");
+    assert_eq!(report_front_matter(&SiteProfile::Mkdocs, report), "---\ntitle: \"Deprecated API\"\n---\n\n");
+    assert_eq!(
+        report_front_matter(&SiteProfile::Jekyll, report),
+        "---\ntitle: \"Deprecated API\"\nlayout: default\n---\n\n"
+    );
+    assert!(gen_jekyll_nav(&ApplicationDoc::new()).ends_with("- title: \"Index\"\n  url: /Index.html\n"));
+}
+
+#[test]
+fn test_merge_mkdocs_nav() {
+    let config = "site_name: Shop\nnav:\n  - Home: index.md\n  - Old: old.md\ntheme:\n  name: material\n";
+    let nav = "nav:\n  - Application Contents: Contents.md\n";
+
+    assert_eq!(
+        merge_mkdocs_nav(config, nav),
+        "site_name: Shop\ntheme:\n  name: material\nnav:\n  - Application Contents: Contents.md\n"
+    );
+    assert_eq!(merge_mkdocs_nav("site_name: Shop\n", nav), format!("site_name: Shop\n{}", nav));
+}
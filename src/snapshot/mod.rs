@@ -0,0 +1,174 @@
+pub mod snapshot {
+    //! Module that writes the API of a project as a snapshot file for code review
+    //!
+    //! The snapshot has one line per type, member variable, constructor, method and enum
+    //! constant, like the `api.txt` files of Android. Every line starts with the kind and
+    //! the qualified name of the element, so a line can be read without its neighbours and
+    //! a change to the API shows up as a changed line in the diff of a pull request.
+
+    use std::collections::BTreeSet;
+
+    use model::model::strip_annotations;
+    use model::model::Method;
+    use model::model::Project;
+    use model::model::Visibility;
+    use model::usage::qualified_name;
+
+    /// Normalizes the signature of a method: the annotations and the parameter names are
+    /// removed, so renaming a parameter does not change the snapshot
+    fn method_signature(method: &Method) -> String {
+        let signature = strip_annotations(&method.signature);
+        let start = match signature.find('(') {
+            Some(start) => start,
+            None => return signature,
+        };
+
+        let mut depth = 0;
+        let mut end = signature.len();
+        for (i, ch) in signature[start..].char_indices() {
+            match ch {
+                '(' => depth += 1,
+                ')' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        end = start + i;
+                        break;
+                    }
+                }
+                _ => (),
+            }
+        }
+        let params: Vec<&str> = method.parameters.iter().map(|p| p.var_type.as_str()).collect();
+
+        format!("{}({}){}", &signature[..start], params.join(", "), &signature[(end + 1).min(signature.len())..])
+    }
+
+    /// Normalizes the signature of a member variable: the annotations and the initializer
+    /// are removed
+    fn field_signature(signature: &str) -> String {
+        let signature = strip_annotations(signature);
+
+        match signature.find('=') {
+            Some(i) => signature[..i].trim().to_string(),
+            None => signature,
+        }
+    }
+
+    /// Gets the name a normalized signature declares, the last word before the parameters
+    /// of a method or the last word of a member variable
+    fn declared_name(signature: &str) -> &str {
+        let head = match signature.find('(') {
+            Some(i) => &signature[..i],
+            None => signature,
+        };
+
+        head.trim().rsplit(' ').next().unwrap_or("")
+    }
+
+    /// Generates the lines of the snapshot of the API of a project. The names of the
+    /// members are read from their normalized signatures. The types are sorted by their
+    /// qualified names and are followed by their enum constants in declaration order and
+    /// by their sorted member variables, constructors and methods.
+    ///
+    /// # Arguments
+    ///
+    /// * `proj` - The documented project
+    /// * `level` - Only the types and members with at least this visibility are listed
+    pub fn api_lines(proj: &Project, level: Visibility) -> Vec<String> {
+        let visible = proj.with_visibility(level);
+        let mut types: Vec<_> = visible.types.iter().filter(|t| t.name.trim() != "").collect();
+        types.sort_by_key(|t| qualified_name(&t.package_name, &t.name));
+
+        let mut lines = Vec::new();
+        for decl in types {
+            let type_name = qualified_name(&decl.package_name, &decl.name);
+            lines.push(format!("{} {}: {}", decl.kind.label(), type_name, strip_annotations(&decl.signature)));
+
+            for field in decl.fields.iter() {
+                lines.push(format!("constant {}.{}", type_name, field.name));
+            }
+
+            let mut members: Vec<String> = decl
+                .variables
+                .iter()
+                .map(|v| field_signature(&v.signature))
+                .filter(|s| s != "")
+                .map(|s| format!("field {}.{}: {}", type_name, declared_name(&s), s))
+                .collect();
+            members.sort();
+            lines.extend(members);
+
+            let mut constructors = Vec::new();
+            let mut methods = Vec::new();
+            for method in decl.methods.iter().filter(|m| m.signature.contains('(')) {
+                let signature = method_signature(method);
+                let name = declared_name(&signature).to_string();
+                let line = format!("{}.{}: {}", type_name, name, signature);
+
+                if name == decl.simple_name() {
+                    constructors.push(format!("constructor {}", line));
+                } else {
+                    methods.push(format!("method {}", line));
+                }
+            }
+            constructors.sort();
+            methods.sort();
+            lines.extend(constructors);
+            lines.extend(methods);
+        }
+
+        lines
+    }
+
+    /// Generates the contents of the snapshot file of the API of a project
+    ///
+    /// # Arguments
+    ///
+    /// * `proj` - The documented project
+    /// * `level` - Only the types and members with at least this visibility are listed
+    pub fn gen_api_snapshot(proj: &Project, level: Visibility) -> String {
+        let mut doc = String::new();
+
+        for line in api_lines(proj, level) {
+            doc.push_str(line.as_str());
+            doc.push_str("\n");
+        }
+
+        doc
+    }
+
+    /// Compares a snapshot with the current one and returns the lines missing from the
+    /// current snapshot prefixed with `-` and the new lines prefixed with `+`. The lines are
+    /// sorted by the names of their elements, so the old and new line of a changed element
+    /// are next to each other. An empty result means the snapshots are equal.
+    ///
+    /// # Arguments
+    ///
+    /// * `expected` - The contents of the committed snapshot file
+    /// * `actual` - The snapshot of the current sources
+    pub fn diff_snapshots(expected: &str, actual: &str) -> Vec<String> {
+        let lines = |text: &str| -> BTreeSet<String> {
+            text.lines()
+                .map(|l| l.trim_end().to_string())
+                .filter(|l| l != "")
+                .collect()
+        };
+        let expected = lines(expected);
+        let actual = lines(actual);
+        let element = |line: &str| {
+            let name = line.splitn(2, ' ').last().unwrap_or("");
+            name.split(':').next().unwrap_or("").to_string()
+        };
+        let mut changes: Vec<(String, char, &String)> = expected
+            .difference(&actual)
+            .map(|l| (element(l), '-', l))
+            .chain(actual.difference(&expected).map(|l| (element(l), '+', l)))
+            .collect();
+        changes.sort_by(|a, b| a.0.cmp(&b.0).then(b.1.cmp(&a.1)));
+
+        changes.iter().map(|&(_, sign, line)| format!("{}{}", sign, line)).collect()
+    }
+}
+
+#[cfg(test)]
+mod test;
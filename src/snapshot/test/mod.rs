@@ -0,0 +1,51 @@
+use model::model::*;
+use parse::parse::parse_contents;
+use snapshot::snapshot::*;
+
+fn project(sources: Vec<&str>) -> Project {
+    let mut proj = Project::new();
+    for source in sources {
+        proj.add_type(parse_contents(source).unwrap());
+    }
+
+    proj
+}
+
+#[test]
+fn test_api_snapshot() {
+    let proj = project(vec![
+        "package a;\npublic enum Color {\n    RED, GREEN;\n}\n",
+        "package a;\n@Entity\npublic class Order {\n    public static final int LIMIT = 10;\n    private long id;\n\n    public Order(long id) {\n    }\n\n    @Override\n    public String toString() {\n        return \"\";\n    }\n\n    public <T> List<T> items(Class<T> type,\n            int max) throws java.io.IOException {\n        return null;\n    }\n}\n",
+    ]);
+
+    assert_eq!(
+        api_lines(&proj, Visibility::Public),
+        vec![
+            "enum a.Color: public enum Color",
+            "constant a.Color.RED",
+            "constant a.Color.GREEN",
+            "class a.Order: public class Order",
+            "field a.Order.LIMIT: public static final int LIMIT",
+            "constructor a.Order.Order: public Order(long)",
+            "method a.Order.items: public <T> List<T> items(Class<T>, int) throws java.io.IOException",
+            "method a.Order.toString: public String toString()",
+        ]
+    );
+}
+
+#[test]
+fn test_diff_snapshots() {
+    let expected = "class a.Order: public class Order\nmethod a.Order.pay: public void pay()\nmethod a.Order.ship: public void ship()\n";
+    let actual = "class a.Order: public class Order\nmethod a.Order.pay: public boolean pay()\nmethod a.Order.total: public long total()\n";
+
+    assert_eq!(
+        diff_snapshots(expected, actual),
+        vec![
+            "-method a.Order.pay: public void pay()",
+            "+method a.Order.pay: public boolean pay()",
+            "-method a.Order.ship: public void ship()",
+            "+method a.Order.total: public long total()",
+        ]
+    );
+    assert!(diff_snapshots(expected, expected).is_empty());
+}
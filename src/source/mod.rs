@@ -0,0 +1,323 @@
+pub mod source {
+    //! Module that renders the parsed java files as syntax highlighted html pages
+    //!
+    //! Every line of a source page has an anchor, `#L12`, the same fragment GitHub uses,
+    //! so the `[[src]]` links of member variables and methods work with both. The pages
+    //! only replace the links of files which are not in a repository with a web remote.
+    //!
+    //! The pages are written to `{dest}/src/`, and to the source directory of the
+    //! markdown book, which mdbook copies next to the book's pages. Hugo and Docusaurus
+    //! would render html files in their content directory as pages, so with these site
+    //! profiles the pages go into the `static` directory of the site at the path the
+    //! destination directory is published at. Hugo and MkDocs publish every page as a
+    //! directory, so their links go up one more level.
+
+    use std::fs;
+    use std::fs::File;
+    use std::io;
+    use std::io::prelude::*;
+    use std::path::Path;
+    use std::path::PathBuf;
+
+    use document::document::package_path;
+    use document::document::resolve_context;
+    use html::html::escape;
+    use model::model::Backend;
+    use model::model::Options;
+    use model::model::Project;
+    use model::model::SiteProfile;
+    use model::model::TypeDecl;
+
+    const JAVA_KEYWORDS: [&str; 52] = [
+        "abstract", "assert", "boolean", "break", "byte", "case", "catch", "char", "class",
+        "const", "continue", "default", "do", "double", "else", "enum", "extends", "false",
+        "final", "finally", "float", "for", "goto", "if", "implements", "import",
+        "instanceof", "int", "interface", "long", "native", "new", "null", "package",
+        "private", "protected", "public", "return", "short", "static", "strictfp", "super",
+        "switch", "synchronized", "this", "throw", "throws", "transient", "true", "try",
+        "void", "volatile",
+    ];
+
+    const STYLESHEET: &str = "body { margin: 0; font-family: sans-serif; }
+h1 { font-size: 1.1em; padding: 0.5em 1em; margin: 0; background: #f5f5f5; }
+pre { margin: 0; font-size: 0.9em; line-height: 1.4; }
+.line { display: block; }
+.line:target { background: #fff8c5; }
+.line a { display: inline-block; width: 4em; padding-right: 1em; text-align: right; color: #999; text-decoration: none; }
+.kw { color: #a626a4; font-weight: bold; }
+.typ { color: #c18401; }
+.str { color: #50a14f; }
+.num { color: #986801; }
+.com { color: #8e908c; font-style: italic; }
+.ann { color: #4078f2; }
+";
+
+    /// A java file and the path of its source page in the destination directory
+    pub struct SourceFile {
+        pub path: String,
+        pub page: String,
+    }
+
+    fn is_ident(ch: char) -> bool {
+        ch.is_alphanumeric() || ch == '_' || ch == '$'
+    }
+
+    /// Splits java source code into highlighted segments. Each segment has the css
+    /// class it is shown with, or an empty class for plain text.
+    fn segments(source: &str) -> Vec<(&'static str, String)> {
+        let chars: Vec<char> = source.chars().collect();
+        let mut segs: Vec<(&'static str, String)> = Vec::new();
+        let mut i = 0;
+
+        while i < chars.len() {
+            let start = i;
+            let class = if chars[i] == '/' && i + 1 < chars.len() && chars[i + 1] == '/' {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+                "com"
+            } else if chars[i] == '/' && i + 1 < chars.len() && chars[i + 1] == '*' {
+                i += 2;
+                while i < chars.len() && !(chars[i] == '/' && chars[i - 1] == '*' && i - 1 > start + 1) {
+                    i += 1;
+                }
+                i = (i + 1).min(chars.len());
+                "com"
+            } else if chars[i] == '"' || chars[i] == '\'' {
+                let quote = chars[i];
+                i += 1;
+                while i < chars.len() && chars[i] != quote && chars[i] != '\n' {
+                    if chars[i] == '\\' {
+                        i += 1;
+                    }
+                    i += 1;
+                }
+                i = (i + 1).min(chars.len());
+                "str"
+            } else if chars[i].is_ascii_digit() {
+                while i < chars.len() && (is_ident(chars[i]) || chars[i] == '.') {
+                    i += 1;
+                }
+                "num"
+            } else if chars[i] == '@' && i + 1 < chars.len() && is_ident(chars[i + 1]) {
+                i += 1;
+                while i < chars.len() && (is_ident(chars[i]) || chars[i] == '.') {
+                    i += 1;
+                }
+                "ann"
+            } else if is_ident(chars[i]) {
+                while i < chars.len() && is_ident(chars[i]) {
+                    i += 1;
+                }
+                let word: String = chars[start..i].iter().collect();
+                if JAVA_KEYWORDS.contains(&word.as_str()) {
+                    "kw"
+                } else if chars[start].is_uppercase() {
+                    "typ"
+                } else {
+                    ""
+                }
+            } else {
+                i += 1;
+                ""
+            };
+
+            let text: String = chars[start..i].iter().collect();
+            if class == "" && segs.last().map_or(false, |last| last.0 == "") {
+                segs.last_mut().unwrap().1.push_str(text.as_str());
+            } else {
+                segs.push((class, text));
+            }
+        }
+
+        segs
+    }
+
+    /// Highlights java source code and returns the html of every line. Comments and
+    /// strings spanning several lines are closed and reopened on every line.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The java source code
+    pub fn highlight_java(source: &str) -> Vec<String> {
+        let mut lines = vec![String::new()];
+
+        for (class, text) in segments(source) {
+            for (i, part) in text.split("\n").enumerate() {
+                if i > 0 {
+                    lines.push(String::new());
+                }
+                if part == "" {
+                    continue;
+                }
+
+                let html = escape(part.trim_end_matches('\r'));
+                let line = lines.last_mut().unwrap();
+                if class == "" {
+                    line.push_str(html.as_str());
+                } else {
+                    line.push_str(format!("<span class=\"{}\">{}</span>", class, html).as_str());
+                }
+            }
+        }
+
+        if source.ends_with("\n") {
+            lines.pop();
+        }
+
+        lines
+    }
+
+    /// Generates the html page of a java file with an anchor for every line
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - The title of the page, the path of the java file
+    /// * `source` - The java source code
+    pub fn gen_source_page(title: &str, source: &str) -> String {
+        let mut doc = String::from("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+
+        doc.push_str(format!("<title>{}</title>\n", escape(title)).as_str());
+        doc.push_str(format!("<style>\n{}</style>\n", STYLESHEET).as_str());
+        doc.push_str("</head>\n<body>\n");
+        doc.push_str(format!("<h1>{}</h1>\n<pre>", escape(title)).as_str());
+
+        for (i, line) in highlight_java(source).iter().enumerate() {
+            doc.push_str(
+                format!(
+                    "<span class=\"line\" id=\"L{}\"><a href=\"#L{}\">{}</a>{}</span>",
+                    i + 1,
+                    i + 1,
+                    i + 1,
+                    line
+                ).as_str(),
+            );
+        }
+        doc.push_str("</pre>\n</body>\n</html>\n");
+
+        doc
+    }
+
+    /// Gets the path of the source page of a type, relative to the destination directory.
+    /// A nested type shares the page of the file it is declared in.
+    pub fn source_page_path(decl: &TypeDecl) -> String {
+        let file_name = decl.name.trim().split('.').next().unwrap_or("");
+
+        format!("src/{}{}.java.html", package_path("", &decl.package_name), file_name)
+    }
+
+    /// Gets the site profile the source links are created for. The html backend and the
+    /// single-file reference are plain pages.
+    fn link_profile(options: &Options) -> SiteProfile {
+        if options.backend == Backend::Markdown && !options.single_file {
+            options.site.clone()
+        } else {
+            SiteProfile::Plain
+        }
+    }
+
+    /// Gets the directories the source pages are written to. The pages of a site profile
+    /// which renders html files in its content directory go into the `static` directory
+    /// of the site, or the destination directory if it is not inside the site's content
+    /// directory.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - The options containing the destination directory, site profile and book
+    pub fn source_dirs(options: &Options) -> Vec<PathBuf> {
+        let dest = PathBuf::from(options.dest.as_str());
+        let content = match link_profile(options) {
+            SiteProfile::Hugo => Some("content"),
+            SiteProfile::Docusaurus => Some("docs"),
+            _ => None,
+        };
+
+        let root = content.and_then(|content| {
+            let dir = dest.ancestors().find(|dir| dir.file_name() == Some(content.as_ref()))?;
+            let site = dir.parent()?;
+            // Hugo publishes the content directory at the root, Docusaurus keeps `docs`
+            let published = if content == "content" { dir } else { site };
+            Some(site.join("static").join(dest.strip_prefix(published).ok()?))
+        });
+        let mut dirs = vec![root.unwrap_or(dest)];
+
+        if options.book && options.backend == Backend::Markdown && !options.single_file {
+            dirs.push(Path::new(options.book_root().as_str()).join("src"));
+        }
+
+        dirs
+    }
+
+    /// Points the `file_path` of every type at its source page, or at the file in the
+    /// repository's web remote when there is one. Returns the java files which need a
+    /// source page. Types whose file can not be read, e.g. because the project was
+    /// loaded from JSON with remote links, are left unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `proj` - The project to relink
+    /// * `options` - The options containing the backend and site profile
+    pub fn link_source_pages(proj: &mut Project, options: &Options) -> Vec<SourceFile> {
+        let mut sources: Vec<SourceFile> = Vec::new();
+        let profile = link_profile(options);
+
+        for decl in proj.types.iter_mut() {
+            let path = PathBuf::from(decl.file_path.as_str());
+            if decl.file_path == "" || !path.is_file() {
+                continue;
+            }
+
+            let remote = resolve_context(&path);
+            if remote.starts_with("http") {
+                decl.ch_file_path(remote);
+                continue;
+            }
+
+            let page = source_page_path(decl);
+            let root = match profile {
+                _ if options.single_file => String::new(),
+                SiteProfile::Hugo | SiteProfile::Mkdocs => format!("../{}", package_path(&decl.package_name, "")),
+                _ => package_path(&decl.package_name, ""),
+            };
+
+            if !sources.iter().any(|s| s.page == page) {
+                sources.push(SourceFile {
+                    path: decl.file_path.clone(),
+                    page: page.clone(),
+                });
+            }
+            decl.ch_file_path(format!("{}{}", root, page));
+        }
+
+        sources
+    }
+
+    /// Writes the source pages of the java files into every source directory
+    ///
+    /// # Arguments
+    ///
+    /// * `sources` - The java files and the paths of their pages
+    /// * `dirs` - The directories the pages are written to, see `source_dirs`
+    pub fn write_source_pages(sources: &Vec<SourceFile>, dirs: &Vec<PathBuf>) -> io::Result<()> {
+        for source in sources {
+            let mut contents = String::new();
+            File::open(source.path.as_str())?.read_to_string(&mut contents)?;
+            let html = gen_source_page(source.path.as_str(), contents.as_str());
+
+            for dir in dirs {
+                let page = dir.join(source.page.as_str());
+                if let Some(page_dir) = page.parent() {
+                    fs::create_dir_all(page_dir)?;
+                }
+
+                let mut file = File::create(page)?;
+                file.write_all(html.as_bytes())?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,87 @@
+use std::env;
+use std::fs;
+use std::fs::File;
+use std::path::PathBuf;
+
+use model::model::*;
+use source::source::*;
+
+#[test]
+fn test_highlight_java() {
+    let lines = highlight_java("/* a\n * b */\n@Override\npublic String name = \"<x>\"; // 1\n");
+
+    assert_eq!(lines.len(), 4);
+    assert_eq!(lines[0], "<span class=\"com\">/* a</span>");
+    assert_eq!(lines[1], "<span class=\"com\"> * b */</span>");
+    assert_eq!(lines[2], "<span class=\"ann\">@Override</span>");
+    assert_eq!(
+        lines[3],
+        "<span class=\"kw\">public</span> <span class=\"typ\">String</span> name = \
+         <span class=\"str\">&quot;&lt;x&gt;&quot;</span>; <span class=\"com\">// 1</span>"
+    );
+}
+
+#[test]
+fn test_source_page() {
+    let page = gen_source_page("Order.java", "class Order {\n}\n");
+
+    assert!(page.contains("<span class=\"line\" id=\"L1\"><a href=\"#L1\">1</a><span class=\"kw\">class</span> "));
+    assert!(page.contains("<span class=\"line\" id=\"L2\"><a href=\"#L2\">2</a>}</span>"));
+
+    let mut decl = TypeDecl::new(TypeKind::Class);
+    decl.ch_name("Order".to_string());
+    decl.ch_package_name("com.shop".to_string());
+    assert_eq!(source_page_path(&decl), "src/com/shop/Order.java.html");
+}
+
+#[test]
+fn test_source_dirs() {
+    let mut options = Options::new("");
+    options.dest = String::from("site/content/api");
+    assert_eq!(source_dirs(&options), vec![PathBuf::from("site/content/api")]);
+
+    options.site = SiteProfile::Hugo;
+    assert_eq!(source_dirs(&options), vec![PathBuf::from("site/static/api")]);
+
+    options.dest = String::from("site/docs/api");
+    options.site = SiteProfile::Docusaurus;
+    assert_eq!(source_dirs(&options), vec![PathBuf::from("site/static/docs/api")]);
+
+    options.dest = String::from("out");
+    options.site = SiteProfile::Plain;
+    options.book = true;
+    assert_eq!(source_dirs(&options), vec![PathBuf::from("out"), PathBuf::from("out/markdown-book/src")]);
+}
+
+#[test]
+fn test_source_links() {
+    let dir = env::temp_dir().join("lojidoc-source-links");
+    fs::create_dir_all(&dir).unwrap();
+    let file = dir.join("Order.java");
+    File::create(&file).unwrap();
+
+    let mut order = TypeDecl::new(TypeKind::Class);
+    order.ch_name("Order".to_string());
+    order.ch_package_name("com.shop".to_string());
+    order.ch_file_path(file.to_str().unwrap().to_string());
+    let mut builder = order.clone();
+    builder.ch_name("Order.Builder".to_string());
+    let mut proj = Project::new();
+    proj.add_type(order);
+    proj.add_type(builder);
+
+    let mut options = Options::new("");
+    let sources = link_source_pages(&mut proj.clone(), &options);
+    assert_eq!(sources.len(), 1);
+
+    let mut plain = proj.clone();
+    link_source_pages(&mut plain, &options);
+    assert_eq!(plain.types[0].file_path, "../../src/com/shop/Order.java.html");
+    assert_eq!(plain.types[1].file_path, "../../src/com/shop/Order.java.html");
+
+    options.site = SiteProfile::Mkdocs;
+    link_source_pages(&mut proj, &options);
+    assert_eq!(proj.types[0].file_path, "../../../src/com/shop/Order.java.html");
+
+    fs::remove_dir_all(dir).unwrap();
+}
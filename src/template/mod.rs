@@ -0,0 +1,446 @@
+pub mod template {
+    //! Module that renders the markdown and AsciiDoc pages of a project from handlebars templates
+    //!
+    //! The default layout of each format is shipped as a set of templates which can be
+    //! replaced one by one by files with the same name in a template directory:
+    //!
+    //! * `type.hbs` - The page of a class, interface or enum
+    //! * `field.hbs` - The block of a member variable, included with `{{> field}}`
+    //! * `method.hbs` - The block of a method, included with `{{> method}}`
+    //! * `deprecation.hbs` - The deprecation badge of a type or member, included with
+    //!   `{{> deprecation}}` inside `{{#with deprecation}}`
+    //! * `contents.hbs` - The page listing the packages of the project
+    //! * `package.hbs` - The overview page of a package
+    //! * `nav.hbs` - The Antora navigation file, only used by the AsciiDoc format
+    //!
+    //! The type page is rendered with every field of the `TypeDecl`, plus `title`
+    //! (e.g. "Class"), `usages` and `usage_count`. Each usage has a `link` relative to
+    //! the page, which goes to the anchor of the using member if there is one, and an
+    //! `xref` to the using type relative to the documentation root. Member variables and
+    //! methods also get a `src` link to their line in the source file, and they and the
+    //! enum constants in `fields` get the `anchor` id the html pages use. Each package of
+    //! the contents page has a `link` and `xref` to its overview page and the first
+    //! sentence of its description as `summary`. The overview page of a package is
+    //! rendered with its `name`, `description`, `diagram`, its Mermaid class diagram
+    //! which is empty unless diagrams were requested, and `groups`, one group for every
+    //! kind of type with a `title` (e.g. "Classes"), the `kind` (e.g. "Class") and the
+    //! `types`. Each type has a `link`, an `xref`, the `summary` of its description,
+    //! whether it is `deprecated` and the first sentence of its `deprecation`.
+
+    use std::fs::File;
+    use std::io::prelude::*;
+    use std::path::Path;
+
+    use handlebars::no_escape;
+    use handlebars::Handlebars;
+    use serde_json;
+    use serde_json::Value;
+
+    use error::error::LojidocError;
+    use model::model::constant_anchor;
+    use model::model::field_anchor;
+    use model::model::first_sentence;
+    use model::model::method_anchor;
+    use model::model::ApplicationDoc;
+    use model::model::Backend;
+    use model::model::Package;
+    use model::model::SiteProfile;
+    use model::model::TypeDecl;
+    use model::model::TypeKind;
+    use model::model::Usage;
+    use model::usage::qualified_name;
+    use site::site::page_link;
+
+    /// The default templates of an output format
+    pub struct TemplateSet {
+        pub extension: &'static str,
+        pub page: &'static str,
+        pub field: &'static str,
+        pub method: &'static str,
+        pub deprecation: &'static str,
+        pub contents: &'static str,
+        pub package: &'static str,
+        pub nav: &'static str,
+    }
+
+    pub const MARKDOWN_TEMPLATES: TemplateSet = TemplateSet {
+        extension: "md",
+        page: include_str!("markdown/type.hbs"),
+        field: include_str!("markdown/field.hbs"),
+        method: include_str!("markdown/method.hbs"),
+        deprecation: include_str!("markdown/deprecation.hbs"),
+        contents: include_str!("markdown/contents.hbs"),
+        package: include_str!("markdown/package.hbs"),
+        nav: "",
+    };
+
+    pub const ASCIIDOC_TEMPLATES: TemplateSet = TemplateSet {
+        extension: "adoc",
+        page: include_str!("asciidoc/type.hbs"),
+        field: include_str!("asciidoc/field.hbs"),
+        method: include_str!("asciidoc/method.hbs"),
+        deprecation: include_str!("asciidoc/deprecation.hbs"),
+        contents: include_str!("asciidoc/contents.hbs"),
+        package: include_str!("asciidoc/package.hbs"),
+        nav: include_str!("asciidoc/nav.hbs"),
+    };
+
+    handlebars_helper!(trim: |text: str| text.trim().to_string());
+
+    #[derive(Serialize)]
+    struct UsageContext {
+        kind: String,
+        label: String,
+        type_name: String,
+        package_name: String,
+        member: String,
+        link: String,
+        xref: String,
+    }
+
+    #[derive(Serialize)]
+    struct TypeLink {
+        name: String,
+        link: String,
+        xref: String,
+    }
+
+    #[derive(Serialize)]
+    struct PackageContext {
+        name: String,
+        link: String,
+        xref: String,
+        summary: String,
+        types: Vec<TypeLink>,
+    }
+
+    #[derive(Serialize)]
+    struct SummaryRow {
+        name: String,
+        link: String,
+        xref: String,
+        summary: String,
+        deprecated: bool,
+        deprecation: String,
+    }
+
+    #[derive(Serialize)]
+    struct KindGroup {
+        title: String,
+        kind: String,
+        types: Vec<SummaryRow>,
+    }
+
+    #[derive(Serialize)]
+    struct PackagePageContext {
+        name: String,
+        description: String,
+        diagram: String,
+        groups: Vec<KindGroup>,
+    }
+
+    /// The order of the groups of types on the overview page of a package
+    const KIND_ORDER: [TypeKind; 5] = [
+        TypeKind::Interface,
+        TypeKind::Class,
+        TypeKind::Enumeration,
+        TypeKind::Record,
+        TypeKind::Annotation,
+    ];
+
+    /// The file name of the overview page of a package, without its extension
+    pub const PACKAGE_PAGE: &'static str = "package-summary";
+
+    #[derive(Serialize)]
+    struct ContentsContext {
+        packages: Vec<PackageContext>,
+    }
+
+    /// The set of templates used to render the documentation pages
+    pub struct Templates {
+        registry: Handlebars,
+        pub extension: &'static str,
+        pub site: SiteProfile,
+        pub single_file: bool,
+    }
+
+    impl Templates {
+        /// Creates the default template set of an output format, replacing the templates
+        /// found in `dir`. An empty `dir` uses only the default templates.
+        ///
+        /// # Arguments
+        ///
+        /// * `backend` - The output format, `Backend::Asciidoc` or `Backend::Markdown`
+        /// * `dir` - The directory containing the user defined templates
+        pub fn new(backend: &Backend, dir: &str) -> Result<Templates, LojidocError> {
+            let defaults = match backend {
+                Backend::Asciidoc => ASCIIDOC_TEMPLATES,
+                _ => MARKDOWN_TEMPLATES,
+            };
+            let mut registry = Handlebars::new();
+            registry.register_escape_fn(no_escape);
+            registry.register_helper("trim", Box::new(trim));
+
+            registry.register_template_string("type", defaults.page)?;
+            registry.register_template_string("contents", defaults.contents)?;
+            registry.register_template_string("package", defaults.package)?;
+            registry.register_template_string("nav", defaults.nav)?;
+            registry.register_partial("field", defaults.field)?;
+            registry.register_partial("method", defaults.method)?;
+            registry.register_partial("deprecation", defaults.deprecation)?;
+
+            if dir != "" {
+                for name in vec!["type", "contents", "package", "nav"] {
+                    let path = Path::new(dir).join(format!("{}.hbs", name));
+
+                    if path.is_file() {
+                        registry.register_template_file(name, &path)?;
+                    }
+                }
+                for name in vec!["field", "method", "deprecation"] {
+                    let path = Path::new(dir).join(format!("{}.hbs", name));
+
+                    if path.is_file() {
+                        registry.register_partial(name, read_template(&path)?)?;
+                    }
+                }
+            }
+
+            Ok(Templates {
+                registry: registry,
+                extension: defaults.extension,
+                site: SiteProfile::Plain,
+                single_file: false,
+            })
+        }
+
+        /// Changes the site generator the links between the pages are created for
+        pub fn ch_site(&mut self, value: SiteProfile) {
+            self.site = value;
+        }
+
+        /// Renders the page of a class, interface or enum
+        ///
+        /// # Arguments
+        ///
+        /// * `decl` - The type declaration containing the javadoc data
+        /// * `usages` - The places in the project where the type is used
+        pub fn render_type(&self, decl: &TypeDecl, usages: Vec<Usage>) -> Result<String, LojidocError> {
+            let mut context = serde_json::to_value(decl)?;
+            let usage_count = usages.len();
+            let usages: Vec<UsageContext> = usages
+                .into_iter()
+                .map(|usage| UsageContext {
+                    kind: format!("{:?}", usage.kind),
+                    label: usage.kind.label().to_string(),
+                    link: self.member_link(&decl.package_name, &usage.package_name, &usage.type_name, &usage.anchor),
+                    xref: self.link("", &usage.package_name, &usage.type_name),
+                    type_name: usage.type_name,
+                    package_name: usage.package_name,
+                    member: usage.member,
+                }).collect();
+
+            if let Value::Object(ref mut map) = context {
+                map.insert("title".to_string(), Value::from(decl.kind.title()));
+                map.insert("usages".to_string(), serde_json::to_value(usages)?);
+                map.insert("usage_count".to_string(), Value::from(usage_count));
+
+                let type_link = |name: &String| TypeLink {
+                    name: name.clone(),
+                    link: self.link(&decl.package_name, &decl.package_name, name),
+                    xref: self.link("", &decl.package_name, name),
+                };
+                let nested_types: Vec<TypeLink> = decl.nested.iter().map(&type_link).collect();
+                map.insert("nested_types".to_string(), serde_json::to_value(nested_types)?);
+                if decl.enclosing != "" {
+                    map.insert("enclosing_type".to_string(), serde_json::to_value(type_link(&decl.enclosing))?);
+                }
+
+                for key in vec!["variables", "methods"] {
+                    if let Some(Value::Array(ref mut items)) = map.get_mut(key) {
+                        for item in items.iter_mut() {
+                            add_src_link(item, &decl.file_path);
+                        }
+                    }
+                }
+                if let Some(Value::Array(ref mut items)) = map.get_mut("variables") {
+                    for (item, member) in items.iter_mut().zip(decl.variables.iter()) {
+                        add_anchor(item, self.anchor(decl, field_anchor(member)));
+                    }
+                }
+                if let Some(Value::Array(ref mut items)) = map.get_mut("methods") {
+                    for (i, item) in items.iter_mut().enumerate() {
+                        add_anchor(item, self.anchor(decl, method_anchor(&decl.methods, i)));
+                    }
+                }
+                if let Some(Value::Array(ref mut items)) = map.get_mut("fields") {
+                    for (item, field) in items.iter_mut().zip(decl.fields.iter()) {
+                        add_anchor(item, self.anchor(decl, constant_anchor(field)));
+                    }
+                }
+            }
+
+            Ok(self.registry.render("type", &context)?)
+        }
+
+        /// Renders the page listing the packages of the project and their types
+        ///
+        /// # Arguments
+        ///
+        /// * `app` - The packages and types of the documented project
+        pub fn render_contents(&self, app: &ApplicationDoc) -> Result<String, LojidocError> {
+            Ok(self.registry.render("contents", &self.contents_context(app))?)
+        }
+
+        /// Renders the overview page of a package, which lists its types grouped by
+        /// their kind
+        ///
+        /// # Arguments
+        ///
+        /// * `package` - The package and the overviews of its types
+        pub fn render_package(&self, package: &Package) -> Result<String, LojidocError> {
+            let mut summaries = package.summaries.clone();
+            summaries.sort_by(|a, b| a.name.cmp(&b.name));
+
+            let groups = KIND_ORDER
+                .iter()
+                .map(|kind| KindGroup {
+                    title: kind.plural().to_string(),
+                    kind: kind.title().to_string(),
+                    types: summaries
+                        .iter()
+                        .filter(|t| t.kind == *kind)
+                        .map(|t| SummaryRow {
+                            name: t.name.clone(),
+                            link: self.link(&package.name, &package.name, &t.name),
+                            xref: self.link("", &package.name, &t.name),
+                            summary: table_cell(&t.summary),
+                            deprecated: t.deprecated,
+                            deprecation: table_cell(&t.deprecation),
+                        }).collect(),
+                }).filter(|group| group.types.len() > 0)
+                .collect();
+            let context = PackagePageContext {
+                name: package.name.trim().to_string(),
+                description: package.description.clone(),
+                diagram: package.diagram.clone(),
+                groups: groups,
+            };
+
+            Ok(self.registry.render("package", &context)?)
+        }
+
+        /// Renders the navigation file listing the packages of the project and their types
+        ///
+        /// # Arguments
+        ///
+        /// * `app` - The packages and types of the documented project
+        pub fn render_nav(&self, app: &ApplicationDoc) -> Result<String, LojidocError> {
+            Ok(self.registry.render("nav", &self.contents_context(app))?)
+        }
+
+        /// Changes whether the pages are sections of a single document, which link to
+        /// each other through anchors
+        pub fn ch_single_file(&mut self, value: bool) {
+            self.single_file = value;
+        }
+
+        /// Creates the anchor id of a member, which is qualified by the name of its type
+        /// when the pages are sections of a single document
+        fn anchor(&self, decl: &TypeDecl, anchor: String) -> String {
+            if self.single_file {
+                format!("{}.{}", qualified_name(&decl.package_name, &decl.name), anchor)
+            } else {
+                anchor
+            }
+        }
+
+        /// Creates the relative link from a page in one package to the page of a type
+        fn link(&self, from_package: &str, to_package: &str, name: &str) -> String {
+            if self.single_file {
+                return format!("#{}", qualified_name(to_package, name));
+            }
+
+            page_link(&self.site, from_package, to_package, name, self.extension)
+        }
+
+        /// Creates the relative link from a page in one package to a member on the page of
+        /// a type, or to the page itself when the anchor is empty
+        fn member_link(&self, from_package: &str, to_package: &str, name: &str, anchor: &str) -> String {
+            if anchor == "" {
+                self.link(from_package, to_package, name)
+            } else if self.single_file {
+                format!("#{}.{}", qualified_name(to_package, name), anchor)
+            } else {
+                format!("{}#{}", self.link(from_package, to_package, name), anchor)
+            }
+        }
+
+        fn contents_context(&self, app: &ApplicationDoc) -> ContentsContext {
+            let prefix = if self.site == SiteProfile::Plain { "./" } else { "" };
+
+            ContentsContext {
+                packages: app
+                    .packages
+                    .iter()
+                    .map(|p| PackageContext {
+                        name: p.name.clone(),
+                        link: format!("{}{}", prefix, self.link("", &p.name, PACKAGE_PAGE)),
+                        xref: self.link("", &p.name, PACKAGE_PAGE),
+                        summary: first_sentence(&p.description),
+                        types: p
+                            .members
+                            .iter()
+                            .map(|name| TypeLink {
+                                name: name.clone(),
+                                link: format!("{}{}", prefix, self.link("", &p.name, name)),
+                                xref: self.link("", &p.name, name),
+                            }).collect(),
+                    }).collect(),
+            }
+        }
+    }
+
+    /// Adds the link to the line of a member variable or method in the source file, or
+    /// to the file when the line is unknown
+    fn add_src_link(item: &mut Value, file_path: &str) {
+        if let Value::Object(ref mut map) = *item {
+            let src = match map.get("line_num").and_then(|l| l.as_str()) {
+                Some("") if file_path != "" => file_path.to_string(),
+                Some(line) if file_path != "" => format!("{}#L{}", file_path, line),
+                _ => String::new(),
+            };
+
+            map.insert("src".to_string(), Value::from(src));
+        }
+    }
+
+    /// Adds the anchor id of a member to its template data
+    fn add_anchor(item: &mut Value, anchor: String) {
+        if let Value::Object(ref mut map) = *item {
+            map.insert("anchor".to_string(), Value::from(anchor));
+        }
+    }
+
+    /// Keeps a text on one line of a table and escapes its column separators
+    pub fn table_cell(text: &str) -> String {
+        text.split_whitespace().collect::<Vec<&str>>().join(" ").replace("|", "\\|")
+    }
+
+    /// Escapes the name of a symbol for a table cell, so the angle brackets of its type
+    /// parameters are not read as html tags
+    pub fn table_name(text: &str) -> String {
+        table_cell(text).replace("<", "&lt;").replace(">", "&gt;")
+    }
+
+    /// Reads a template file into a string
+    fn read_template(path: &Path) -> Result<String, LojidocError> {
+        let mut contents = String::new();
+        File::open(path)?.read_to_string(&mut contents)?;
+
+        Ok(contents)
+    }
+}
+
+#[cfg(test)]
+mod test;
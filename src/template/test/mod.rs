@@ -0,0 +1,154 @@
+use std::fs;
+use std::fs::File;
+use std::io::prelude::*;
+
+use model::model::*;
+use template::template::*;
+
+fn sample_type() -> TypeDecl {
+    let mut decl = TypeDecl::new(TypeKind::Class);
+    decl.ch_name("Order".to_string());
+    decl.ch_package_name("com.shop".to_string());
+    decl.ch_access("public".to_string());
+    decl.ch_file_path("src/Order.java".to_string());
+
+    let mut method = Method::new();
+    method.ch_method_name("total".to_string());
+    method.ch_line_num("14".to_string());
+    method.ch_return_type("int".to_string());
+    decl.add_method(method);
+
+    decl
+}
+
+#[test]
+fn test_default_templates() {
+    let templates = Templates::new(&Backend::Markdown, "").unwrap();
+    let doc = templates.render_type(&sample_type(), Vec::new()).unwrap();
+
+    assert!(doc.starts_with("# Class Order [[src]](src/Order.java)  \n"));
+    assert!(doc.contains("Access: public  \npackage: com.shop  \n\n"));
+    assert!(doc.contains("## No member variables in this class\n\n"));
+    assert!(doc.contains("### total [[src]](src/Order.java#L14)\n\n"));
+    assert!(doc.contains("+ return: int  \n\nThis method has no parameters.  \n\n\n"));
+
+    let mut decl = sample_type();
+    decl.methods[0].ch_return_desc("The total in cents".to_string());
+    let doc = templates.render_type(&decl, Vec::new()).unwrap();
+    assert!(doc.contains("+ return: The total in cents  \n"));
+}
+
+#[test]
+fn test_user_templates() {
+    let dir = "./target/test-templates";
+    fs::create_dir_all(dir).unwrap();
+    let mut file = File::create(format!("{}/method.hbs", dir)).unwrap();
+    file.write_all(b"* {{name}} returns {{return_type}} ({{src}})\n").unwrap();
+
+    let templates = Templates::new(&Backend::Markdown, dir).unwrap();
+    let doc = templates.render_type(&sample_type(), Vec::new()).unwrap();
+
+    assert!(doc.contains("## Methods\n\n* total returns int (src/Order.java#L14)\n"));
+    assert!(doc.starts_with("# Class Order"));
+}
+
+#[test]
+fn test_asciidoc_templates() {
+    let templates = Templates::new(&Backend::Asciidoc, "").unwrap();
+    let usage = Usage {
+        kind: UsageKind::Return,
+        type_name: "OrderService".to_string(),
+        package_name: "com.shop.service".to_string(),
+        member: "find".to_string(),
+        anchor: "method.find".to_string(),
+    };
+    let doc = templates.render_type(&sample_type(), vec![usage]).unwrap();
+
+    assert!(doc.starts_with("= Class Order\n\nlink:src/Order.java[src]\n"));
+    assert!(doc.contains("* xref:com/shop/service/OrderService.adoc[OrderService].find (return type)\n"));
+    assert!(doc.contains("=== total\n\nlink:src/Order.java#L14[src]\n"));
+
+    let mut app = ApplicationDoc::new();
+    app.add_package_class("com.shop".to_string(), String::new(), "Order".to_string());
+    let nav = templates.render_nav(&app).unwrap();
+
+    assert_eq!(
+        nav,
+        "* xref:index.adoc[Application Contents]\n\
+         ** xref:com/shop/package-summary.adoc[com.shop]\n\
+         *** xref:com/shop/Order.adoc[Order]\n"
+    );
+}
+
+#[test]
+fn test_package_page() {
+    let templates = Templates::new(&Backend::Markdown, "").unwrap();
+    let mut order = sample_type();
+    order.ch_description("An order of the shop. It holds the items.".to_string());
+    let mut deprecation = Deprecation::new();
+    deprecation.ch_reason("Use Cart | Checkout instead. Removed soon.".to_string());
+    order.ch_deprecation(Some(deprecation));
+    let mut status = TypeDecl::new(TypeKind::Enumeration);
+    status.ch_name("Status".to_string());
+    status.ch_description("The state of an order".to_string());
+
+    let mut app = ApplicationDoc::new();
+    app.add_package_class("com.shop".to_string(), String::new(), "Order".to_string());
+    app.add_type_summary("com.shop", TypeSummary::new(&order));
+    app.add_type_summary("com.shop", TypeSummary::new(&status));
+    app.packages[0].ch_description("The shop. Everything is sold here.".to_string());
+
+    assert_eq!(
+        templates.render_package(&app.packages[0]).unwrap(),
+        "# Package com.shop\n\nThe shop. Everything is sold here.\n\n\
+         ## Classes\n\n| Class | Description |\n|---|---|\n\
+         | [Order](Order.md) | **Deprecated.** _Use Cart \\| Checkout instead._ An order of the shop. |\n\n\
+         ## Enums\n\n| Enum | Description |\n|---|---|\n\
+         | [Status](Status.md) | The state of an order |\n\n"
+    );
+    assert!(
+        templates
+            .render_contents(&app)
+            .unwrap()
+            .starts_with("# Application Contents\n\n## [com.shop](./com/shop/package-summary.md)\nThe shop.\n\n")
+    );
+}
+
+#[test]
+fn test_single_file_links() {
+    let mut templates = Templates::new(&Backend::Markdown, "").unwrap();
+    templates.ch_single_file(true);
+    let usage = Usage {
+        kind: UsageKind::Field,
+        type_name: "Cart".to_string(),
+        package_name: "com.shop.cart".to_string(),
+        member: "order".to_string(),
+        anchor: "field.order".to_string(),
+    };
+    let doc = templates.render_type(&sample_type(), vec![usage]).unwrap();
+
+    assert!(doc.contains("<li><a href=\"#com.shop.cart.Cart.field.order\">Cart</a>.order (field type)</li>"));
+}
+
+#[test]
+fn test_usage_member_links() {
+    let templates = Templates::new(&Backend::Markdown, "").unwrap();
+    let parent = Usage {
+        kind: UsageKind::Parent,
+        type_name: "SpecialOrder".to_string(),
+        package_name: "com.shop".to_string(),
+        member: String::new(),
+        anchor: String::new(),
+    };
+    let param = Usage {
+        kind: UsageKind::Parameter,
+        type_name: "Cart".to_string(),
+        package_name: "com.shop.cart".to_string(),
+        member: "add".to_string(),
+        anchor: "method.add-1".to_string(),
+    };
+    let doc = templates.render_type(&sample_type(), vec![parent, param]).unwrap();
+
+    assert!(doc.contains("<li><a href=\"SpecialOrder.md\">SpecialOrder</a> (parent)</li>"));
+    assert!(doc.contains("<li><a href=\"cart/Cart.md#method.add-1\">Cart</a>.add (parameter type)</li>"));
+}
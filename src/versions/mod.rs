@@ -0,0 +1,263 @@
+pub mod versions {
+    //! Module that creates the reports of the API added in every version of a project
+    //!
+    //! The `@since` tags of the types and members are collected into a `New-in-{version}.md`
+    //! page for every version, and `Versions.md` lists the versions from the newest to the
+    //! oldest. A member without its own `@since` tag, or tagged with the version of its type,
+    //! arrived with its type and is only listed through it.
+
+    use std::cmp::Ordering;
+
+    use deprecated::deprecated::method_group;
+    use deprecated::deprecated::FIELDS;
+    use deprecated::deprecated::GROUPS;
+    use model::model::constant_anchor;
+    use model::model::field_anchor;
+    use model::model::first_sentence;
+    use model::model::method_anchor;
+    use model::model::LinkFn;
+    use model::model::Project;
+    use model::usage::qualified_name;
+    use template::template::table_cell;
+    use template::template::table_name;
+
+    /// A type or member added in a version
+    pub struct VersionEntry {
+        /// The qualified name of the type or member
+        pub name: String,
+        /// The group of the report listing the entry, e.g. "Methods"
+        pub group: String,
+        pub version: String,
+        pub summary: String,
+        pub link: String,
+    }
+
+    /// Compares two version numbers. The numeric parts are compared as numbers, so
+    /// `3.10` is newer than `3.9`, and the other parts alphabetically.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The first version
+    /// * `b` - The second version
+    pub fn compare_versions(a: &str, b: &str) -> Ordering {
+        let a_parts: Vec<&str> = a.trim().split(|c| c == '.' || c == '-' || c == '_').collect();
+        let b_parts: Vec<&str> = b.trim().split(|c| c == '.' || c == '-' || c == '_').collect();
+
+        for (x, y) in a_parts.iter().zip(b_parts.iter()) {
+            let order = match (x.parse::<u64>(), y.parse::<u64>()) {
+                (Ok(x), Ok(y)) => x.cmp(&y),
+                _ => x.cmp(y),
+            };
+
+            if order != Ordering::Equal {
+                return order;
+            }
+        }
+
+        a_parts.len().cmp(&b_parts.len())
+    }
+
+    /// Gets the major number of a version or of a tag naming one, e.g. `3` for `v3.2.0`
+    /// or `2` for `v2`. A revision whose first number is followed by a letter, like a
+    /// commit id, or which has no number, like a branch, has no major number.
+    ///
+    /// # Arguments
+    ///
+    /// * `version` - The version or tag
+    pub fn major_version(version: &str) -> Option<u64> {
+        let number = version.trim().trim_start_matches(|c: char| !c.is_digit(10));
+        let major: String = number.chars().take_while(|c| c.is_digit(10)).collect();
+
+        let rest = &number[major.len()..];
+        if major.is_empty() || rest.starts_with(char::is_alphanumeric) {
+            return None;
+        }
+
+        major.parse::<u64>().ok()
+    }
+
+    /// Whether the newer of two versions increments the major number of the older one.
+    /// Without a major number in both versions there is no bump.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The older version
+    /// * `to` - The newer version
+    pub fn is_major_bump(from: &str, to: &str) -> bool {
+        match (major_version(from), major_version(to)) {
+            (Some(from), Some(to)) => to > from,
+            _ => false,
+        }
+    }
+
+    /// Gets the versions of the `@since` tags of a project from the newest to the oldest
+    ///
+    /// # Arguments
+    ///
+    /// * `proj` - The documented project
+    pub fn project_versions(proj: &Project) -> Vec<String> {
+        let mut versions: Vec<String> = Vec::new();
+
+        for decl in proj.types.iter() {
+            let constants = decl.fields.iter().map(|f| &f.since);
+            let members = decl.variables.iter().map(|m| &m.since);
+            let methods = decl.methods.iter().map(|m| &m.since);
+
+            for since in Some(&decl.since)
+                .into_iter()
+                .chain(constants)
+                .chain(members)
+                .chain(methods)
+            {
+                if since != "" && !versions.contains(since) {
+                    versions.push(since.clone());
+                }
+            }
+        }
+        versions.sort_by(|a, b| compare_versions(b, a));
+
+        versions
+    }
+
+    /// Gets the file name of the page of a version without its extension
+    ///
+    /// # Arguments
+    ///
+    /// * `version` - The version of the page
+    pub fn version_page(version: &str) -> String {
+        let name: String = version
+            .trim()
+            .chars()
+            .map(|c| if c.is_alphanumeric() || c == '.' || c == '-' || c == '_' { c } else { '-' })
+            .collect();
+
+        format!("New-in-{}", name)
+    }
+
+    /// Finds the types and members with a `@since` tag
+    ///
+    /// # Arguments
+    ///
+    /// * `proj` - The documented project
+    /// * `link` - Creates the links of the type pages
+    pub fn find_new_api(proj: &Project, link: &LinkFn) -> Vec<VersionEntry> {
+        let mut entries = Vec::new();
+
+        for decl in proj.types.iter() {
+            let type_name = qualified_name(&decl.package_name, &decl.name);
+
+            if decl.since != "" {
+                entries.push(VersionEntry {
+                    name: type_name.clone(),
+                    group: decl.kind.plural().to_string(),
+                    version: decl.since.clone(),
+                    summary: first_sentence(&decl.description),
+                    link: link(decl, ""),
+                });
+            }
+            for field in decl.fields.iter() {
+                if field.since != "" && field.since != decl.since {
+                    entries.push(VersionEntry {
+                        name: format!("{}.{}", type_name, field.name),
+                        group: FIELDS.to_string(),
+                        version: field.since.clone(),
+                        summary: first_sentence(&field.desc),
+                        link: link(decl, &constant_anchor(field)),
+                    });
+                }
+            }
+            for member in decl.variables.iter() {
+                if member.since != "" && member.since != decl.since {
+                    entries.push(VersionEntry {
+                        name: format!("{}.{}", type_name, member.name),
+                        group: FIELDS.to_string(),
+                        version: member.since.clone(),
+                        summary: first_sentence(&member.desc),
+                        link: link(decl, &field_anchor(member)),
+                    });
+                }
+            }
+            for (i, method) in decl.methods.iter().enumerate() {
+                if method.since != "" && method.since != decl.since {
+                    let params: Vec<&str> = method.parameters.iter().map(|p| p.var_type.as_str()).collect();
+
+                    entries.push(VersionEntry {
+                        name: format!("{}.{}({})", type_name, method.name, params.join(", ")),
+                        group: method_group(decl, method).to_string(),
+                        version: method.since.clone(),
+                        summary: first_sentence(&method.description),
+                        link: link(decl, &method_anchor(&decl.methods, i)),
+                    });
+                }
+            }
+        }
+        entries.sort_by(|a, b| a.name.cmp(&b.name));
+
+        entries
+    }
+
+    /// Generates the markdown of the page listing the API added in a version, grouped
+    /// by kind
+    ///
+    /// # Arguments
+    ///
+    /// * `version` - The version of the page
+    /// * `entries` - The types and members with a `@since` tag
+    pub fn gen_version_page(version: &str, entries: &Vec<VersionEntry>) -> String {
+        let added: Vec<&VersionEntry> = entries.iter().filter(|e| e.version == version).collect();
+        let mut doc = format!(
+            "# New in {0}\n\n{1} elements were added in version {0}.\n\n",
+            version,
+            added.len()
+        );
+
+        for group in GROUPS.iter() {
+            let rows: Vec<&&VersionEntry> = added.iter().filter(|e| e.group == *group).collect();
+            if rows.len() == 0 {
+                continue;
+            }
+
+            doc.push_str(format!("## {}\n\n| Element | Description |\n|---|---|\n", group).as_str());
+            for entry in rows {
+                doc.push_str(
+                    format!(
+                        "| [{}]({}) | {} |\n",
+                        table_name(&entry.name),
+                        entry.link,
+                        table_cell(&entry.summary)
+                    ).as_str(),
+                );
+            }
+            doc.push_str("\n");
+        }
+
+        doc
+    }
+
+    /// Generates the markdown of the `Versions.md` page linking to the page of every
+    /// version with the number of elements added in it
+    ///
+    /// # Arguments
+    ///
+    /// * `versions` - The versions of the project from the newest to the oldest
+    /// * `entries` - The types and members with a `@since` tag
+    pub fn gen_versions_page(versions: &Vec<String>, entries: &Vec<VersionEntry>) -> String {
+        let mut doc = String::from("# API by Version\n\n");
+
+        if versions.len() == 0 {
+            doc.push_str("No type or member of the project has a `@since` tag.\n");
+            return doc;
+        }
+
+        doc.push_str("| Version | New elements |\n|---|---|\n");
+        for version in versions.iter() {
+            let count = entries.iter().filter(|e| &e.version == version).count();
+            doc.push_str(format!("| [{}]({}.md) | {} |\n", version, version_page(version), count).as_str());
+        }
+
+        doc
+    }
+}
+
+#[cfg(test)]
+mod test;
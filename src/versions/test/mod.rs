@@ -0,0 +1,90 @@
+use std::cmp::Ordering;
+
+use model::model::*;
+use versions::versions::*;
+
+#[test]
+fn test_compare_versions() {
+    assert_eq!(compare_versions("3.10", "3.9"), Ordering::Greater);
+    assert_eq!(compare_versions("3.2", "3.2.1"), Ordering::Less);
+    assert_eq!(compare_versions("2.0-beta", "2.0-alpha"), Ordering::Greater);
+    assert_eq!(compare_versions("1.0", "1.0"), Ordering::Equal);
+}
+
+#[test]
+fn test_major_version() {
+    assert_eq!(major_version("v3.2.0"), Some(3));
+    assert_eq!(major_version("release-10.1"), Some(10));
+    assert_eq!(major_version("v2"), Some(2));
+    assert_eq!(major_version("2"), Some(2));
+    assert_eq!(major_version("v2-rc1"), Some(2));
+    assert_eq!(major_version("main"), None);
+    assert_eq!(major_version("a1b2c3d"), None);
+    assert!(is_major_bump("v1.4.2", "v2.0.0"));
+    assert!(!is_major_bump("v1.4.2", "v1.5.0"));
+    assert!(is_major_bump("v1", "v2"));
+    assert!(!is_major_bump("v1.4.2", "HEAD"));
+}
+
+#[test]
+fn test_version_pages() {
+    let mut proj = Project::new();
+    let mut decl = TypeDecl::new(TypeKind::Class);
+    decl.ch_name("Order".to_string());
+    decl.ch_package_name("com.shop".to_string());
+    decl.ch_description("An order. Placed by a customer.".to_string());
+    decl.ch_since("1.0".to_string());
+
+    let mut member = Member::new();
+    member.ch_name("id".to_string());
+    member.ch_since("1.0".to_string());
+    decl.add_variable(member);
+
+    let mut method = Method::new();
+    method.ch_method_name("cancel".to_string());
+    method.ch_description("Cancels the order".to_string());
+    method.ch_since("1.10".to_string());
+    decl.add_method(method);
+    decl.add_method(Method::new());
+    proj.add_type(decl);
+
+    let mut status = TypeDecl::new(TypeKind::Enumeration);
+    status.ch_name("Status".to_string());
+    status.ch_package_name("com.shop".to_string());
+    status.ch_fields(vec![EnumField {
+        name: "REFUNDED".to_string(),
+        value: "0".to_string(),
+        desc: "Paid back. Fully.".to_string(),
+        deprecation: None,
+        since: "1.2".to_string(),
+    }]);
+    proj.add_type(status);
+
+    let versions = project_versions(&proj);
+    let entries = find_new_api(&proj, &|decl, anchor| format!("{}.md#{}", decl.name, anchor));
+
+    assert_eq!(versions, vec!["1.10", "1.2", "1.0"]);
+    assert_eq!(entries.len(), 3);
+    assert_eq!(version_page("2.0 beta"), "New-in-2.0-beta");
+
+    let page = gen_version_page("1.10", &entries);
+
+    assert!(page.starts_with("# New in 1.10\n\n1 elements were added in version 1.10.\n\n"));
+    assert!(page.contains(
+        "## Methods\n\n| Element | Description |\n|---|---|\n\
+         | [com.shop.Order.cancel()](Order.md#method.cancel) | Cancels the order |\n"
+    ));
+    assert!(gen_version_page("1.0", &entries).contains(
+        "## Classes\n\n| Element | Description |\n|---|---|\n\
+         | [com.shop.Order](Order.md#) | An order. |\n"
+    ));
+    assert!(gen_version_page("1.2", &entries).contains(
+        "## Fields\n\n| Element | Description |\n|---|---|\n\
+         | [com.shop.Status.REFUNDED](Status.md#constant.REFUNDED) | Paid back. |\n"
+    ));
+    assert_eq!(
+        gen_versions_page(&versions, &entries),
+        "# API by Version\n\n| Version | New elements |\n|---|---|\n\
+         | [1.10](New-in-1.10.md) | 1 |\n| [1.2](New-in-1.2.md) | 1 |\n| [1.0](New-in-1.0.md) | 1 |\n"
+    );
+}